@@ -1,15 +1,22 @@
-use std::{env, error::Error};
+use std::{env, error::Error, fs, path::Path};
 use fs_extra::copy_items;
 use fs_extra::dir::CopyOptions;
 
+#[path = "ferride_core/src/file_system/archive.rs"]
+mod archive;
+
 fn main() -> Result<(), Box<dyn Error>> {
+    println!("cargo:rerun-if-changed=res");
 
     let out_dir = env::var("OUT_DIR")?;
     let mut copy_options = CopyOptions::new();
     copy_options.overwrite = true;
     let mut paths_to_copy = Vec::new();
     paths_to_copy.push("res/");
-    copy_items(&paths_to_copy, out_dir, &copy_options)?;
+    copy_items(&paths_to_copy, &out_dir, &copy_options)?;
+
+    let packed_ressources = archive::pack_directory(Path::new("res"), "res")?;
+    fs::write(Path::new(&out_dir).join("res.pak"), packed_ressources)?;
 
     Ok(())
 }
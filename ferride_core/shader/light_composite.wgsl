@@ -0,0 +1,27 @@
+// Multiplies the light map onto everything drawn before the lighting RenderScene.
+// Draws one full screen triangle without a vertex buffer.
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+@group(0) @binding(0)
+var light_map: texture_2d<f32>;
+@group(0) @binding(1)
+var light_sampler: sampler;
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    out.uv = uv;
+    out.clip_position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let light = textureSampleLevel(light_map, light_sampler, in.uv, 0.0);
+    return vec4<f32>(light.rgb, 1.0);
+}
@@ -0,0 +1,45 @@
+// Built-in shader for RenderScenes of kind Lighting.
+// Adds every light to the light map, shadows are resolved by the depth test.
+
+struct CameraUniform {
+    projection: mat3x2<f32>,
+}
+
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) center: vec2<f32>,
+    @location(2) color: vec4<f32>,
+    @location(3) radius: f32,
+    @location(4) depth: f32,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) position: vec2<f32>,
+    @location(1) center: vec2<f32>,
+    @location(2) color: vec4<f32>,
+    @location(3) radius: f32,
+};
+
+@group(0) @binding(0)
+var<uniform> camera: CameraUniform;
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = in.position;
+    out.center = in.center;
+    out.color = in.color;
+    out.radius = in.radius;
+    out.clip_position = vec4<f32>(camera.projection * vec3<f32>(in.position, 1.0), in.depth, 1.0);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if in.radius <= 0.0 {
+        return in.color;
+    }
+    let falloff = clamp(1.0 - distance(in.position, in.center) / in.radius, 0.0, 1.0);
+    return in.color * falloff * falloff;
+}
@@ -0,0 +1,21 @@
+#include "common.wgsl"
+
+struct ChromaticAberration {
+    ///Maximum offset at the corners of the screen in pixels
+    offset: f32,
+    _padding_0: f32,
+    _padding_1: f32,
+    _padding_2: f32,
+};
+@group(2) @binding(0)
+var<uniform> aberration: ChromaticAberration;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let direction = (in.uv - vec2<f32>(0.5, 0.5)) * 2.0;
+    let offset = direction * aberration.offset / globals.resolution;
+    let center = sample_input(in.uv);
+    let r = sample_input(in.uv + offset).r;
+    let b = sample_input(in.uv - offset).b;
+    return vec4<f32>(r, center.g, b, center.a);
+}
@@ -0,0 +1,35 @@
+#include "common.wgsl"
+
+// Lookup table as horizontal strip of N slices with N*N pixels each, e.g. 256x16 for N = 16.
+// Red grows along x inside a slice, green along y and blue selects the slice.
+@group(0) @binding(2)
+var lut: texture_2d<f32>;
+
+struct ColorGrading {
+    intensity: f32,
+    _padding_0: f32,
+    _padding_1: f32,
+    _padding_2: f32,
+};
+@group(2) @binding(0)
+var<uniform> grading: ColorGrading;
+
+fn lut_uv(color: vec3<f32>, slice: f32, size: f32) -> vec2<f32> {
+    let x = (slice * size + color.r * (size - 1.0) + 0.5) / (size * size);
+    let y = (color.g * (size - 1.0) + 0.5) / size;
+    return vec2<f32>(x, y);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = sample_input(in.uv);
+    let size = f32(textureDimensions(lut).y);
+    let graded_color = clamp(color.rgb, vec3<f32>(0.0), vec3<f32>(1.0));
+    let blue = graded_color.b * (size - 1.0);
+    let lower = floor(blue);
+    let upper = min(lower + 1.0, size - 1.0);
+    let a = textureSampleLevel(lut, input_sampler, lut_uv(graded_color, lower, size), 0.0).rgb;
+    let b = textureSampleLevel(lut, input_sampler, lut_uv(graded_color, upper, size), 0.0).rgb;
+    let graded = mix(a, b, blue - lower);
+    return vec4<f32>(mix(color.rgb, graded, grading.intensity), color.a);
+}
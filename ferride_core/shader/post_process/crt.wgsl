@@ -0,0 +1,31 @@
+#include "common.wgsl"
+
+struct Crt {
+    curvature: f32,
+    scanline_intensity: f32,
+    ///Scanlines per screen height. 0 uses one per pixel row
+    scanline_count: f32,
+    _padding: f32,
+};
+@group(2) @binding(0)
+var<uniform> crt: Crt;
+
+const PI: f32 = 3.14159265;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let centered = in.uv * 2.0 - 1.0;
+    let bent = centered * (1.0 + crt.curvature * dot(centered, centered));
+    let uv = bent * 0.5 + 0.5;
+    if uv.x < 0.0 || uv.x > 1.0 || uv.y < 0.0 || uv.y > 1.0 {
+        return vec4<f32>(0.0, 0.0, 0.0, 1.0);
+    }
+    let color = sample_input(uv);
+    var lines = crt.scanline_count;
+    if lines <= 0.0 {
+        lines = globals.resolution.y;
+    }
+    let scanline = 0.5 + 0.5 * sin(uv.y * lines * PI * 2.0);
+    let darkening = 1.0 - crt.scanline_intensity * (1.0 - scanline);
+    return vec4<f32>(color.rgb * darkening, color.a);
+}
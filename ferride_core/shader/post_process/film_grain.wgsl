@@ -0,0 +1,19 @@
+#include "common.wgsl"
+
+struct FilmGrain {
+    intensity: f32,
+    speed: f32,
+    grain_size: f32,
+    _padding: f32,
+};
+@group(2) @binding(0)
+var<uniform> grain: FilmGrain;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = sample_input(in.uv);
+    let cell = floor(in.uv * globals.resolution / max(grain.grain_size, 1.0));
+    let seed = cell + vec2<f32>(floor(globals.time * grain.speed) * 17.0, 0.0);
+    let noise = random(seed) - 0.5;
+    return vec4<f32>(color.rgb + vec3<f32>(noise * grain.intensity), color.a);
+}
@@ -0,0 +1,20 @@
+#include "common.wgsl"
+
+struct Vignette {
+    color: vec4<f32>,
+    intensity: f32,
+    radius: f32,
+    softness: f32,
+    _padding: f32,
+};
+@group(2) @binding(0)
+var<uniform> vignette: Vignette;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = sample_input(in.uv);
+    let distance = length(in.uv - vec2<f32>(0.5, 0.5));
+    let darkness = smoothstep(vignette.radius - vignette.softness, vignette.radius, distance);
+    let amount = darkness * vignette.intensity * vignette.color.a;
+    return vec4<f32>(mix(color.rgb, vignette.color.rgb, amount), color.a);
+}
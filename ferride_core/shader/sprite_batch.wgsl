@@ -1,8 +1,22 @@
-struct VertexInput {
-    @location(0) position: vec2<f32>,
+// Built-in shader for RenderScenes of kind SpriteBatch.
+// Every instance is the unit quad scaled, rotated and moved into place.
+
+struct CameraUniform {
+    projection: mat3x2<f32>,
+}
+
+struct QuadInput {
+    @location(0) corner: vec2<f32>,
     @location(1) tex_coords: vec2<f32>,
-    @location(2) tex_index: u32,
-    @location(3) blend_color: u32,
+};
+
+struct InstanceInput {
+    @location(2) position: vec2<f32>,
+    @location(3) size: vec2<f32>,
+    @location(4) rotation: f32,
+    @location(5) uv_rect: vec4<f32>,
+    @location(6) texture: u32,
+    @location(7) color: u32,
 };
 
 struct VertexOutput {
@@ -12,33 +26,33 @@ struct VertexOutput {
     @location(2) blend_color: u32,
 };
 
-struct CameraUniform {
-    projection: mat3x2<f32>,
-}
+@group(0) @binding(0)
+var texture_array: binding_array<texture_2d<f32>>;
+@group(0) @binding(1)
+var sampler_array: binding_array<sampler>;
+
 @group(1) @binding(0)
 var<uniform> camera: CameraUniform;
 
 @vertex
 fn vs_main(
-    model: VertexInput,
+    quad: QuadInput,
+    instance: InstanceInput,
 ) -> VertexOutput {
-    var out: VertexOutput;
-    out.tex_coords = model.tex_coords;
-    out.tex_index = model.tex_index;
-    out.blend_color = model.blend_color;
-
-    let position = camera.projection * vec3<f32>(model.position, 1.0);
-    out.clip_position = vec4<f32>(position, 0.0, 1.0);
+    let scaled = quad.corner * instance.size;
+    let c = cos(instance.rotation);
+    let s = sin(instance.rotation);
+    let rotated = vec2<f32>(scaled.x * c - scaled.y * s, scaled.x * s + scaled.y * c);
+    let position = instance.position + rotated;
 
+    var out: VertexOutput;
+    out.tex_coords = instance.uv_rect.xy + quad.tex_coords * instance.uv_rect.zw;
+    out.tex_index = instance.texture;
+    out.blend_color = instance.color;
+    out.clip_position = vec4<f32>(camera.projection * vec3<f32>(position, 1.0), 0.0, 1.0);
     return out;
 }
 
-// Fragment shader
-@group(0) @binding(0) 
-var texture_array: binding_array<texture_2d<f32>>;
-@group(0) @binding(1) 
-var sampler_array: binding_array<sampler>;
-
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var sample = textureSampleLevel(texture_array[in.tex_index], sampler_array[in.tex_index], in.tex_coords, 0.0);
@@ -53,6 +67,6 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         if alpha_b == 0.0 {
             sample.a = alpha_b;
         }
-    } 
+    }
     return sample;
 }
@@ -0,0 +1,292 @@
+use std::{
+    cell::RefCell,
+    io::Cursor,
+    path::Path,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use log::warn;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+
+use super::SoundName;
+
+///Identifies one playing instance of a sound, the same sound may play several times at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaybackId(pub u64);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayRequest {
+    pub sound: SoundName,
+    pub looping: bool,
+    pub volume: f32,
+    ///Time into the sound to start at
+    pub start: Duration,
+}
+
+///Plays sounds for the `AudioManager`. Volumes already include the volume and mute state of the
+///bus the sound plays on
+pub trait AudioBackend {
+    fn play(&mut self, id: PlaybackId, path: &Path, request: PlayRequest);
+    fn set_volume(&mut self, id: PlaybackId, volume: f32);
+    fn stop(&mut self, id: PlaybackId);
+    fn is_finished(&self, id: PlaybackId) -> bool;
+    ///Time into the sound, starting over with every loop
+    fn position(&self, id: PlaybackId) -> Duration;
+}
+
+type MemoryDecoder = Decoder<Cursor<Arc<[u8]>>>;
+
+///Decodes a sound from memory, starting over at its end if it loops, and shares how many samples
+///into the sound it is
+struct TrackedSource {
+    bytes: Arc<[u8]>,
+    decoder: MemoryDecoder,
+    looping: bool,
+    samples: Arc<AtomicU64>,
+}
+impl TrackedSource {
+    fn new(bytes: Arc<[u8]>, looping: bool, start: Duration) -> Result<Self, String> {
+        let mut decoder = Decoder::new(Cursor::new(bytes.clone())).map_err(|e| e.to_string())?;
+        let samples_per_second = decoder.sample_rate() as u64 * decoder.channels() as u64;
+        let mut samples = 0;
+        if !start.is_zero() {
+            samples = (start.as_secs_f64() * samples_per_second as f64) as u64;
+            if decoder.try_seek(start).is_err() {
+                decoder.by_ref().take(samples as usize).for_each(drop);
+            }
+        }
+        Ok(Self {
+            bytes,
+            decoder,
+            looping,
+            samples: Arc::new(AtomicU64::new(samples)),
+        })
+    }
+}
+impl Iterator for TrackedSource {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        let sample = match self.decoder.next() {
+            Some(sample) => sample,
+            None if self.looping => {
+                self.decoder = Decoder::new(Cursor::new(self.bytes.clone())).ok()?;
+                self.samples.store(0, Ordering::Relaxed);
+                self.decoder.next()?
+            }
+            None => return None,
+        };
+        self.samples.fetch_add(1, Ordering::Relaxed);
+        Some(sample)
+    }
+}
+impl Source for TrackedSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.decoder.current_frame_len()
+    }
+    fn channels(&self) -> u16 {
+        self.decoder.channels()
+    }
+    fn sample_rate(&self) -> u32 {
+        self.decoder.sample_rate()
+    }
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+struct RodioPlayback {
+    id: PlaybackId,
+    sink: Sink,
+    samples: Arc<AtomicU64>,
+    samples_per_second: u64,
+}
+
+///Plays on the default output device of the system
+pub struct RodioBackend {
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+    ///Files are read once from the vfs and decoded from memory on every play
+    files: Vec<(SoundName, Arc<[u8]>)>,
+    playbacks: Vec<RodioPlayback>,
+}
+impl RodioBackend {
+    ///None if there is no output device
+    pub fn new() -> Option<Self> {
+        match OutputStream::try_default() {
+            Ok((stream, handle)) => Some(Self {
+                _stream: stream,
+                handle,
+                files: Vec::new(),
+                playbacks: Vec::new(),
+            }),
+            Err(err) => {
+                warn!("No audio output available: {}", err);
+                None
+            }
+        }
+    }
+
+    fn file(&mut self, sound: &SoundName, path: &Path) -> Arc<[u8]> {
+        if let Some((_, bytes)) = self.files.iter().find(|(name, _)| name == sound) {
+            return bytes.clone();
+        }
+        let bytes: Arc<[u8]> = crate::vfs::read(path)
+            .expect(&format!("Could not read sound file at {:?}", path))
+            .into();
+        self.files.push((sound.clone(), bytes.clone()));
+        bytes
+    }
+}
+impl AudioBackend for RodioBackend {
+    fn play(&mut self, id: PlaybackId, path: &Path, request: PlayRequest) {
+        let bytes = self.file(&request.sound, path);
+        let source = TrackedSource::new(bytes, request.looping, request.start)
+            .unwrap_or_else(|err| panic!("Could not decode sound file at {:?}: {}", path, err));
+        let samples = source.samples.clone();
+        let samples_per_second = source.sample_rate() as u64 * source.channels() as u64;
+        let sink = Sink::try_new(&self.handle).expect("Could not create an audio sink");
+        sink.set_volume(request.volume);
+        sink.append(source);
+        self.playbacks.push(RodioPlayback {
+            id,
+            sink,
+            samples,
+            samples_per_second,
+        });
+    }
+
+    fn set_volume(&mut self, id: PlaybackId, volume: f32) {
+        if let Some(playback) = self.playbacks.iter().find(|p| p.id == id) {
+            playback.sink.set_volume(volume);
+        }
+    }
+
+    fn stop(&mut self, id: PlaybackId) {
+        self.playbacks.retain(|playback| {
+            if playback.id == id {
+                playback.sink.stop();
+            }
+            playback.id != id
+        });
+    }
+
+    fn is_finished(&self, id: PlaybackId) -> bool {
+        self.playbacks
+            .iter()
+            .find(|p| p.id == id)
+            .map_or(true, |playback| playback.sink.empty())
+    }
+
+    fn position(&self, id: PlaybackId) -> Duration {
+        self.playbacks
+            .iter()
+            .find(|p| p.id == id)
+            .map_or(Duration::ZERO, |playback| {
+                Duration::from_secs_f64(
+                    playback.samples.load(Ordering::Relaxed) as f64
+                        / playback.samples_per_second.max(1) as f64,
+                )
+            })
+    }
+}
+
+///Plays nothing, every sound is finished right away
+pub struct NullBackend;
+impl AudioBackend for NullBackend {
+    fn play(&mut self, _id: PlaybackId, _path: &Path, _request: PlayRequest) {}
+    fn set_volume(&mut self, _id: PlaybackId, _volume: f32) {}
+    fn stop(&mut self, _id: PlaybackId) {}
+    fn is_finished(&self, _id: PlaybackId) -> bool {
+        true
+    }
+    fn position(&self, _id: PlaybackId) -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioRecord {
+    Played(PlaybackId, PlayRequest),
+    VolumeChanged(PlaybackId, f32),
+    Stopped(PlaybackId),
+}
+
+///Plays nothing, but remembers every call. Sounds play until they are stopped or `finish` is
+///called and stay at their start position until `set_position`. Clones share their records, so a
+///clone kept by a test sees what the `AudioManager` did
+#[derive(Debug, Clone, Default)]
+pub struct RecordingBackend {
+    records: Rc<RefCell<Vec<AudioRecord>>>,
+    finished: Rc<RefCell<Vec<PlaybackId>>>,
+    positions: Rc<RefCell<Vec<(PlaybackId, Duration)>>>,
+}
+impl RecordingBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn records(&self) -> Vec<AudioRecord> {
+        self.records.borrow().clone()
+    }
+
+    ///Names of all sounds played so far, in order
+    pub fn played(&self) -> Vec<SoundName> {
+        self.records
+            .borrow()
+            .iter()
+            .filter_map(|record| match record {
+                AudioRecord::Played(_, request) => Some(request.sound.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    ///Lets the playback end, as if the sound reached its end
+    pub fn finish(&self, id: PlaybackId) {
+        self.finished.borrow_mut().push(id);
+    }
+
+    ///Pretends the playback got `position` into its sound
+    pub fn set_position(&self, id: PlaybackId, position: Duration) {
+        let mut positions = self.positions.borrow_mut();
+        positions.retain(|(i, _)| *i != id);
+        positions.push((id, position));
+    }
+}
+impl AudioBackend for RecordingBackend {
+    fn play(&mut self, id: PlaybackId, _path: &Path, request: PlayRequest) {
+        self.set_position(id, request.start);
+        self.records
+            .borrow_mut()
+            .push(AudioRecord::Played(id, request));
+    }
+
+    fn set_volume(&mut self, id: PlaybackId, volume: f32) {
+        self.records
+            .borrow_mut()
+            .push(AudioRecord::VolumeChanged(id, volume));
+    }
+
+    fn stop(&mut self, id: PlaybackId) {
+        self.records.borrow_mut().push(AudioRecord::Stopped(id));
+        self.finished.borrow_mut().push(id);
+    }
+
+    fn is_finished(&self, id: PlaybackId) -> bool {
+        self.finished.borrow().contains(&id)
+    }
+
+    fn position(&self, id: PlaybackId) -> Duration {
+        self.positions
+            .borrow()
+            .iter()
+            .find(|(i, _)| *i == id)
+            .map_or(Duration::ZERO, |(_, position)| *position)
+    }
+}
@@ -0,0 +1,493 @@
+use std::{path::PathBuf, time::Duration};
+
+use log::warn;
+
+use crate::create_name_struct;
+
+mod backend;
+pub use backend::{
+    AudioBackend, AudioRecord, NullBackend, PlayRequest, PlaybackId, RecordingBackend, RodioBackend,
+};
+
+create_name_struct!(SoundName);
+
+///Group of sounds sharing a volume, so music and effects can be adjusted separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bus {
+    Music,
+    Sfx,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioCommand {
+    Play(SoundName),
+    ///Plays the sound again and again until it is stopped
+    Loop(SoundName),
+    ///Stops every playback of the sound
+    Stop(SoundName),
+    StopBus(Bus),
+    ///Clamped to 0.0..=1.0
+    SetBusVolume(Bus, f32),
+    SetBusMuted(Bus, bool),
+    ///Crossfades from the current music to the track over the duration. A track that played
+    ///before resumes where it was faded out
+    PlayMusic(SoundName, Duration),
+    ///Fades out the music and all of its stems
+    StopMusic(Duration),
+    ///Fades in a stem on top of the current music, in sync with it
+    AddStem(SoundName, Duration),
+    RemoveStem(SoundName, Duration),
+}
+
+#[derive(Debug, Clone)]
+struct BusSettings {
+    bus: Bus,
+    volume: f32,
+    muted: bool,
+}
+impl BusSettings {
+    fn volume(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.volume
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Fade {
+    from: f32,
+    to: f32,
+    duration: Duration,
+    elapsed: Duration,
+    stop_at_end: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Playback {
+    id: PlaybackId,
+    sound: SoundName,
+    bus: Bus,
+    ///Volume of the playback relative to its bus
+    gain: f32,
+    fade: Option<Fade>,
+}
+
+#[derive(Debug, Clone)]
+struct Music {
+    track: SoundName,
+    playback: PlaybackId,
+    stems: Vec<(SoundName, PlaybackId)>,
+}
+
+///Plays the sounds of the `RessourceDescriptor` on their bus through an `AudioBackend`
+pub struct AudioManager {
+    backend: Box<dyn AudioBackend>,
+    sounds: Vec<(SoundName, PathBuf, Bus)>,
+    buses: Vec<BusSettings>,
+    playbacks: Vec<Playback>,
+    music: Option<Music>,
+    ///Where tracks were when they were faded out, to resume them from there
+    music_positions: Vec<(SoundName, Duration)>,
+    next_id: u64,
+}
+impl AudioManager {
+    pub fn new(backend: Box<dyn AudioBackend>, sounds: Vec<(SoundName, PathBuf, Bus)>) -> Self {
+        Self {
+            backend,
+            sounds,
+            buses: [Bus::Music, Bus::Sfx]
+                .into_iter()
+                .map(|bus| BusSettings {
+                    bus,
+                    volume: 1.0,
+                    muted: false,
+                })
+                .collect(),
+            playbacks: Vec::new(),
+            music: None,
+            music_positions: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    ///Plays on the default output device, or nowhere if there is none
+    pub fn with_default_backend(sounds: Vec<(SoundName, PathBuf, Bus)>) -> Self {
+        let backend: Box<dyn AudioBackend> = match RodioBackend::new() {
+            Some(backend) => Box::new(backend),
+            None => Box::new(NullBackend),
+        };
+        Self::new(backend, sounds)
+    }
+
+    pub fn set_backend(&mut self, backend: Box<dyn AudioBackend>) {
+        for playback in self.playbacks.drain(..) {
+            self.backend.stop(playback.id);
+        }
+        self.music = None;
+        self.backend = backend;
+    }
+
+    pub fn handle_command(&mut self, command: &AudioCommand) {
+        match command {
+            AudioCommand::Play(sound) => {
+                self.play(sound, false);
+            }
+            AudioCommand::Loop(sound) => {
+                self.play(sound, true);
+            }
+            AudioCommand::Stop(sound) => self.stop(sound),
+            AudioCommand::StopBus(bus) => self.stop_bus(*bus),
+            AudioCommand::SetBusVolume(bus, volume) => self.set_bus_volume(*bus, *volume),
+            AudioCommand::SetBusMuted(bus, muted) => self.set_bus_muted(*bus, *muted),
+            AudioCommand::PlayMusic(track, crossfade) => self.play_music(track, *crossfade),
+            AudioCommand::StopMusic(fade) => self.stop_music(*fade),
+            AudioCommand::AddStem(stem, fade) => self.add_stem(stem, *fade),
+            AudioCommand::RemoveStem(stem, fade) => self.remove_stem(stem, *fade),
+        }
+    }
+
+    ///None if the sound is not declared
+    pub fn play(&mut self, sound: &SoundName, looping: bool) -> Option<PlaybackId> {
+        self.start(sound, looping, Duration::ZERO, 1.0)
+    }
+
+    fn start(
+        &mut self,
+        sound: &SoundName,
+        looping: bool,
+        start: Duration,
+        gain: f32,
+    ) -> Option<PlaybackId> {
+        let Some((_, path, bus)) = self.sounds.iter().find(|(name, _, _)| name == sound) else {
+            warn!("Tried to play sound {:?}, but it is not declared", sound);
+            return None;
+        };
+        let id = PlaybackId(self.next_id);
+        self.next_id += 1;
+        let request = PlayRequest {
+            sound: sound.clone(),
+            looping,
+            volume: self.bus_settings(*bus).volume() * gain,
+            start,
+        };
+        self.backend.play(id, path, request);
+        self.playbacks.push(Playback {
+            id,
+            sound: sound.clone(),
+            bus: *bus,
+            gain,
+            fade: None,
+        });
+        Some(id)
+    }
+
+    pub fn stop(&mut self, sound: &SoundName) {
+        self.stop_where(|playback| playback.sound == *sound);
+    }
+
+    pub fn stop_bus(&mut self, bus: Bus) {
+        self.stop_where(|playback| playback.bus == bus);
+    }
+
+    fn stop_where(&mut self, condition: impl Fn(&Playback) -> bool) {
+        let backend = &mut self.backend;
+        self.playbacks.retain(|playback| {
+            if condition(playback) {
+                backend.stop(playback.id);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    pub fn play_music(&mut self, track: &SoundName, crossfade: Duration) {
+        if self
+            .music
+            .as_ref()
+            .is_some_and(|music| music.track == *track)
+        {
+            return;
+        }
+        self.stop_music(crossfade);
+        let start = self
+            .music_positions
+            .iter()
+            .find(|(sound, _)| sound == track)
+            .map_or(Duration::ZERO, |(_, position)| *position);
+        if let Some(playback) = self.start(track, true, start, 0.0) {
+            self.fade(playback, 1.0, crossfade, false);
+            self.music = Some(Music {
+                track: track.clone(),
+                playback,
+                stems: Vec::new(),
+            });
+        }
+    }
+
+    pub fn stop_music(&mut self, fade: Duration) {
+        let Some(music) = self.music.take() else {
+            return;
+        };
+        let position = self.backend.position(music.playback);
+        self.music_positions
+            .retain(|(sound, _)| *sound != music.track);
+        self.music_positions.push((music.track, position));
+        self.fade(music.playback, 0.0, fade, true);
+        for (_, stem) in music.stems {
+            self.fade(stem, 0.0, fade, true);
+        }
+    }
+
+    pub fn add_stem(&mut self, stem: &SoundName, fade: Duration) {
+        let Some(music) = &self.music else {
+            warn!("Tried to add stem {:?}, but no music is playing", stem);
+            return;
+        };
+        if music.stems.iter().any(|(sound, _)| sound == stem) {
+            return;
+        }
+        let position = self.backend.position(music.playback);
+        if let Some(playback) = self.start(stem, true, position, 0.0) {
+            self.fade(playback, 1.0, fade, false);
+            if let Some(music) = &mut self.music {
+                music.stems.push((stem.clone(), playback));
+            }
+        }
+    }
+
+    pub fn remove_stem(&mut self, stem: &SoundName, fade: Duration) {
+        let Some(music) = &mut self.music else {
+            return;
+        };
+        let Some(index) = music.stems.iter().position(|(sound, _)| sound == stem) else {
+            return;
+        };
+        let (_, playback) = music.stems.remove(index);
+        self.fade(playback, 0.0, fade, true);
+    }
+
+    pub fn current_music(&self) -> Option<&SoundName> {
+        self.music.as_ref().map(|music| &music.track)
+    }
+
+    ///Changes the gain of the playback to `to` over `duration`, stopping it at the end if
+    ///`stop_at_end`
+    fn fade(&mut self, id: PlaybackId, to: f32, duration: Duration, stop_at_end: bool) {
+        let Some(playback) = self.playbacks.iter_mut().find(|p| p.id == id) else {
+            return;
+        };
+        playback.fade = Some(Fade {
+            from: playback.gain,
+            to,
+            duration,
+            elapsed: Duration::ZERO,
+            stop_at_end,
+        });
+        if duration.is_zero() {
+            self.update(Duration::ZERO);
+        }
+    }
+
+    pub fn set_bus_volume(&mut self, bus: Bus, volume: f32) {
+        self.bus_settings_mut(bus).volume = volume.clamp(0.0, 1.0);
+        self.apply_bus_volume(bus);
+    }
+
+    pub fn set_bus_muted(&mut self, bus: Bus, muted: bool) {
+        self.bus_settings_mut(bus).muted = muted;
+        self.apply_bus_volume(bus);
+    }
+
+    pub fn bus_volume(&self, bus: Bus) -> f32 {
+        self.bus_settings(bus).volume
+    }
+
+    pub fn is_bus_muted(&self, bus: Bus) -> bool {
+        self.bus_settings(bus).muted
+    }
+
+    pub fn is_playing(&self, sound: &SoundName) -> bool {
+        self.playbacks
+            .iter()
+            .any(|playback| playback.sound == *sound)
+    }
+
+    ///Advances fades and forgets playbacks that reached their end
+    pub fn update(&mut self, delta_t: Duration) {
+        let bus_volumes = self
+            .buses
+            .iter()
+            .map(|settings| (settings.bus, settings.volume()))
+            .collect::<Vec<_>>();
+        let mut stopped = Vec::new();
+        for playback in self.playbacks.iter_mut() {
+            let Some(fade) = &mut playback.fade else {
+                continue;
+            };
+            fade.elapsed += delta_t;
+            let t = if fade.duration.is_zero() {
+                1.0
+            } else {
+                (fade.elapsed.as_secs_f32() / fade.duration.as_secs_f32()).min(1.0)
+            };
+            playback.gain = fade.from + (fade.to - fade.from) * t;
+            if t >= 1.0 {
+                if fade.stop_at_end {
+                    stopped.push(playback.id);
+                }
+                playback.fade = None;
+            }
+            let (_, bus_volume) = bus_volumes
+                .iter()
+                .find(|(bus, _)| *bus == playback.bus)
+                .expect("Every bus has settings");
+            self.backend
+                .set_volume(playback.id, bus_volume * playback.gain);
+        }
+        self.stop_where(|playback| stopped.contains(&playback.id));
+        let backend = &self.backend;
+        self.playbacks
+            .retain(|playback| !backend.is_finished(playback.id));
+    }
+
+    fn apply_bus_volume(&mut self, bus: Bus) {
+        let volume = self.bus_settings(bus).volume();
+        for playback in self.playbacks.iter().filter(|p| p.bus == bus) {
+            self.backend.set_volume(playback.id, volume * playback.gain);
+        }
+    }
+
+    fn bus_settings(&self, bus: Bus) -> &BusSettings {
+        self.buses
+            .iter()
+            .find(|settings| settings.bus == bus)
+            .expect("Every bus has settings")
+    }
+
+    fn bus_settings_mut(&mut self, bus: Bus) -> &mut BusSettings {
+        self.buses
+            .iter_mut()
+            .find(|settings| settings.bus == bus)
+            .expect("Every bus has settings")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager() -> (AudioManager, RecordingBackend) {
+        let recording = RecordingBackend::new();
+        let manager = AudioManager::new(
+            Box::new(recording.clone()),
+            vec![
+                ("Jungle".into(), "res/audio/Jungle.mp3".into(), Bus::Music),
+                ("Battle".into(), "res/audio/battle.ogg".into(), Bus::Music),
+                ("Drums".into(), "res/audio/drums.ogg".into(), Bus::Music),
+                ("Hit".into(), "res/audio/hit.wav".into(), Bus::Sfx),
+            ],
+        );
+        (manager, recording)
+    }
+
+    #[test]
+    fn plays_sounds_on_their_bus() {
+        let (mut audio, recording) = manager();
+        audio.handle_command(&AudioCommand::SetBusVolume(Bus::Music, 0.25));
+        audio.handle_command(&AudioCommand::Loop("Jungle".into()));
+        audio.handle_command(&AudioCommand::SetBusMuted(Bus::Sfx, true));
+        audio.handle_command(&AudioCommand::Play("Hit".into()));
+        audio.handle_command(&AudioCommand::Play("Missing".into()));
+        assert_eq!(recording.played(), vec!["Jungle".into(), "Hit".into()]);
+        assert_eq!(
+            recording.records()[..2],
+            [
+                AudioRecord::Played(
+                    PlaybackId(0),
+                    PlayRequest {
+                        sound: "Jungle".into(),
+                        looping: true,
+                        volume: 0.25,
+                        start: Duration::ZERO,
+                    }
+                ),
+                AudioRecord::Played(
+                    PlaybackId(1),
+                    PlayRequest {
+                        sound: "Hit".into(),
+                        looping: false,
+                        volume: 0.0,
+                        start: Duration::ZERO,
+                    }
+                ),
+            ]
+        );
+
+        audio.handle_command(&AudioCommand::SetBusMuted(Bus::Sfx, false));
+        assert_eq!(
+            recording.records().last(),
+            Some(&AudioRecord::VolumeChanged(PlaybackId(1), 1.0))
+        );
+        recording.finish(PlaybackId(1));
+        audio.update(Duration::ZERO);
+        assert!(!audio.is_playing(&"Hit".into()));
+
+        audio.handle_command(&AudioCommand::StopBus(Bus::Music));
+        assert_eq!(
+            recording.records().last(),
+            Some(&AudioRecord::Stopped(PlaybackId(0)))
+        );
+        assert!(!audio.is_playing(&"Jungle".into()));
+    }
+
+    #[test]
+    fn crossfades_and_resumes_music() {
+        let (mut audio, recording) = manager();
+        let second = Duration::from_secs(1);
+        audio.handle_command(&AudioCommand::PlayMusic("Jungle".into(), Duration::ZERO));
+        recording.set_position(PlaybackId(0), Duration::from_secs(42));
+        audio.handle_command(&AudioCommand::PlayMusic("Battle".into(), 2 * second));
+        audio.handle_command(&AudioCommand::AddStem("Drums".into(), second));
+        assert_eq!(
+            recording.records().last(),
+            Some(&AudioRecord::Played(
+                PlaybackId(2),
+                PlayRequest {
+                    sound: "Drums".into(),
+                    looping: true,
+                    volume: 0.0,
+                    start: Duration::ZERO,
+                }
+            ))
+        );
+
+        audio.update(second);
+        let records = recording.records();
+        let volumes = &records[records.len() - 3..];
+        assert!(volumes.contains(&AudioRecord::VolumeChanged(PlaybackId(0), 0.5)));
+        assert!(volumes.contains(&AudioRecord::VolumeChanged(PlaybackId(1), 0.5)));
+        assert!(volumes.contains(&AudioRecord::VolumeChanged(PlaybackId(2), 1.0)));
+
+        audio.update(second);
+        assert!(!audio.is_playing(&"Jungle".into()));
+        assert_eq!(audio.current_music(), Some(&"Battle".into()));
+
+        audio.handle_command(&AudioCommand::PlayMusic("Jungle".into(), second));
+        let records = recording.records();
+        assert!(records.contains(&AudioRecord::Played(
+            PlaybackId(3),
+            PlayRequest {
+                sound: "Jungle".into(),
+                looping: true,
+                volume: 0.0,
+                start: Duration::from_secs(42),
+            }
+        )));
+        audio.update(second);
+        assert!(!audio.is_playing(&"Drums".into()));
+        assert!(!audio.is_playing(&"Battle".into()));
+    }
+}
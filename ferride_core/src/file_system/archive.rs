@@ -0,0 +1,181 @@
+//! Packed asset archive.
+//!
+//! This file only depends on `std`, so build scripts can pull it in with
+//! `#[path = ".../archive.rs"] mod archive;` to write archives at compile time.
+//!
+//! Layout (all integers little endian):
+//! ```text
+//! b"FPAK" | u32 version | u32 entry count
+//! per entry: u32 path length | path (utf8, '/' separated) | u64 offset | u64 length
+//! data
+//! ```
+//! Offsets are relative to the start of the data section.
+#![allow(dead_code)]
+
+use std::{
+    borrow::Cow,
+    fs, io,
+    ops::Range,
+    path::{Component, Path},
+};
+
+const MAGIC: &[u8; 4] = b"FPAK";
+const VERSION: u32 = 1;
+
+///Turns `./res\\images/../fonts/font.png` into `res/fonts/font.png`, so lookups do not depend on
+///the platform or on how the path was written
+pub fn normalize_path(path: &Path) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => {
+                for part in part.to_string_lossy().split('\\') {
+                    if !part.is_empty() {
+                        parts.push(part.to_string());
+                    }
+                }
+            }
+            Component::ParentDir => {
+                parts.pop();
+            }
+            Component::CurDir | Component::RootDir | Component::Prefix(_) => {}
+        }
+    }
+    parts.join("/")
+}
+
+pub struct PackedArchive {
+    data: Cow<'static, [u8]>,
+    entries: Vec<(String, Range<usize>)>,
+}
+impl PackedArchive {
+    ///Use with `include_bytes!` for archives built by a build script
+    pub fn from_static(bytes: &'static [u8]) -> io::Result<Self> {
+        Self::parse(Cow::Borrowed(bytes))
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> io::Result<Self> {
+        Self::parse(Cow::Owned(bytes))
+    }
+
+    fn parse(data: Cow<'static, [u8]>) -> io::Result<Self> {
+        let mut cursor = 0;
+        if read_slice(&data, &mut cursor, 4)? != MAGIC {
+            return Err(invalid_data("Not a packed archive: bad magic"));
+        }
+        let version = read_u32(&data, &mut cursor)?;
+        if version != VERSION {
+            return Err(invalid_data(&format!(
+                "Unsupported packed archive version {}",
+                version
+            )));
+        }
+        let count = read_u32(&data, &mut cursor)?;
+        let mut raw_entries = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let path_len = read_u32(&data, &mut cursor)? as usize;
+            let path = String::from_utf8(read_slice(&data, &mut cursor, path_len)?.to_vec())
+                .map_err(|_| invalid_data("Packed archive entry is not valid utf8"))?;
+            let offset = read_u64(&data, &mut cursor)? as usize;
+            let len = read_u64(&data, &mut cursor)? as usize;
+            raw_entries.push((path, offset, len));
+        }
+        let data_start = cursor;
+        let mut entries = Vec::with_capacity(raw_entries.len());
+        for (path, offset, len) in raw_entries {
+            let start = data_start + offset;
+            if start + len > data.len() {
+                return Err(invalid_data(&format!(
+                    "Packed archive entry '{}' points outside of the archive",
+                    path
+                )));
+            }
+            entries.push((path, start..start + len));
+        }
+        Ok(Self { data, entries })
+    }
+
+    pub fn get(&self, path: &Path) -> Option<&[u8]> {
+        let path = normalize_path(path);
+        self.entries
+            .iter()
+            .find(|(entry, _)| *entry == path)
+            .map(|(_, range)| &self.data[range.clone()])
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(path, _)| path.as_str())
+    }
+}
+
+///Packs every file below `directory` into an archive. Entries are stored under `prefix`, e.g.
+///packing `./res` with the prefix `res` stores `./res/fonts/font.png` as `res/fonts/font.png`
+pub fn pack_directory(directory: &Path, prefix: &str) -> io::Result<Vec<u8>> {
+    let mut files = Vec::new();
+    collect_files(directory, Path::new(prefix), &mut files)?;
+    files.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut entries = Vec::with_capacity(files.len());
+    for (path, file) in files {
+        entries.push((normalize_path(&path), fs::read(file)?));
+    }
+    Ok(write_archive(&entries))
+}
+
+pub fn write_archive(entries: &[(String, Vec<u8>)]) -> Vec<u8> {
+    let mut archive = Vec::new();
+    archive.extend_from_slice(MAGIC);
+    archive.extend_from_slice(&VERSION.to_le_bytes());
+    archive.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+    let mut offset = 0u64;
+    for (path, data) in entries {
+        archive.extend_from_slice(&(path.len() as u32).to_le_bytes());
+        archive.extend_from_slice(path.as_bytes());
+        archive.extend_from_slice(&offset.to_le_bytes());
+        archive.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        offset += data.len() as u64;
+    }
+    for (_, data) in entries {
+        archive.extend_from_slice(data);
+    }
+    archive
+}
+
+fn collect_files(
+    directory: &Path,
+    prefix: &Path,
+    files: &mut Vec<(std::path::PathBuf, std::path::PathBuf)>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        let archive_path = prefix.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            collect_files(&path, &archive_path, files)?;
+        } else {
+            files.push((archive_path, path));
+        }
+    }
+    Ok(())
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_slice<'a>(data: &'a [u8], cursor: &mut usize, len: usize) -> io::Result<&'a [u8]> {
+    let slice = data
+        .get(*cursor..*cursor + len)
+        .ok_or_else(|| invalid_data("Packed archive is truncated"))?;
+    *cursor += len;
+    Ok(slice)
+}
+
+fn read_u32(data: &[u8], cursor: &mut usize) -> io::Result<u32> {
+    let bytes = read_slice(data, cursor, 4)?;
+    Ok(u32::from_le_bytes(bytes.try_into().expect("Unreachable")))
+}
+
+fn read_u64(data: &[u8], cursor: &mut usize) -> io::Result<u64> {
+    let bytes = read_slice(data, cursor, 8)?;
+    Ok(u64::from_le_bytes(bytes.try_into().expect("Unreachable")))
+}
@@ -0,0 +1,217 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use log::{info, warn};
+
+mod archive;
+pub use archive::{normalize_path, pack_directory, write_archive, PackedArchive};
+
+///Environment variable pointing to a directory whose files take precedence over every other
+///mount. Meant for mods and for editing assets without rebuilding the archive
+pub const OVERRIDE_DIRECTORY_VARIABLE: &str = "FERRIDE_OVERRIDE_DIR";
+
+///Files shipped with the engine, like the built-in post-process shaders. They are looked up
+///after every mount, so games can replace them
+const ENGINE_FILES: &[(&str, &[u8])] = &[
+    (
+        "ferride/shader/sprite_batch.wgsl",
+        include_bytes!("../../shader/sprite_batch.wgsl"),
+    ),
+    (
+        "ferride/shader/lighting.wgsl",
+        include_bytes!("../../shader/lighting.wgsl"),
+    ),
+    (
+        "ferride/shader/light_composite.wgsl",
+        include_bytes!("../../shader/light_composite.wgsl"),
+    ),
+    (
+        "ferride/shader/post_process/common.wgsl",
+        include_bytes!("../../shader/post_process/common.wgsl"),
+    ),
+    (
+        "ferride/shader/post_process/vignette.wgsl",
+        include_bytes!("../../shader/post_process/vignette.wgsl"),
+    ),
+    (
+        "ferride/shader/post_process/film_grain.wgsl",
+        include_bytes!("../../shader/post_process/film_grain.wgsl"),
+    ),
+    (
+        "ferride/shader/post_process/chromatic_aberration.wgsl",
+        include_bytes!("../../shader/post_process/chromatic_aberration.wgsl"),
+    ),
+    (
+        "ferride/shader/post_process/crt.wgsl",
+        include_bytes!("../../shader/post_process/crt.wgsl"),
+    ),
+    (
+        "ferride/shader/post_process/color_grading.wgsl",
+        include_bytes!("../../shader/post_process/color_grading.wgsl"),
+    ),
+];
+
+enum Mount {
+    Directory(PathBuf),
+    Archive(PackedArchive),
+}
+impl Mount {
+    fn read(&self, path: &Path) -> Option<Vec<u8>> {
+        match self {
+            Mount::Directory(directory) => fs::read(directory.join(path)).ok(),
+            Mount::Archive(archive) => archive.get(path).map(|data| data.to_vec()),
+        }
+    }
+}
+
+///Resolves relative ressource paths like `res/images/cursor/Cursor_Goth_Cursor.png`.
+///Lookup order: override directories, mounts in the order they were added, the files shipped
+///with the engine under `ferride/` and finally the current working directory
+pub struct VirtualFileSystem {
+    overrides: Vec<PathBuf>,
+    mounts: Vec<Mount>,
+}
+impl VirtualFileSystem {
+    ///Mounts the directory of the executable and the directory in `FERRIDE_OVERRIDE_DIR`, if set
+    pub fn new() -> Self {
+        let mut file_system = Self {
+            overrides: Vec::new(),
+            mounts: Vec::new(),
+        };
+        if let Some(directory) = env::var_os(OVERRIDE_DIRECTORY_VARIABLE) {
+            file_system = file_system.with_override_directory(directory);
+        }
+        if let Some(directory) = env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(|p| p.to_path_buf()))
+        {
+            file_system = file_system.with_directory(directory);
+        }
+        file_system
+    }
+
+    pub fn with_override_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.overrides.insert(0, directory.into());
+        self
+    }
+
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.mounts.push(Mount::Directory(directory.into()));
+        self
+    }
+
+    pub fn with_archive(mut self, archive: PackedArchive) -> Self {
+        self.mounts.push(Mount::Archive(archive));
+        self
+    }
+
+    ///Makes this the file system used by the engine for textures, shaders, cursors, icons and
+    ///audio. Can only be called once, before the first ressource is loaded
+    pub fn install(self) {
+        if FILE_SYSTEM.set(self).is_err() {
+            warn!("VirtualFileSystem already installed. Ignoring the new one");
+        }
+    }
+
+    pub fn read(&self, path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        let path = path.as_ref();
+        if path.is_absolute() {
+            return fs::read(path);
+        }
+        for directory in &self.overrides {
+            if let Ok(data) = fs::read(directory.join(path)) {
+                info!("Loading {:?} from override directory {:?}", path, directory);
+                return Ok(data);
+            }
+        }
+        for mount in &self.mounts {
+            if let Some(data) = mount.read(path) {
+                return Ok(data);
+            }
+        }
+        let normalized_path = normalize_path(path);
+        if let Some((_, data)) = ENGINE_FILES.iter().find(|(p, _)| *p == normalized_path) {
+            return Ok(data.to_vec());
+        }
+        fs::read(path).map_err(|err| {
+            io::Error::new(
+                err.kind(),
+                format!("{:?} not found in any mount of the VirtualFileSystem", path),
+            )
+        })
+    }
+
+    pub fn read_to_string(&self, path: impl AsRef<Path>) -> io::Result<String> {
+        String::from_utf8(self.read(path)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn exists(&self, path: impl AsRef<Path>) -> bool {
+        self.read(path).is_ok()
+    }
+}
+impl Default for VirtualFileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static FILE_SYSTEM: OnceLock<VirtualFileSystem> = OnceLock::new();
+
+///The installed VirtualFileSystem or the default one
+pub fn file_system() -> &'static VirtualFileSystem {
+    FILE_SYSTEM.get_or_init(VirtualFileSystem::default)
+}
+
+pub fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    file_system().read(path)
+}
+
+pub fn read_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+    file_system().read_to_string(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archive_roundtrip() {
+        let archive = write_archive(&[
+            ("res/shader/a.wgsl".to_string(), b"a".to_vec()),
+            ("res/fonts/font.png".to_string(), vec![1, 2, 3]),
+        ]);
+        let archive = PackedArchive::from_bytes(archive).unwrap();
+        assert_eq!(archive.get(Path::new("res/shader/a.wgsl")), Some(&b"a"[..]));
+        assert_eq!(
+            archive.get(Path::new("./res/images/../fonts/font.png")),
+            Some(&[1, 2, 3][..])
+        );
+        assert_eq!(archive.get(Path::new("res/missing.png")), None);
+        assert!(PackedArchive::from_bytes(b"FPAK".to_vec()).is_err());
+    }
+
+    #[test]
+    fn override_takes_precedence() {
+        let directory = env::temp_dir().join("ferride_vfs_override_test");
+        fs::create_dir_all(directory.join("res")).unwrap();
+        fs::write(directory.join("res/a.txt"), "override").unwrap();
+        let archive = write_archive(&[
+            ("res/a.txt".to_string(), b"archive".to_vec()),
+            ("res/b.txt".to_string(), b"archive".to_vec()),
+        ]);
+        let file_system = VirtualFileSystem {
+            overrides: Vec::new(),
+            mounts: Vec::new(),
+        }
+        .with_archive(PackedArchive::from_bytes(archive).unwrap())
+        .with_override_directory(&directory);
+        assert_eq!(file_system.read_to_string("res/a.txt").unwrap(), "override");
+        assert_eq!(file_system.read_to_string("res/b.txt").unwrap(), "archive");
+        assert!(!file_system.exists("res/c.txt"));
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,202 @@
+use std::{path::PathBuf, str::FromStr};
+
+use super::{BitmapFont, BitmapGlyph};
+
+///Splits a line like `page id=0 file="a b.png"` into its tag and key value pairs
+fn split_line(line: &str) -> (&str, Vec<(&str, &str)>) {
+    let line = line.trim();
+    let (tag, mut rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let mut pairs = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        let Some((key, value)) = rest.split_once('=') else {
+            break;
+        };
+        let (value, remainder) = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => value.split_once(char::is_whitespace).unwrap_or((value, "")),
+        };
+        pairs.push((key.trim(), value));
+        rest = remainder;
+    }
+    (tag, pairs)
+}
+
+fn value<T: FromStr>(pairs: &[(&str, &str)], key: &str, line: usize) -> Result<T, String> {
+    let (_, value) = pairs
+        .iter()
+        .find(|(k, _)| *k == key)
+        .ok_or_else(|| format!("Line {}: missing '{}'", line, key))?;
+    value
+        .parse()
+        .map_err(|_| format!("Line {}: invalid {} '{}'", line, key, value))
+}
+
+fn character(id: u32, line: usize) -> Result<char, String> {
+    char::from_u32(id).ok_or_else(|| format!("Line {}: {} is no character", line, id))
+}
+
+pub(super) fn parse_text(source: &str) -> Result<BitmapFont, String> {
+    let mut font = BitmapFont::default();
+    let mut pages = Vec::new();
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let (tag, pairs) = split_line(line);
+        let int = |key| value::<i32>(&pairs, key, line_number);
+        match tag {
+            "info" => font.size = int("size")?.unsigned_abs() as u16,
+            "common" => {
+                font.line_height = int("lineHeight")? as u16;
+                font.base = int("base")? as u16;
+                font.scale_width = int("scaleW")? as u16;
+                font.scale_height = int("scaleH")? as u16;
+            }
+            "page" => {
+                let file = value::<String>(&pairs, "file", line_number)?;
+                pages.push((int("id")?, PathBuf::from(file)));
+            }
+            "char" => {
+                let glyph = BitmapGlyph {
+                    x: int("x")? as u16,
+                    y: int("y")? as u16,
+                    width: int("width")? as u16,
+                    height: int("height")? as u16,
+                    x_offset: int("xoffset")? as i16,
+                    y_offset: int("yoffset")? as i16,
+                    x_advance: int("xadvance")? as i16,
+                    page: int("page")? as u8,
+                };
+                let id = value::<u32>(&pairs, "id", line_number)?;
+                font.glyphs.insert(character(id, line_number)?, glyph);
+            }
+            "kerning" => {
+                let first = value::<u32>(&pairs, "first", line_number)?;
+                let second = value::<u32>(&pairs, "second", line_number)?;
+                font.kerning.insert(
+                    (
+                        character(first, line_number)?,
+                        character(second, line_number)?,
+                    ),
+                    int("amount")? as i16,
+                );
+            }
+            _ => {}
+        }
+    }
+    pages.sort_by_key(|(id, _)| *id);
+    font.pages = pages.into_iter().map(|(_, file)| file).collect();
+    validate(font)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .bytes
+            .get(self.position..self.position + length)
+            .ok_or_else(|| format!("Unexpected end of file at byte {}", self.position))?;
+        self.position += length;
+        Ok(bytes)
+    }
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+    fn i16(&mut self) -> Result<i16, String> {
+        Ok(i16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
+
+///Version 3 of the binary format, as written by the BMFont tool
+pub(super) fn parse_binary(bytes: &[u8]) -> Result<BitmapFont, String> {
+    let mut reader = Reader { bytes, position: 0 };
+    let header = reader.take(4)?;
+    if header != b"BMF\x03" {
+        return Err(format!("Unsupported binary version {}", header[3]));
+    }
+    let mut font = BitmapFont::default();
+    while reader.position < bytes.len() {
+        let kind = reader.u8()?;
+        let length = reader.u32()? as usize;
+        let mut block = Reader {
+            bytes: reader.take(length)?,
+            position: 0,
+        };
+        match kind {
+            1 => font.size = block.i16()?.unsigned_abs(),
+            2 => {
+                font.line_height = block.u16()?;
+                font.base = block.u16()?;
+                font.scale_width = block.u16()?;
+                font.scale_height = block.u16()?;
+            }
+            3 => {
+                font.pages = block
+                    .bytes
+                    .split(|byte| *byte == 0)
+                    .filter(|name| !name.is_empty())
+                    .map(|name| PathBuf::from(String::from_utf8_lossy(name).to_string()))
+                    .collect();
+            }
+            4 => {
+                while block.position < block.bytes.len() {
+                    let id = block.u32()?;
+                    let glyph = BitmapGlyph {
+                        x: block.u16()?,
+                        y: block.u16()?,
+                        width: block.u16()?,
+                        height: block.u16()?,
+                        x_offset: block.i16()?,
+                        y_offset: block.i16()?,
+                        x_advance: block.i16()?,
+                        page: block.u8()?,
+                    };
+                    block.u8()?;
+                    let character =
+                        char::from_u32(id).ok_or_else(|| format!("{} is no character", id))?;
+                    font.glyphs.insert(character, glyph);
+                }
+            }
+            5 => {
+                while block.position < block.bytes.len() {
+                    let (first, second) = (block.u32()?, block.u32()?);
+                    let amount = block.i16()?;
+                    if let (Some(first), Some(second)) =
+                        (char::from_u32(first), char::from_u32(second))
+                    {
+                        font.kerning.insert((first, second), amount);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    validate(font)
+}
+
+fn validate(font: BitmapFont) -> Result<BitmapFont, String> {
+    if font.pages.is_empty() {
+        return Err("The font has no pages".to_string());
+    }
+    if let Some((character, glyph)) = font
+        .glyphs
+        .iter()
+        .find(|(_, glyph)| glyph.page as usize >= font.pages.len())
+    {
+        return Err(format!(
+            "Glyph {:?} is on page {}, but there are {} pages",
+            character,
+            glyph.page,
+            font.pages.len()
+        ));
+    }
+    Ok(font)
+}
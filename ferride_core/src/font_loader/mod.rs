@@ -0,0 +1,381 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use crate::create_name_struct;
+use crate::game_engine::{SpriteSheetName, TextureCoordinates};
+
+mod bmfont;
+mod vector;
+pub use vector::VectorFont;
+
+create_name_struct!(FontName);
+
+///Drawn for characters a font has no glyph for, `?` if the font lacks it as well
+pub const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+#[derive(Debug)]
+pub enum FontError {
+    Io(String, std::io::Error),
+    ///File and what is wrong with it
+    Parse(String, String),
+}
+impl Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontError::Io(file, err) => write!(f, "Could not read {}: {}", file, err),
+            FontError::Parse(file, message) => write!(f, "Could not parse {}: {}", file, message),
+        }
+    }
+}
+impl std::error::Error for FontError {}
+
+///Where a glyph is on its page and how it is placed, in pixels of the font
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitmapGlyph {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    ///From the pen position to the left edge of the glyph
+    pub x_offset: i16,
+    ///From the top of the line to the top edge of the glyph
+    pub y_offset: i16,
+    ///How far the pen moves after the glyph
+    pub x_advance: i16,
+    pub page: u8,
+}
+
+///A glyph placed at some font size, in units relative to the pen at the top of the line
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FontGlyph {
+    pub page: u8,
+    pub uv_min: TextureCoordinates,
+    pub uv_max: TextureCoordinates,
+    pub left: f32,
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+    ///How far the pen moves after the glyph, without kerning
+    pub advance: f32,
+}
+
+///A font in the AngelCode BMFont format, text or binary
+#[derive(Debug, Clone, Default)]
+pub struct BitmapFont {
+    ///Size the font was rendered at, glyphs are scaled by `font size / size`
+    pub size: u16,
+    ///Distance between two lines
+    pub line_height: u16,
+    ///From the top of the line to the baseline
+    pub base: u16,
+    ///Size of every page
+    pub scale_width: u16,
+    pub scale_height: u16,
+    ///Image files of the pages. `load` resolves them relative to the `.fnt` file
+    pub pages: Vec<PathBuf>,
+    glyphs: HashMap<char, BitmapGlyph>,
+    kerning: HashMap<(char, char), i16>,
+}
+impl BitmapFont {
+    ///Tells text from binary files by the `BMF` magic of the latter. `file` only names the
+    ///source in errors
+    pub fn parse(file: &str, bytes: &[u8]) -> Result<Self, FontError> {
+        let result = if bytes.starts_with(b"BMF") {
+            bmfont::parse_binary(bytes)
+        } else {
+            std::str::from_utf8(bytes)
+                .map_err(|err| err.to_string())
+                .and_then(bmfont::parse_text)
+        };
+        result.map_err(|message| FontError::Parse(file.to_string(), message))
+    }
+
+    ///Reads the `.fnt` file through the vfs
+    pub fn load(path: &Path) -> Result<Self, FontError> {
+        let file = path.to_string_lossy().to_string();
+        let bytes = crate::vfs::read(path).map_err(|err| FontError::Io(file.clone(), err))?;
+        let mut font = Self::parse(&file, &bytes)?;
+        let directory = path.parent().unwrap_or(Path::new(""));
+        font.pages = font.pages.iter().map(|page| directory.join(page)).collect();
+        Ok(font)
+    }
+
+    pub fn glyph(&self, character: char) -> Option<&BitmapGlyph> {
+        self.glyphs.get(&character)
+    }
+
+    ///Falls back to `REPLACEMENT_CHARACTER` and `?`, None only if the font has neither
+    pub fn glyph_or_replacement(&self, character: char) -> Option<&BitmapGlyph> {
+        self.glyph(character)
+            .or_else(|| self.glyph(REPLACEMENT_CHARACTER))
+            .or_else(|| self.glyph('?'))
+    }
+
+    ///Added to the advance of `first` when `second` follows it
+    pub fn kerning(&self, first: char, second: char) -> i16 {
+        self.kerning.get(&(first, second)).copied().unwrap_or(0)
+    }
+
+    ///`glyph_or_replacement` scaled to `size`
+    pub fn placed_glyph(&self, character: char, size: f32) -> Option<FontGlyph> {
+        let glyph = self.glyph_or_replacement(character)?;
+        let scale = size / self.size.max(1) as f32;
+        let (width, height) = (
+            self.scale_width.max(1) as f32,
+            self.scale_height.max(1) as f32,
+        );
+        Some(FontGlyph {
+            page: glyph.page,
+            uv_min: TextureCoordinates {
+                u: glyph.x as f32 / width,
+                v: glyph.y as f32 / height,
+            },
+            uv_max: TextureCoordinates {
+                u: (glyph.x + glyph.width) as f32 / width,
+                v: (glyph.y + glyph.height) as f32 / height,
+            },
+            left: glyph.x_offset as f32 * scale,
+            top: glyph.y_offset as f32 * scale,
+            width: glyph.width as f32 * scale,
+            height: glyph.height as f32 * scale,
+            advance: glyph.x_advance as f32 * scale,
+        })
+    }
+
+    pub fn characters(&self) -> impl Iterator<Item = &char> {
+        self.glyphs.keys()
+    }
+
+    ///Name of the SpriteSheet `Game` loads a page of the font registered as `name` into
+    pub fn page_sprite_sheet(name: &FontName, page: u8) -> SpriteSheetName {
+        format!("{}#{}", name.as_str(), page).into()
+    }
+}
+
+///A bitmap or a vector font, measured in units of the font size it is drawn at
+#[derive(Debug)]
+pub enum Font {
+    Bitmap(BitmapFont),
+    Vector(VectorFont),
+}
+impl Font {
+    ///Loads `.ttf` and `.otf` files as `VectorFont`, everything else as `BitmapFont`
+    pub fn load(path: &Path) -> Result<Self, FontError> {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        match extension.to_lowercase().as_str() {
+            "ttf" | "otf" => VectorFont::load(path).map(Font::Vector),
+            _ => BitmapFont::load(path).map(Font::Bitmap),
+        }
+    }
+
+    pub fn line_height(&self, size: f32) -> f32 {
+        match self {
+            Font::Bitmap(font) => font.line_height as f32 * size / font.size.max(1) as f32,
+            Font::Vector(font) => font.line_height(size),
+        }
+    }
+
+    ///From the top of a line to the baseline
+    pub fn ascent(&self, size: f32) -> f32 {
+        match self {
+            Font::Bitmap(font) => font.base as f32 * size / font.size.max(1) as f32,
+            Font::Vector(font) => font.ascent(size),
+        }
+    }
+
+    ///Falls back to the replacement glyph of the font. `pixels_per_unit` is how many pixels of
+    ///the display a unit covers, vector fonts rasterize for it
+    pub fn glyph(&self, character: char, size: f32, pixels_per_unit: f32) -> Option<FontGlyph> {
+        match self {
+            Font::Bitmap(font) => font.placed_glyph(character, size),
+            Font::Vector(font) => Some(font.glyph(character, size, pixels_per_unit)),
+        }
+    }
+
+    ///How far the pen moves after the glyph, without kerning. Measures text without rasterizing
+    ///it
+    pub fn advance(&self, character: char, size: f32) -> f32 {
+        match self {
+            Font::Bitmap(font) => font.glyph_or_replacement(character).map_or(0.0, |glyph| {
+                glyph.x_advance as f32 * size / font.size.max(1) as f32
+            }),
+            Font::Vector(font) => font.advance(character, size),
+        }
+    }
+
+    ///Added to the advance of `first` when `second` follows it
+    pub fn kerning(&self, first: char, second: char, size: f32) -> f32 {
+        match self {
+            Font::Bitmap(font) => {
+                font.kerning(first, second) as f32 * size / font.size.max(1) as f32
+            }
+            Font::Vector(font) => font.kerning(first, second, size),
+        }
+    }
+
+    ///SpriteSheets the pages of the font registered as `name` are loaded into, indexed by
+    ///`FontGlyph::page`
+    pub fn sprite_sheets(&self, name: &FontName) -> Vec<SpriteSheetName> {
+        match self {
+            Font::Bitmap(font) => (0..font.pages.len())
+                .map(|page| BitmapFont::page_sprite_sheet(name, page as u8))
+                .collect(),
+            Font::Vector(_) => vec![VectorFont::atlas_sprite_sheet(name)],
+        }
+    }
+}
+impl From<BitmapFont> for Font {
+    fn from(font: BitmapFont) -> Self {
+        Font::Bitmap(font)
+    }
+}
+impl From<VectorFont> for Font {
+    fn from(font: VectorFont) -> Self {
+        Font::Vector(font)
+    }
+}
+
+type Fonts = RwLock<Vec<(FontName, Arc<Font>)>>;
+
+static FONTS: OnceLock<Fonts> = OnceLock::new();
+
+fn fonts() -> &'static Fonts {
+    FONTS.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+///Makes the font available by `name`, replacing an earlier font of that name. `Game` registers
+///the fonts of its `RessourceDescriptor`
+pub fn register_font(name: FontName, font: impl Into<Font>) {
+    let mut fonts = fonts().write().expect("Font lock poisoned");
+    fonts.retain(|(n, _)| *n != name);
+    fonts.push((name, Arc::new(font.into())));
+}
+
+pub fn font(name: &FontName) -> Option<Arc<Font>> {
+    fonts()
+        .read()
+        .expect("Font lock poisoned")
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, font)| font.clone())
+}
+
+///SpriteSheets of every page of the font, empty if no font is registered as `name`
+pub fn font_sprite_sheets(name: &FontName) -> Vec<SpriteSheetName> {
+    font(name).map_or(Vec::new(), |font| font.sprite_sheets(name))
+}
+
+///Every registered vector font, whose atlas `Game` keeps uploaded
+pub(crate) fn vector_fonts() -> Vec<(FontName, Arc<Font>)> {
+    fonts()
+        .read()
+        .expect("Font lock poisoned")
+        .iter()
+        .filter(|(_, font)| matches!(**font, Font::Vector(_)))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = r#"info face="Test" size=32 bold=0 italic=0 charset="" unicode=1 stretchH=100 smooth=0 aa=1 padding=0,0,0,0 spacing=1,1
+common lineHeight=36 base=28 scaleW=256 scaleH=128 pages=2 packed=0
+page id=0 file="test_0.png"
+page id=1 file="test_1.png"
+chars count=3
+char id=65   x=0     y=0     width=20    height=24    xoffset=1     yoffset=4     xadvance=22    page=0  chnl=15
+char id=86   x=20    y=0     width=20    height=24    xoffset=0     yoffset=4     xadvance=21    page=0  chnl=15
+char id=252  x=0     y=32    width=16    height=20    xoffset=2     yoffset=8     xadvance=18    page=1  chnl=15
+kernings count=1
+kerning first=65 second=86 amount=-3
+"#;
+
+    fn binary() -> Vec<u8> {
+        let mut bytes = b"BMF\x03".to_vec();
+        let mut block = |kind: u8, content: Vec<u8>| {
+            bytes.push(kind);
+            bytes.extend((content.len() as u32).to_le_bytes());
+            bytes.extend(content);
+        };
+        let mut info = 32i16.to_le_bytes().to_vec();
+        info.extend([0, 0, 100, 0, 1, 0, 0, 0, 0, 1, 1, 0]);
+        info.extend(b"Test\0");
+        block(1, info);
+        let common = [36u16, 28, 256, 128, 2]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .chain([0, 0, 0, 0, 0])
+            .collect();
+        block(2, common);
+        block(3, b"test_0.png\0test_1.png\0".to_vec());
+        let mut chars = Vec::new();
+        for (id, x, y, w, h, xo, yo, xa, page) in [
+            (65u32, 0u16, 0u16, 20u16, 24u16, 1i16, 4i16, 22i16, 0u8),
+            (86, 20, 0, 20, 24, 0, 4, 21, 0),
+            (252, 0, 32, 16, 20, 2, 8, 18, 1),
+        ] {
+            chars.extend(id.to_le_bytes());
+            [x, y, w, h]
+                .iter()
+                .for_each(|v| chars.extend(v.to_le_bytes()));
+            [xo, yo, xa]
+                .iter()
+                .for_each(|v| chars.extend(v.to_le_bytes()));
+            chars.extend([page, 15]);
+        }
+        block(4, chars);
+        let mut kerning = 65u32.to_le_bytes().to_vec();
+        kerning.extend(86u32.to_le_bytes());
+        kerning.extend((-3i16).to_le_bytes());
+        block(5, kerning);
+        bytes
+    }
+
+    #[test]
+    fn parses_text_and_binary_fonts() {
+        for (file, bytes) in [
+            ("test.fnt", TEXT.as_bytes().to_vec()),
+            ("test.bin", binary()),
+        ] {
+            let font = BitmapFont::parse(file, &bytes).unwrap();
+            assert_eq!(
+                (font.size, font.line_height, font.base),
+                (32, 36, 28),
+                "{}",
+                file
+            );
+            assert_eq!((font.scale_width, font.scale_height), (256, 128));
+            assert_eq!(
+                font.pages,
+                vec![PathBuf::from("test_0.png"), PathBuf::from("test_1.png")]
+            );
+            assert_eq!(
+                font.glyph('ü'),
+                Some(&BitmapGlyph {
+                    x: 0,
+                    y: 32,
+                    width: 16,
+                    height: 20,
+                    x_offset: 2,
+                    y_offset: 8,
+                    x_advance: 18,
+                    page: 1,
+                })
+            );
+            assert_eq!(font.kerning('A', 'V'), -3);
+            assert_eq!(font.kerning('V', 'A'), 0);
+            assert_eq!(font.glyph_or_replacement('🐸'), None);
+        }
+        assert!(matches!(
+            BitmapFont::parse("broken.fnt", b"char id=x"),
+            Err(FontError::Parse(..))
+        ));
+        assert!(BitmapFont::parse("short.bin", &binary()[..40]).is_err());
+    }
+}
@@ -0,0 +1,408 @@
+use std::{collections::HashMap, path::Path, sync::Mutex};
+
+use ab_glyph::{point, Font as _, FontArc, GlyphId, PxScale, ScaleFont};
+use log::warn;
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+
+use super::{FontError, FontGlyph, FontName, REPLACEMENT_CHARACTER};
+use crate::game_engine::{SpriteSheetName, TextureCoordinates};
+
+const INITIAL_ATLAS_SIZE: u32 = 256;
+///Largest texture every wgpu backend supports
+const MAX_ATLAS_SIZE: u32 = 4096;
+///Empty pixels around every glyph, so neighbours do not bleed into each other
+const PADDING: u32 = 1;
+
+///Where a rasterized glyph is in the atlas, relative to the pen on the baseline
+#[derive(Debug, Clone)]
+struct AtlasGlyph {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    left: f32,
+    top: f32,
+}
+
+///Pixels of an atlas that changed since it was last taken by `VectorFont::take_atlas_update`
+#[derive(Debug)]
+pub(crate) struct AtlasUpdate {
+    ///RGBA8 of the changed region, white with the coverage as alpha
+    pub pixels: Vec<u8>,
+    ///Top left corner of the changed region in the atlas
+    pub origin: PhysicalPosition<u32>,
+    ///Size of the changed region, the size of the atlas if all of it has to be uploaded
+    pub size: PhysicalSize<u32>,
+    pub atlas_size: PhysicalSize<u32>,
+    ///Glyphs moved in texture coordinates, text rendered with the old atlas has to be rendered
+    ///again
+    pub invalidated: bool,
+}
+
+///Glyphs packed into shelves, rows of glyphs as high as the highest one of them. Grows by
+///doubling its smaller side and starts over once it would outgrow `MAX_ATLAS_SIZE`
+#[derive(Debug)]
+struct GlyphAtlas {
+    size: PhysicalSize<u32>,
+    pixels: Vec<u8>,
+    shelf_x: u32,
+    shelf_y: u32,
+    shelf_height: u32,
+    ///By glyph and font size in pixels. None for glyphs without an outline, like spaces
+    glyphs: HashMap<(GlyphId, u32), Option<AtlasGlyph>>,
+    ///Left, top, right and bottom of the pixels changed since the atlas was last taken
+    dirty: Option<(u32, u32, u32, u32)>,
+    ///The atlas is new, grew or was cleared, so all of it has to be uploaded
+    reupload: bool,
+    invalidated: bool,
+}
+impl GlyphAtlas {
+    fn new() -> Self {
+        let size = PhysicalSize::new(INITIAL_ATLAS_SIZE, INITIAL_ATLAS_SIZE);
+        Self {
+            pixels: vec![0; (size.width * size.height * 4) as usize],
+            size,
+            shelf_x: 0,
+            shelf_y: 0,
+            shelf_height: 0,
+            glyphs: HashMap::new(),
+            dirty: None,
+            reupload: true,
+            invalidated: false,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.pixels.iter_mut().for_each(|pixel| *pixel = 0);
+        self.shelf_x = 0;
+        self.shelf_y = 0;
+        self.shelf_height = 0;
+        self.glyphs.clear();
+        self.reupload = true;
+        self.invalidated = true;
+    }
+
+    fn grow(&mut self) -> bool {
+        let size = if self.size.width <= self.size.height {
+            PhysicalSize::new(self.size.width * 2, self.size.height)
+        } else {
+            PhysicalSize::new(self.size.width, self.size.height * 2)
+        };
+        if size.width > MAX_ATLAS_SIZE || size.height > MAX_ATLAS_SIZE {
+            return false;
+        }
+        let mut pixels = vec![0; (size.width * size.height * 4) as usize];
+        let row = (self.size.width * 4) as usize;
+        for (y, old_row) in self.pixels.chunks_exact(row).enumerate() {
+            let start = y * (size.width * 4) as usize;
+            pixels[start..start + row].copy_from_slice(old_row);
+        }
+        self.pixels = pixels;
+        self.size = size;
+        self.reupload = true;
+        self.invalidated = true;
+        true
+    }
+
+    fn mark_dirty(&mut self, x: u32, y: u32, width: u32, height: u32) {
+        let (right, bottom) = (x + width, y + height);
+        self.dirty = Some(match self.dirty {
+            Some((left, top, old_right, old_bottom)) => (
+                left.min(x),
+                top.min(y),
+                old_right.max(right),
+                old_bottom.max(bottom),
+            ),
+            None => (x, y, right, bottom),
+        });
+    }
+
+    ///Changes since the atlas was last taken, all of it if `whole` or it has to be uploaded again
+    fn take_update(&mut self, whole: bool) -> Option<AtlasUpdate> {
+        let dirty = if whole || self.reupload {
+            Some((0, 0, self.size.width, self.size.height))
+        } else {
+            self.dirty
+        };
+        self.dirty = None;
+        self.reupload = false;
+        let (left, top, right, bottom) = dirty?;
+        let row = (self.size.width * 4) as usize;
+        let pixels = if (left, right) == (0, self.size.width) {
+            self.pixels[top as usize * row..bottom as usize * row].to_vec()
+        } else {
+            self.pixels
+                .chunks_exact(row)
+                .skip(top as usize)
+                .take((bottom - top) as usize)
+                .flat_map(|pixel_row| &pixel_row[left as usize * 4..right as usize * 4])
+                .copied()
+                .collect()
+        };
+        Some(AtlasUpdate {
+            pixels,
+            origin: PhysicalPosition::new(left, top),
+            size: PhysicalSize::new(right - left, bottom - top),
+            atlas_size: self.size,
+            invalidated: std::mem::take(&mut self.invalidated),
+        })
+    }
+
+    ///Finds room for a `width` x `height` glyph, making room if there is none
+    fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        let (padded_width, padded_height) = (width + PADDING, height + PADDING);
+        if padded_width > MAX_ATLAS_SIZE || padded_height > MAX_ATLAS_SIZE {
+            return None;
+        }
+        loop {
+            if self.shelf_x + padded_width > self.size.width {
+                self.shelf_y += self.shelf_height;
+                self.shelf_x = 0;
+                self.shelf_height = 0;
+            }
+            if self.shelf_x + padded_width <= self.size.width
+                && self.shelf_y + padded_height <= self.size.height
+            {
+                let position = (self.shelf_x, self.shelf_y);
+                self.shelf_x += padded_width;
+                self.shelf_height = self.shelf_height.max(padded_height);
+                return Some(position);
+            }
+            if !self.grow() {
+                warn!("Glyph atlas is full, rasterizing all glyphs again");
+                self.clear();
+            }
+        }
+    }
+}
+
+///A TrueType or OpenType font. Glyphs are rasterized when they are first asked for at a size
+///and cached in an atlas, that `Game` uploads as the SpriteSheet `atlas_sprite_sheet`
+#[derive(Debug)]
+pub struct VectorFont {
+    font: FontArc,
+    atlas: Mutex<GlyphAtlas>,
+}
+impl VectorFont {
+    ///`file` only names the source in errors
+    pub fn parse(file: &str, bytes: Vec<u8>) -> Result<Self, FontError> {
+        let font = FontArc::try_from_vec(bytes)
+            .map_err(|err| FontError::Parse(file.to_string(), err.to_string()))?;
+        Ok(Self {
+            font,
+            atlas: Mutex::new(GlyphAtlas::new()),
+        })
+    }
+
+    ///Reads the `.ttf` or `.otf` file through the vfs
+    pub fn load(path: &Path) -> Result<Self, FontError> {
+        let file = path.to_string_lossy().to_string();
+        let bytes = crate::vfs::read(path).map_err(|err| FontError::Io(file.clone(), err))?;
+        Self::parse(&file, bytes)
+    }
+
+    ///Name of the SpriteSheet `Game` uploads the atlas of the font registered as `name` to
+    pub fn atlas_sprite_sheet(name: &FontName) -> SpriteSheetName {
+        format!("{}#atlas", name.as_str()).into()
+    }
+
+    fn scaled(&self, pixels: f32) -> ab_glyph::PxScaleFont<&FontArc> {
+        self.font.as_scaled(PxScale::from(pixels))
+    }
+
+    ///From the top of a line to the baseline
+    pub fn ascent(&self, size: f32) -> f32 {
+        self.scaled(size).ascent()
+    }
+
+    pub fn line_height(&self, size: f32) -> f32 {
+        let font = self.scaled(size);
+        font.ascent() - font.descent() + font.line_gap()
+    }
+
+    ///Falls back to `REPLACEMENT_CHARACTER`, `?` and the `.notdef` glyph of the font
+    fn glyph_id(&self, character: char) -> GlyphId {
+        [character, REPLACEMENT_CHARACTER, '?']
+            .into_iter()
+            .map(|c| self.font.glyph_id(c))
+            .find(|id| id.0 != 0)
+            .unwrap_or(GlyphId(0))
+    }
+
+    ///How far the pen moves after the glyph, without rasterizing it
+    pub fn advance(&self, character: char, size: f32) -> f32 {
+        self.scaled(size).h_advance(self.glyph_id(character))
+    }
+
+    pub fn kerning(&self, first: char, second: char, size: f32) -> f32 {
+        self.scaled(size)
+            .kern(self.glyph_id(first), self.glyph_id(second))
+    }
+
+    ///Rasterizes the glyph at `size * pixels_per_unit` pixels, so it covers whole pixels of the
+    ///display. Everything but the texture coordinates is in units
+    pub fn glyph(&self, character: char, size: f32, pixels_per_unit: f32) -> FontGlyph {
+        let pixels = (size * pixels_per_unit).round().max(1.0);
+        let font = self.scaled(pixels);
+        let id = self.glyph_id(character);
+        let mut atlas = self.atlas.lock().expect("Glyph atlas lock poisoned");
+        let key = (id, pixels as u32);
+        if !atlas.glyphs.contains_key(&key) {
+            let rasterized = self.rasterize(&mut atlas, id, pixels);
+            atlas.glyphs.insert(key, rasterized);
+        }
+        let advance = font.h_advance(id) / pixels_per_unit;
+        let ascent = font.ascent();
+        let (width, height) = (atlas.size.width as f32, atlas.size.height as f32);
+        match &atlas.glyphs[&key] {
+            Some(glyph) => FontGlyph {
+                page: 0,
+                uv_min: TextureCoordinates {
+                    u: glyph.x as f32 / width,
+                    v: glyph.y as f32 / height,
+                },
+                uv_max: TextureCoordinates {
+                    u: (glyph.x + glyph.width) as f32 / width,
+                    v: (glyph.y + glyph.height) as f32 / height,
+                },
+                left: glyph.left / pixels_per_unit,
+                top: (ascent + glyph.top) / pixels_per_unit,
+                width: glyph.width as f32 / pixels_per_unit,
+                height: glyph.height as f32 / pixels_per_unit,
+                advance,
+            },
+            None => FontGlyph {
+                advance,
+                ..FontGlyph::default()
+            },
+        }
+    }
+
+    fn rasterize(&self, atlas: &mut GlyphAtlas, id: GlyphId, pixels: f32) -> Option<AtlasGlyph> {
+        let glyph = id.with_scale_and_position(pixels, point(0.0, 0.0));
+        let outline = self.scaled(pixels).outline_glyph(glyph)?;
+        let bounds = outline.px_bounds();
+        let (width, height) = (bounds.width() as u32, bounds.height() as u32);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let (x, y) = atlas.allocate(width, height)?;
+        let row = atlas.size.width;
+        outline.draw(|glyph_x, glyph_y, coverage| {
+            let index = (((y + glyph_y) * row + x + glyph_x) * 4) as usize;
+            if let Some(pixel) = atlas.pixels.get_mut(index..index + 4) {
+                pixel.copy_from_slice(&[255, 255, 255, (coverage.clamp(0.0, 1.0) * 255.0) as u8]);
+            }
+        });
+        atlas.mark_dirty(x, y, width, height);
+        Some(AtlasGlyph {
+            x,
+            y,
+            width,
+            height,
+            left: bounds.min.x,
+            top: bounds.min.y,
+        })
+    }
+
+    ///The pixels glyphs were rasterized into since the last call. The whole atlas if it is new,
+    ///grew or was cleared, or if asked for with `whole` because it was never uploaded
+    pub(crate) fn take_atlas_update(&self, whole: bool) -> Option<AtlasUpdate> {
+        self.atlas
+            .lock()
+            .expect("Glyph atlas lock poisoned")
+            .take_update(whole)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font_loader::Font;
+
+    #[test]
+    fn packs_grows_and_starts_over() {
+        let mut atlas = GlyphAtlas::new();
+        let mut rects: Vec<(u32, u32)> = Vec::new();
+        for _ in 0..64 {
+            let (x, y) = atlas.allocate(40, 50).unwrap();
+            assert!(x + 40 <= atlas.size.width && y + 50 <= atlas.size.height);
+            assert!(rects
+                .iter()
+                .all(|(other_x, other_y)| x.abs_diff(*other_x) > 40 || y.abs_diff(*other_y) > 50));
+            rects.push((x, y));
+        }
+        assert_eq!(atlas.size, PhysicalSize::new(512, 512));
+        assert!(atlas.invalidated);
+        assert_eq!(atlas.pixels.len(), 512 * 512 * 4);
+
+        assert_eq!(atlas.allocate(MAX_ATLAS_SIZE, 1), None);
+        while atlas.size.width < MAX_ATLAS_SIZE || atlas.size.height < MAX_ATLAS_SIZE {
+            atlas.allocate(1000, 1000);
+        }
+        atlas.glyphs.insert((GlyphId(1), 40), None);
+        for _ in 0..20 {
+            atlas.allocate(1000, 1000);
+        }
+        assert!(atlas.glyphs.is_empty());
+        assert_eq!(
+            atlas.size,
+            PhysicalSize::new(MAX_ATLAS_SIZE, MAX_ATLAS_SIZE)
+        );
+    }
+
+    #[test]
+    fn takes_only_the_changed_region() {
+        let mut atlas = GlyphAtlas::new();
+        assert_eq!(atlas.take_update(false).unwrap().size, atlas.size);
+        assert!(atlas.take_update(false).is_none());
+        let row = (atlas.size.width * 4) as usize;
+        atlas.pixels[3 * row + 2 * 4 + 3] = 7;
+        atlas.pixels[4 * row + 5 * 4 + 3] = 9;
+        atlas.mark_dirty(2, 3, 1, 1);
+        atlas.mark_dirty(5, 4, 1, 1);
+        let update = atlas.take_update(false).unwrap();
+        assert_eq!(update.origin, PhysicalPosition::new(2, 3));
+        assert_eq!(update.size, PhysicalSize::new(4, 2));
+        let alpha = update.pixels.chunks_exact(4).map(|pixel| pixel[3]);
+        assert_eq!(alpha.collect::<Vec<_>>(), [7, 0, 0, 0, 0, 0, 0, 9]);
+
+        assert_eq!(atlas.take_update(true).unwrap().size, atlas.size);
+        atlas.mark_dirty(0, 0, 1, 1);
+        assert!(atlas.grow());
+        let update = atlas.take_update(false).unwrap();
+        assert_eq!((update.size, update.atlas_size), (atlas.size, atlas.size));
+        assert_eq!(update.pixels, atlas.pixels);
+    }
+
+    #[test]
+    fn rasterizes_vector_fonts_at_any_size() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../res/fonts/DejaVuSans-Bold.ttf");
+        let bytes = std::fs::read(&path).unwrap();
+        let font = Font::from(VectorFont::parse("DejaVuSans-Bold.ttf", bytes).unwrap());
+        assert_eq!(
+            font.sprite_sheets(&"Menu".into()),
+            vec![SpriteSheetName::from("Menu#atlas")]
+        );
+        for size in [12.0, 40.0, 97.0] {
+            let ascent = font.ascent(size);
+            let a = font.glyph('A', size, 1.0).unwrap();
+            let g = font.glyph('g', size, 1.0).unwrap();
+            let space = font.glyph(' ', size, 1.0).unwrap();
+            assert!(a.height > size * 0.6 && a.height < size, "{}", size);
+            // 'A' sits on the baseline, 'g' reaches below it
+            assert!((a.top + a.height - ascent).abs() <= 1.0);
+            assert!(g.top + g.height > ascent + size * 0.1);
+            assert!(g.top + g.height <= font.line_height(size) + 1.0);
+            assert_eq!((space.width, space.height), (0.0, 0.0));
+            assert!(space.advance > 0.0);
+            assert!(font.kerning('A', 'V', size) < 0.0);
+            assert!(a.uv_min.u < a.uv_max.u && a.uv_min.v < a.uv_max.v);
+        }
+        // Sharp on a display with more pixels than units
+        let small = font.glyph('A', 40.0, 1.0).unwrap();
+        let sharp = font.glyph('A', 40.0, 2.0).unwrap();
+        assert!((small.width - sharp.width).abs() <= 1.0);
+        assert!(font.glyph('🐸', 40.0, 1.0).is_some());
+    }
+}
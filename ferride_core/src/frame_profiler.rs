@@ -0,0 +1,347 @@
+use std::{
+    fmt::Write as _,
+    fs, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use log::{info, warn};
+
+///Path the chrome trace gets written to when the application exits. Setting it enables tracing
+pub const TRACE_FILE_VARIABLE: &str = "FERRIDE_TRACE";
+///Trace events beyond this are dropped, so a long session can not eat all memory
+const MAX_TRACE_EVENTS: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stage {
+    ///`Entity::update` and the camera update
+    Update,
+    ///`Entity::render` into the cpu side buffers
+    Render,
+    ///Writing the buffers of a scene to the gpu
+    Upload,
+    ///Encoding and submitting the draw calls of a window
+    Draw,
+}
+impl Stage {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Stage::Update => "update",
+            Stage::Render => "render",
+            Stage::Upload => "upload",
+            Stage::Draw => "draw",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SceneMetrics {
+    pub name: String,
+    pub update: Duration,
+    pub render: Duration,
+    pub upload: Duration,
+    pub vertices: u32,
+    pub indices: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EntityTypeMetrics {
+    pub entity_type: String,
+    pub update: Duration,
+    pub render: Duration,
+    ///Number of updated entities of this type
+    pub count: u32,
+}
+
+///Everything measured between two timer ticks
+#[derive(Debug, Clone, Default)]
+pub struct FrameMetrics {
+    pub frame: u64,
+    pub frame_time: Duration,
+    ///Time the timer event spent in the event loop before it got handled
+    pub event_latency: Duration,
+    ///Events handled by the application, including the timer event
+    pub events: u32,
+    pub draw: Duration,
+    pub scenes: Vec<SceneMetrics>,
+    pub entity_types: Vec<EntityTypeMetrics>,
+}
+impl FrameMetrics {
+    fn scene(&mut self, name: &str) -> &mut SceneMetrics {
+        let index = match self.scenes.iter().position(|s| s.name == name) {
+            Some(index) => index,
+            None => {
+                self.scenes.push(SceneMetrics {
+                    name: name.to_string(),
+                    ..Default::default()
+                });
+                self.scenes.len() - 1
+            }
+        };
+        &mut self.scenes[index]
+    }
+
+    fn entity_type(&mut self, entity_type: &str) -> &mut EntityTypeMetrics {
+        let index = match self
+            .entity_types
+            .iter()
+            .position(|e| e.entity_type == entity_type)
+        {
+            Some(index) => index,
+            None => {
+                self.entity_types.push(EntityTypeMetrics {
+                    entity_type: entity_type.to_string(),
+                    ..Default::default()
+                });
+                self.entity_types.len() - 1
+            }
+        };
+        &mut self.entity_types[index]
+    }
+}
+
+#[derive(Debug)]
+enum TraceEvent {
+    Span {
+        name: String,
+        stage: Stage,
+        start: Duration,
+        duration: Duration,
+    },
+    Counter {
+        name: &'static str,
+        time: Duration,
+        value: u64,
+    },
+}
+
+///Collects timings per scene, entity type and stage. The metrics of the last complete frame are
+///always available, a chrome trace is only recorded if tracing is enabled
+#[derive(Debug)]
+pub struct FrameProfiler {
+    origin: Instant,
+    frame_start: Option<Instant>,
+    current: FrameMetrics,
+    last_frame: FrameMetrics,
+    tracing: bool,
+    trace: Vec<TraceEvent>,
+}
+impl FrameProfiler {
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            frame_start: None,
+            current: FrameMetrics::default(),
+            last_frame: FrameMetrics::default(),
+            tracing: std::env::var_os(TRACE_FILE_VARIABLE).is_some(),
+            trace: Vec::new(),
+        }
+    }
+
+    pub fn with_tracing(mut self, tracing: bool) -> Self {
+        self.tracing = tracing;
+        self
+    }
+
+    pub fn set_tracing(&mut self, tracing: bool) {
+        self.tracing = tracing;
+    }
+
+    ///Finishes the current frame. `sent_at` is when the timer event starting the new frame was
+    ///put into the event loop
+    pub fn begin_frame(&mut self, sent_at: Instant) {
+        let now = Instant::now();
+        let frame = self.current.frame + 1;
+        if let Some(frame_start) = self.frame_start {
+            self.current.frame_time = now - frame_start;
+            if self.tracing {
+                let time = now - self.origin;
+                let vertices = self.current.scenes.iter().map(|s| s.vertices as u64).sum();
+                let events = self.current.events as u64;
+                self.push_trace(TraceEvent::Counter {
+                    name: "vertices",
+                    time,
+                    value: vertices,
+                });
+                self.push_trace(TraceEvent::Counter {
+                    name: "events",
+                    time,
+                    value: events,
+                });
+            }
+            self.last_frame = std::mem::take(&mut self.current);
+        }
+        self.frame_start = Some(now);
+        self.current.frame = frame;
+        self.current.event_latency = now.saturating_duration_since(sent_at);
+    }
+
+    pub fn last_frame(&self) -> &FrameMetrics {
+        &self.last_frame
+    }
+
+    pub fn count_event(&mut self) {
+        self.current.events += 1;
+    }
+
+    ///Records the time from `start` until now for a scene, or a window for `Stage::Draw`
+    pub fn record_scene(&mut self, scene: &str, stage: Stage, start: Instant) {
+        let duration = start.elapsed();
+        match stage {
+            Stage::Update => self.current.scene(scene).update += duration,
+            Stage::Render => self.current.scene(scene).render += duration,
+            Stage::Upload => self.current.scene(scene).upload += duration,
+            Stage::Draw => self.current.draw += duration,
+        }
+        self.record_span(scene, stage, start, duration);
+    }
+
+    pub fn record_entity_type(&mut self, entity_type: &str, stage: Stage, duration: Duration) {
+        let metrics = self.current.entity_type(entity_type);
+        match stage {
+            Stage::Update => {
+                metrics.update += duration;
+                metrics.count += 1;
+            }
+            Stage::Render => metrics.render += duration,
+            Stage::Upload | Stage::Draw => {}
+        }
+    }
+
+    pub fn record_buffers(&mut self, scene: &str, vertices: u32, indices: u32) {
+        let metrics = self.current.scene(scene);
+        metrics.vertices = vertices;
+        metrics.indices = indices;
+    }
+
+    fn record_span(&mut self, name: &str, stage: Stage, start: Instant, duration: Duration) {
+        if self.tracing {
+            self.push_trace(TraceEvent::Span {
+                name: name.to_string(),
+                stage,
+                start: start.saturating_duration_since(self.origin),
+                duration,
+            });
+        }
+    }
+
+    fn push_trace(&mut self, event: TraceEvent) {
+        if self.trace.len() < MAX_TRACE_EVENTS {
+            self.trace.push(event);
+        } else if self.trace.len() == MAX_TRACE_EVENTS {
+            warn!(
+                "Trace is full after {} events, dropping the rest",
+                MAX_TRACE_EVENTS
+            );
+            self.trace.push(TraceEvent::Counter {
+                name: "dropped",
+                time: self.origin.elapsed(),
+                value: 1,
+            });
+        }
+    }
+
+    ///Serializes the recorded trace in the chrome trace event format, viewable in
+    ///`chrome://tracing` or perfetto
+    pub fn chrome_trace(&self) -> String {
+        let mut json = String::from("{\"traceEvents\":[");
+        for (i, event) in self.trace.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            match event {
+                TraceEvent::Span {
+                    name,
+                    stage,
+                    start,
+                    duration,
+                } => write!(
+                    json,
+                    "{{\"name\":\"{}\",\"cat\":\"{}\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":1,\"tid\":1}}",
+                    escape(name),
+                    stage.as_str(),
+                    start.as_micros(),
+                    duration.as_micros()
+                ),
+                TraceEvent::Counter { name, time, value } => write!(
+                    json,
+                    "{{\"name\":\"{}\",\"ph\":\"C\",\"ts\":{},\"pid\":1,\"args\":{{\"{}\":{}}}}}",
+                    name,
+                    time.as_micros(),
+                    name,
+                    value
+                ),
+            }
+            .expect("Writing to a String can not fail");
+        }
+        json.push_str("]}");
+        json
+    }
+
+    pub fn write_chrome_trace(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.chrome_trace())
+    }
+
+    ///Writes the trace to the path in `TRACE_FILE_VARIABLE`, if it is set
+    pub fn write_chrome_trace_from_env(&self) {
+        if let Some(path) = std::env::var_os(TRACE_FILE_VARIABLE) {
+            let path = Path::new(&path);
+            match self.write_chrome_trace(path) {
+                Ok(()) => info!("Wrote trace to {:?}", path),
+                Err(err) => warn!("Could not write trace to {:?}: {}", path, err),
+            }
+        }
+    }
+}
+
+fn escape(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                write!(escaped, "\\u{:04x}", c as u32).expect("Writing to a String can not fail")
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregates_frames_and_exports_trace() {
+        let mut profiler = FrameProfiler::new().with_tracing(true);
+        profiler.begin_frame(Instant::now());
+        let start = Instant::now();
+        profiler.record_scene("Main \"Scene\"", Stage::Update, start);
+        profiler.record_scene("Main \"Scene\"", Stage::Render, start);
+        profiler.record_entity_type("Player", Stage::Update, Duration::from_millis(2));
+        profiler.record_entity_type("Player", Stage::Update, Duration::from_millis(3));
+        profiler.record_buffers("Main \"Scene\"", 8, 12);
+        profiler.record_scene("Window", Stage::Draw, start);
+        profiler.count_event();
+        profiler.count_event();
+        assert_eq!(profiler.last_frame().frame, 0);
+
+        profiler.begin_frame(Instant::now());
+        let frame = profiler.last_frame();
+        assert_eq!(frame.frame, 1);
+        assert_eq!(frame.events, 2);
+        assert_eq!(frame.scenes.len(), 1);
+        assert_eq!(frame.scenes[0].vertices, 8);
+        assert_eq!(frame.scenes[0].indices, 12);
+        assert_eq!(frame.entity_types[0].count, 2);
+        assert_eq!(frame.entity_types[0].update, Duration::from_millis(5));
+
+        let trace = profiler.chrome_trace();
+        assert!(trace.starts_with("{\"traceEvents\":[{\"name\":\"Main \\\"Scene\\\"\""));
+        assert!(trace.contains("\"cat\":\"render\""));
+        assert!(trace.contains("\"args\":{\"vertices\":8}"));
+        assert!(trace.ends_with("]}"));
+    }
+}
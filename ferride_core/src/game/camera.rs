@@ -10,8 +10,9 @@ use winit::{
 use crate::game_engine::BoundingBox;
 
 use super::{
+    debug_overlay::CameraDebugInfo,
     entity::{EntityName, EntityType},
-    Direction, Entity, ExternalEvent, VelocityController,
+    Direction, Entity, ExternalEvent, SceneName, VelocityController,
 };
 
 pub fn static_camera(view_size: PhysicalSize<f32>) -> [[f32; 2]; 3] {
@@ -22,6 +23,12 @@ pub fn static_camera(view_size: PhysicalSize<f32>) -> [[f32; 2]; 3] {
     ]
 }
 
+///Scales the output of a camera in normalized device coordinates, see
+///`VirtualResolution::ndc_scale`
+pub(super) fn scale_camera(view: [[f32; 2]; 3], [x, y]: [f32; 2]) -> [[f32; 2]; 3] {
+    view.map(|[column_x, column_y]| [column_x * x, column_y * y])
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 struct CameraUniform {
@@ -31,17 +38,17 @@ impl From<&Camera> for CameraUniform {
     fn from(camera: &Camera) -> Self {
         let x = camera.position.x + camera.offset_position.x;
         let y = camera.position.y + camera.offset_position.y;
-        let c = Self {
-            view: [
-                [2.0 / camera.view_size.width, 0.0],
-                [0.0, 2.0 / camera.view_size.height],
-                [
-                    -2.0 * x / camera.view_size.width,
-                    -2.0 * y / camera.view_size.height,
-                ],
+        let view = [
+            [2.0 / camera.view_size.width, 0.0],
+            [0.0, 2.0 / camera.view_size.height],
+            [
+                -2.0 * x / camera.view_size.width,
+                -2.0 * y / camera.view_size.height,
             ],
-        };
-        c
+        ];
+        Self {
+            view: scale_camera(view, camera.ndc_scale),
+        }
     }
 }
 
@@ -93,6 +100,8 @@ pub struct Camera {
     decceleration_factor: f32,
     velocity: VelocityController,
     view_size: PhysicalSize<f32>,
+    ///Set from the virtual resolution of the window the scene is drawn to
+    ndc_scale: [f32; 2],
     target_entity: EntityName,
     bound_entity: Option<EntityName>,
 }
@@ -107,11 +116,16 @@ impl Camera {
                 descriptor.speed / descriptor.acceleration_steps as f32,
             ),
             view_size: descriptor.view_size,
+            ndc_scale: [1.0, 1.0],
             bound_entity: descriptor.bound_entity.clone(),
             target_entity: descriptor.target_entity.clone(),
         }
     }
 
+    pub fn set_ndc_scale(&mut self, ndc_scale: [f32; 2]) {
+        self.ndc_scale = ndc_scale;
+    }
+
     pub fn reset_offset(&mut self) {
         self.velocity.stop_movement();
         self.offset_position = Vector::scalar(0.0);
@@ -161,6 +175,52 @@ impl Camera {
         Ok(())
     }
 
+    ///Area of the world currently shown
+    pub fn view(&self) -> BoundingBox {
+        BoundingBox {
+            anchor: &self.position + &self.offset_position,
+            size: self.view_size,
+        }
+    }
+
+    ///Maps a bounding box from world space into a static view of `view_size`, the way it
+    ///currently appears on screen
+    pub fn project(&self, bounding_box: &BoundingBox, view_size: &PhysicalSize<f32>) -> BoundingBox {
+        let scale_x = view_size.width / self.view_size.width;
+        let scale_y = view_size.height / self.view_size.height;
+        let center = &self.position + &self.offset_position;
+        BoundingBox {
+            anchor: Vector::new(
+                (bounding_box.anchor.x - center.x) * scale_x,
+                (bounding_box.anchor.y - center.y) * scale_y,
+                bounding_box.anchor.z,
+            ),
+            size: PhysicalSize::new(
+                bounding_box.size.width * scale_x,
+                bounding_box.size.height * scale_y,
+            ),
+        }
+    }
+
+    pub fn debug_info<T: EntityType, E: ExternalEvent>(
+        &self,
+        scene: &SceneName,
+        entities: &[Box<dyn Entity<T, E>>],
+        view_size: &PhysicalSize<f32>,
+    ) -> CameraDebugInfo {
+        let find = |name: &EntityName| {
+            entities
+                .iter()
+                .find(|entity| entity.name() == name)
+                .map(|entity| self.project(&entity.bounding_box(), view_size))
+        };
+        CameraDebugInfo {
+            scene: scene.clone(),
+            target: find(&self.target_entity),
+            bound: self.bound_entity.as_ref().and_then(find),
+        }
+    }
+
     pub fn as_bytes(&self) -> Vec<u8> {
         let mut v = Vec::new();
         v.extend_from_slice(bytemuck::cast_slice(&CameraUniform::from(self).view));
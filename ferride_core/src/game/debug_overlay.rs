@@ -0,0 +1,112 @@
+use std::{collections::VecDeque, time::Duration};
+
+use winit::{dpi::PhysicalSize, keyboard::PhysicalKey};
+
+use crate::{frame_profiler::FrameMetrics, graphics::RenderSceneName};
+
+use super::{BoundingBox, EntityName, SceneName};
+
+///Number of frames the frame time is averaged over
+const FRAME_SAMPLES: usize = 60;
+
+///Everything the debug overlay shows for one frame. Bounding boxes are given in the
+///coordinate system of the overlay scene, which spans `view_size` around the window center
+#[derive(Debug, Clone)]
+pub struct DebugInfo {
+    pub view_size: PhysicalSize<f32>,
+    pub entities: Vec<EntityDebugInfo>,
+    pub cameras: Vec<CameraDebugInfo>,
+    ///Average time between two updates
+    pub frame_time: Duration,
+    pub fps: f32,
+    ///Timings of the last complete frame
+    pub metrics: FrameMetrics,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityDebugInfo {
+    pub scene: SceneName,
+    pub name: EntityName,
+    pub bounding_box: BoundingBox,
+    pub z: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct CameraDebugInfo {
+    pub scene: SceneName,
+    pub target: Option<BoundingBox>,
+    pub bound: Option<BoundingBox>,
+}
+
+pub(super) struct DebugOverlay {
+    pub scene: SceneName,
+    pub render_scene: RenderSceneName,
+    pub toggle_key: PhysicalKey,
+    pub view_size: PhysicalSize<f32>,
+    pub enabled: bool,
+    frame_times: VecDeque<Duration>,
+}
+impl DebugOverlay {
+    pub fn new(
+        scene: SceneName,
+        render_scene: RenderSceneName,
+        toggle_key: PhysicalKey,
+        view_size: PhysicalSize<f32>,
+    ) -> Self {
+        Self {
+            scene,
+            render_scene,
+            toggle_key,
+            view_size,
+            enabled: false,
+            frame_times: VecDeque::with_capacity(FRAME_SAMPLES),
+        }
+    }
+
+    pub fn record_frame(&mut self, delta_t: &Duration) {
+        if self.frame_times.len() == FRAME_SAMPLES {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(*delta_t);
+    }
+
+    ///Average frame time and the resulting fps over the last `FRAME_SAMPLES` frames
+    pub fn frame_statistics(&self) -> (Duration, f32) {
+        if self.frame_times.is_empty() {
+            return (Duration::ZERO, 0.0);
+        }
+        let frame_time = self.frame_times.iter().sum::<Duration>() / self.frame_times.len() as u32;
+        let fps = if frame_time.is_zero() {
+            0.0
+        } else {
+            1.0 / frame_time.as_secs_f32()
+        };
+        (frame_time, fps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::keyboard::KeyCode;
+
+    #[test]
+    fn averages_recent_frames() {
+        let mut overlay = DebugOverlay::new(
+            "Debug".into(),
+            "Debug".into(),
+            PhysicalKey::Code(KeyCode::F3),
+            PhysicalSize::new(100.0, 100.0),
+        );
+        assert_eq!(overlay.frame_statistics(), (Duration::ZERO, 0.0));
+        for _ in 0..FRAME_SAMPLES {
+            overlay.record_frame(&Duration::from_millis(100));
+        }
+        for _ in 0..FRAME_SAMPLES / 2 {
+            overlay.record_frame(&Duration::from_millis(20));
+        }
+        let (frame_time, fps) = overlay.frame_statistics();
+        assert_eq!(frame_time, Duration::from_millis(60));
+        assert!((fps - 1.0 / 0.06).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,46 @@
+///How the window of a scene maps onto the pixels of its display, see `Entity::display_scale`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayScale {
+    ///Physical pixels per logical pixel, 2.0 on a typical 4K laptop
+    pub scale_factor: f64,
+    ///Physical pixels per unit of the window's virtual resolution. Without one, units are
+    ///physical pixels
+    pub pixels_per_unit: f32,
+}
+impl DisplayScale {
+    ///Rounds a length in units, so it covers a whole number of physical pixels. Keeps bitmap
+    ///fonts and thin lines from drawing uneven pixels
+    pub fn snap(&self, length: f32) -> f32 {
+        (length * self.pixels_per_unit).round().max(1.0) / self.pixels_per_unit
+    }
+}
+impl Default for DisplayScale {
+    fn default() -> Self {
+        Self {
+            scale_factor: 1.0,
+            pixels_per_unit: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_to_physical_pixels() {
+        let laptop = DisplayScale {
+            scale_factor: 1.0,
+            pixels_per_unit: 1366.0 / 1920.0,
+        };
+        let snapped = laptop.snap(40.0);
+        assert!((snapped * laptop.pixels_per_unit - 28.0).abs() < 1e-4);
+
+        let uhd = DisplayScale {
+            scale_factor: 2.0,
+            pixels_per_unit: 2.0,
+        };
+        assert_eq!(uhd.snap(40.0), 40.0);
+        assert_eq!(uhd.snap(0.1), 0.5);
+    }
+}
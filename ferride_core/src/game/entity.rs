@@ -7,8 +7,8 @@ use threed::Vector;
 use winit::event::KeyEvent;
 
 use super::{
-    ressource_descriptor::SpriteSheetName, sprite_sheet::SpriteSheet, BoundingBox, ExternalEvent,
-    SceneName,
+    debug_overlay::DebugInfo, display_scale::DisplayScale, ressource_descriptor::SpriteSheetName,
+    sprite_sheet::SpriteSheet, BoundingBox, ExternalEvent, Light, LineSegment, SceneName,
 };
 
 create_name_struct!(EntityName);
@@ -45,6 +45,37 @@ pub trait Entity<T: EntityType, E: ExternalEvent>: Debug + Send {
         self.bounding_box().anchor
     }
     fn delete_child_entity(&mut self, _name: &EntityName) {}
+    ///Whether the entity would render differently than last time. Asked once per frame after
+    ///`update`, so implementations should reset their flag. A scene is only rendered again, if
+    ///any of its entities is dirty
+    fn is_dirty(&mut self) -> bool {
+        true
+    }
+    ///Area of the world the camera of the scene currently shows. Called after every camera update
+    ///for entities in scenes with a camera, so they can skip rendering what is not visible
+    fn camera_view(&mut self, _view: &BoundingBox) {}
+    ///Part of the virtual resolution of the scene's window that is visible, see
+    ///`WindowDescriptor::with_virtual_resolution`. Called when the scene becomes active and
+    ///whenever the window is resized, so layouts can anchor to its edges
+    fn safe_area(&mut self, _area: &BoundingBox) {}
+    ///Called when the scene becomes active and whenever the window is resized or moved to a
+    ///display with another scale factor
+    fn display_scale(&mut self, _scale: &DisplayScale) {}
+    ///Called after the language of the `Localization` changed, so localized strings can be
+    ///looked up again
+    fn language_changed(&mut self) {}
+    ///Lights the entity casts while its scene is lit, see `Game::with_lighting`. Asked every
+    ///frame after the camera update
+    fn lights(&self) -> Vec<Light> {
+        vec![]
+    }
+    ///Edges that block the lights of a lit scene
+    fn light_occluders(&self) -> Vec<LineSegment> {
+        vec![]
+    }
+    ///Only called for entities of the debug overlay scene while the overlay is shown, right
+    ///before `update`
+    fn debug_info(&mut self, _info: &DebugInfo) {}
     fn handle_event(&mut self, _event: E::EntityEvent) -> Vec<E> {
         vec![]
     }
@@ -1,13 +1,14 @@
 use std::fmt::Debug;
 use std::{
     path::{Path, PathBuf},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use crate::app::{IndexBuffer, VertexBuffer};
+use crate::audio::AudioCommand;
 use crate::graphics_provider::{RenderSceneDescriptor, UniformBufferName, Visibility};
 use crate::{
-    app::{ApplicationEvent, WindowDescriptor},
+    app::{ApplicationEvent, CursorName, WindowDescriptor},
     graphics::{RenderSceneName, ShaderDescriptor},
 };
 use winit::window::WindowId;
@@ -18,9 +19,11 @@ use super::ressource_descriptor::{SpriteSheetName, WindowName};
 
 #[derive(Debug)]
 pub enum GameEvent<E: ExternalEvent> {
-    Timer(Duration),
+    ///Time since the last timer event and when this one was sent
+    Timer(Duration, Instant),
     Resumed,
-    NewWindow(WindowId, WindowName),
+    ///Id, name and scale factor of the new window
+    NewWindow(WindowId, WindowName, f64),
     RequestNewWindow(WindowDescriptor, WindowName),
     RenderUpdate(RenderSceneName, VertexBuffer, IndexBuffer),
     NewSpriteSheet(SpriteSheetName, Option<u32>),
@@ -35,6 +38,7 @@ pub enum GameEvent<E: ExternalEvent> {
         Vec<(UniformBufferName, Vec<u8>, wgpu::ShaderStages)>,
     ),
     RequestSetVisibilityRenderScene(RenderSceneName, Visibility),
+    RequestSetCursor(WindowId, CursorName),
     External(E),
     EndGame,
 }
@@ -84,6 +88,14 @@ impl<E: ExternalEvent> ApplicationEvent for GameEvent<E> {
         }
     }
 
+    fn is_request_set_cursor<'a>(&'a self) -> Option<(&'a WindowId, &'a CursorName)> {
+        if let Self::RequestSetCursor(window, cursor) = self {
+            Some((window, cursor))
+        } else {
+            None
+        }
+    }
+
     fn is_request_new_render_scene<'a>(
         &'a self,
     ) -> Option<(
@@ -121,8 +133,8 @@ impl<E: ExternalEvent> ApplicationEvent for GameEvent<E> {
         Self::NewSpriteSheet(label.into(), id)
     }
 
-    fn new_window(id: &WindowId, name: &str) -> Self {
-        Self::NewWindow(id.clone(), name.into())
+    fn new_window(id: &WindowId, name: &str, scale_factor: f64) -> Self {
+        Self::NewWindow(id.clone(), name.into(), scale_factor)
     }
 
     fn is_quit(&self) -> bool {
@@ -144,6 +156,9 @@ pub trait ExternalEvent: Debug + Send {
     fn is_request_activate_suspended_scene<'a>(&'a self) -> Option<&'a SceneName>;
     ///Deleting a scene will remove it entirely from the game, such that it cannot be rendere again
     fn is_request_delete_scene<'a>(&'a self) -> Option<&'a SceneName>;
+    ///Switches the window to one of the cursors of its `WindowDescriptor`, animated cursors start
+    ///over
+    fn is_request_set_cursor<'a>(&'a self) -> Option<(&'a WindowName, &'a CursorName)>;
     fn is_request_new_scenes<'a>(&'a self) -> bool;
     /// Should only be called if is_request_new_scene returns true
     fn consume_scenes_request(self) -> Option<Vec<Scene<Self>>>
@@ -153,6 +168,10 @@ pub trait ExternalEvent: Debug + Send {
     where
         Self: Sized;
     fn is_update_uniform_buffer<'a>(&'a self) -> Option<(&'a UniformBufferName, &'a [u8])>;
+    fn is_audio_command<'a>(&'a self) -> Option<&'a AudioCommand>;
+    ///Switches the `Localization` to a language code like `de` and calls
+    ///`Entity::language_changed` on every entity
+    fn is_request_set_language<'a>(&'a self) -> Option<&'a str>;
     fn is_delete_entity<'a>(&'a self) -> Option<(&'a EntityName, &'a SceneName)>;
     fn is_add_entities<'a>(&'a self) -> bool;
     /// Should only be called if is_add_entities returns true
@@ -205,6 +224,11 @@ pub mod example {
         fn is_request_suspend_scene<'a>(&'a self) -> Option<&'a crate::game_engine::SceneName> {
             None
         }
+        fn is_request_set_cursor<'a>(
+            &'a self,
+        ) -> Option<(&'a WindowName, &'a crate::app::CursorName)> {
+            None
+        }
         fn is_add_entities<'a>(&'a self) -> bool {
             false
         }
@@ -257,5 +281,11 @@ pub mod example {
         ) -> Option<(&'a crate::graphics::UniformBufferName, &'a [u8])> {
             None
         }
+        fn is_audio_command<'a>(&'a self) -> Option<&'a AudioCommand> {
+            None
+        }
+        fn is_request_set_language<'a>(&'a self) -> Option<&'a str> {
+            None
+        }
     }
 }
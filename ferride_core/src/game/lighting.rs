@@ -0,0 +1,502 @@
+use std::{
+    f32::consts::{PI, TAU},
+    time::Duration,
+};
+
+use threed::Vector;
+
+use crate::{
+    app::{IndexBuffer, VertexBuffer},
+    graphics::{LightVertex, RenderSceneName, UniformBufferName},
+};
+
+use super::{BoundingBox, Entity, EntityType, ExternalEvent, SceneName};
+
+///Segments of the circle a point light is drawn with
+const LIGHT_SEGMENTS: usize = 32;
+///Largest angle one piece of the far edge of a shadow covers
+const MAX_SHADOW_STEP: f32 = PI / 3.0;
+
+#[derive(Debug, Clone)]
+pub enum LightKind {
+    Point,
+    ///Lights `angle` radians around `direction`, which is counterclockwise from the x axis
+    Cone {
+        direction: f32,
+        angle: f32,
+    },
+}
+
+///A light of a scene lit with `Game::with_lighting`. Entities return them from `Entity::lights`,
+///so a light positioned at the entity follows it
+#[derive(Debug, Clone)]
+pub struct Light {
+    pub kind: LightKind,
+    pub position: Vector<f32>,
+    ///Distance at which the light has faded out completely
+    pub radius: f32,
+    pub color: [f32; 3],
+    pub intensity: f32,
+    ///How far the intensity drops at most, between 0 and 1, and the flickers per second
+    pub flicker: Option<(f32, f32)>,
+}
+impl Light {
+    pub fn point(position: Vector<f32>, radius: f32, color: [f32; 3]) -> Self {
+        Self {
+            kind: LightKind::Point,
+            position,
+            radius,
+            color,
+            intensity: 1.0,
+            flicker: None,
+        }
+    }
+
+    pub fn cone(
+        position: Vector<f32>,
+        radius: f32,
+        color: [f32; 3],
+        direction: f32,
+        angle: f32,
+    ) -> Self {
+        Self {
+            kind: LightKind::Cone { direction, angle },
+            ..Self::point(position, radius, color)
+        }
+    }
+
+    pub fn with_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    pub fn with_flicker(mut self, amount: f32, speed: f32) -> Self {
+        self.flicker = Some((amount.clamp(0.0, 1.0), speed));
+        self
+    }
+
+    ///Intensity after `time` seconds. `seed` keeps lights with the same flicker from pulsing
+    ///in sync
+    pub fn intensity_at(&self, time: f32, seed: f32) -> f32 {
+        match self.flicker {
+            None => self.intensity,
+            Some((amount, speed)) => {
+                let t = time * speed * TAU + seed;
+                let noise = 0.5 + 0.3 * t.sin() + 0.2 * (t * 2.71 + seed * 1.3).sin();
+                self.intensity * (1.0 - amount * noise)
+            }
+        }
+    }
+
+    ///Outline of the lit area around the light and whether it wraps around. It circumscribes
+    ///the radius, so the light fades out before the edge
+    fn outline(&self) -> (Vec<[f32; 2]>, bool) {
+        let (start, sweep, closed) = match self.kind {
+            LightKind::Point => (0.0, TAU, true),
+            LightKind::Cone { direction, angle } => {
+                let angle = angle.clamp(0.0, TAU);
+                (direction - angle / 2.0, angle, angle >= TAU)
+            }
+        };
+        let segments = ((sweep / TAU * LIGHT_SEGMENTS as f32).ceil() as usize).max(1);
+        let step = sweep / segments as f32;
+        let distance = self.radius / (step / 2.0).cos();
+        let points = if closed { segments } else { segments + 1 };
+        let outline = (0..points)
+            .map(|i| {
+                let angle = start + step * i as f32;
+                [
+                    self.position.x + distance * angle.cos(),
+                    self.position.y + distance * angle.sin(),
+                ]
+            })
+            .collect();
+        (outline, closed)
+    }
+}
+
+///Edge that casts shadows
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineSegment {
+    pub start: [f32; 2],
+    pub end: [f32; 2],
+}
+impl LineSegment {
+    pub fn new(start: [f32; 2], end: [f32; 2]) -> Self {
+        Self { start, end }
+    }
+
+    ///The four sides of the box
+    pub fn from_bounding_box(bounding_box: &BoundingBox) -> Vec<Self> {
+        let (x, y) = (bounding_box.anchor.x, bounding_box.anchor.y);
+        let width = bounding_box.size.width / 2.0;
+        let height = bounding_box.size.height / 2.0;
+        let corners = [
+            [x - width, y + height],
+            [x + width, y + height],
+            [x + width, y - height],
+            [x - width, y - height],
+        ];
+        (0..4)
+            .map(|i| Self::new(corners[i], corners[(i + 1) % 4]))
+            .collect()
+    }
+
+    pub fn distance_to(&self, point: [f32; 2]) -> f32 {
+        let direction = sub(self.end, self.start);
+        let length = dot(direction, direction);
+        let t = if length == 0.0 {
+            0.0
+        } else {
+            (dot(sub(point, self.start), direction) / length).clamp(0.0, 1.0)
+        };
+        let closest = [
+            self.start[0] + direction[0] * t,
+            self.start[1] + direction[1] * t,
+        ];
+        let offset = sub(point, closest);
+        dot(offset, offset).sqrt()
+    }
+}
+
+fn sub(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn dot(a: [f32; 2], b: [f32; 2]) -> f32 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn cross(a: [f32; 2], b: [f32; 2]) -> f32 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+///Area `segment` hides from a light at `light` reaching `radius`, as counterclockwise convex
+///polygon starting with the segment and extending past the radius. None if the segment is out
+///of reach or lies on a line through the light
+pub fn shadow_polygon(
+    light: [f32; 2],
+    radius: f32,
+    segment: &LineSegment,
+) -> Option<Vec<[f32; 2]>> {
+    if segment.distance_to(light) >= radius {
+        return None;
+    }
+    let (a, b) = (sub(segment.start, light), sub(segment.end, light));
+    let (length_a, length_b) = (dot(a, a).sqrt(), dot(b, b).sqrt());
+    let winding = cross(a, b);
+    if winding.abs() <= 1e-6 * length_a * length_b {
+        return None;
+    }
+    let (a, b, length_a, length_b) = if winding > 0.0 {
+        (a, b, length_a, length_b)
+    } else {
+        (b, a, length_b, length_a)
+    };
+    let start = a[1].atan2(a[0]);
+    let sweep = cross(a, b).atan2(dot(a, b));
+    let far = 2.0 * radius;
+    let steps = ((sweep / MAX_SHADOW_STEP).ceil() as usize).max(1);
+    let project = |angle: f32, distance: f32| {
+        [
+            light[0] + distance * angle.cos(),
+            light[1] + distance * angle.sin(),
+        ]
+    };
+
+    let mut polygon = vec![
+        [light[0] + a[0], light[1] + a[1]],
+        project(start, length_a.max(far)),
+    ];
+    polygon.extend((1..steps).map(|i| project(start + sweep * i as f32 / steps as f32, far)));
+    polygon.push(project(start + sweep, length_b.max(far)));
+    polygon.push([light[0] + b[0], light[1] + b[1]]);
+    Some(polygon)
+}
+
+fn write_fan(
+    points: &[[f32; 2]],
+    closed: bool,
+    vertex: impl Fn([f32; 2]) -> LightVertex,
+    vertices: &mut VertexBuffer,
+    indices: &mut IndexBuffer,
+) {
+    let start_index = vertices.len();
+    let new_vertices = points.iter().map(|p| vertex(*p)).collect::<Vec<_>>();
+    vertices.extend_from_slice(&new_vertices);
+    let triangles = if closed {
+        points.len() - 1
+    } else {
+        points.len() - 2
+    };
+    let new_indices = (1..=triangles as u32)
+        .flat_map(|i| {
+            let next = if i as usize + 1 == points.len() {
+                1
+            } else {
+                i + 1
+            };
+            [start_index, start_index + i, start_index + next]
+        })
+        .collect::<Vec<_>>();
+    indices.extend_from_slice(&new_indices);
+}
+
+///Writes the light map of one frame: the `ambient` light over `view`, then every light after
+///its shadows. Each light lies a bit deeper than the one before, so the depth test lets its
+///shadows cover only the light itself
+pub fn write_light_map(
+    ambient: [f32; 3],
+    view: &BoundingBox,
+    lights: &[Light],
+    occluders: &[LineSegment],
+    time: f32,
+    vertices: &mut VertexBuffer,
+    indices: &mut IndexBuffer,
+) {
+    let depth_step = 1.0 / (2 * lights.len() + 2) as f32;
+    let ambient_color = [ambient[0], ambient[1], ambient[2], 1.0];
+    let view_outline = LineSegment::from_bounding_box(view)
+        .into_iter()
+        .map(|side| side.start)
+        .collect::<Vec<_>>();
+    write_fan(
+        &view_outline,
+        false,
+        |position| LightVertex {
+            position,
+            center: position,
+            color: ambient_color,
+            radius: 0.0,
+            depth: 0.0,
+        },
+        vertices,
+        indices,
+    );
+
+    for (i, light) in lights.iter().enumerate() {
+        let center = [light.position.x, light.position.y];
+        let light_depth = depth_step * (2 * i + 1) as f32;
+        let shadow_depth = light_depth + depth_step;
+        for shadow in occluders
+            .iter()
+            .filter_map(|occluder| shadow_polygon(center, light.radius, occluder))
+        {
+            write_fan(
+                &shadow,
+                false,
+                |position| LightVertex {
+                    position,
+                    center,
+                    color: [0.0; 4],
+                    radius: 0.0,
+                    depth: shadow_depth,
+                },
+                vertices,
+                indices,
+            );
+        }
+
+        let intensity = light.intensity_at(time, i as f32 * 7.31);
+        let color = [
+            light.color[0] * intensity,
+            light.color[1] * intensity,
+            light.color[2] * intensity,
+            1.0,
+        ];
+        let (mut outline, closed) = light.outline();
+        outline.insert(0, center);
+        write_fan(
+            &outline,
+            closed,
+            |position| LightVertex {
+                position,
+                center,
+                color,
+                radius: light.radius,
+                depth: light_depth,
+            },
+            vertices,
+            indices,
+        );
+    }
+}
+
+///Lighting of one scene, drawn into its own RenderScene of kind `RenderSceneKind::Lighting`
+pub(super) struct SceneLighting {
+    pub scene: SceneName,
+    pub render_scene: RenderSceneName,
+    pub camera: UniformBufferName,
+    pub ambient: [f32; 3],
+    time: Duration,
+    ///Light map of the last `render`, kept to reuse the allocations
+    pub vertices: VertexBuffer,
+    pub indices: IndexBuffer,
+}
+impl SceneLighting {
+    pub fn new(scene: SceneName, render_scene: RenderSceneName, ambient: [f32; 3]) -> Self {
+        Self {
+            camera: format!("{:?} camera", render_scene.as_str()).into(),
+            scene,
+            render_scene,
+            ambient,
+            time: Duration::ZERO,
+            vertices: VertexBuffer::new(),
+            indices: IndexBuffer::new(),
+        }
+    }
+
+    ///Collects the lights and occluders of all entities of the scene into `vertices` and
+    ///`indices`
+    pub fn render<T: EntityType, E: ExternalEvent>(
+        &mut self,
+        delta_t: &Duration,
+        view: &BoundingBox,
+        entities: &[Box<dyn Entity<T, E>>],
+    ) {
+        self.time += *delta_t;
+        self.vertices.clear();
+        self.indices.clear();
+        let lights = entities
+            .iter()
+            .flat_map(|entity| entity.lights())
+            .filter(|light| light.radius > 0.0)
+            .collect::<Vec<_>>();
+        let occluders = entities
+            .iter()
+            .flat_map(|entity| entity.light_occluders())
+            .collect::<Vec<_>>();
+        write_light_map(
+            self.ambient,
+            view,
+            &lights,
+            &occluders,
+            self.time.as_secs_f32(),
+            &mut self.vertices,
+            &mut self.indices,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics_provider::BufferWriter;
+    use winit::dpi::PhysicalSize;
+
+    ///Whether `point` lies inside the counterclockwise convex `polygon`
+    fn contains(polygon: &[[f32; 2]], point: [f32; 2]) -> bool {
+        (0..polygon.len()).all(|i| {
+            let edge = sub(polygon[(i + 1) % polygon.len()], polygon[i]);
+            cross(edge, sub(point, polygon[i])) >= 0.0
+        })
+    }
+
+    #[test]
+    fn shadows_cover_what_is_behind_a_wall() {
+        let wall = LineSegment::new([10.0, -5.0], [10.0, 5.0]);
+        let shadow = shadow_polygon([0.0, 0.0], 50.0, &wall).unwrap();
+        assert_eq!(shadow.first(), Some(&[10.0, -5.0]));
+        assert_eq!(shadow.last(), Some(&[10.0, 5.0]));
+        assert!(contains(&shadow, [20.0, 0.0]));
+        assert!(contains(&shadow, [49.0, 9.0]));
+        assert!(!contains(&shadow, [5.0, 0.0]));
+        assert!(!contains(&shadow, [20.0, 15.0]));
+
+        // The reversed wall casts the same shadow
+        let reversed = LineSegment::new(wall.end, wall.start);
+        assert_eq!(shadow_polygon([0.0, 0.0], 50.0, &reversed), Some(shadow));
+
+        // Walls right next to the light still cover the whole circle behind them
+        let close = shadow_polygon(
+            [0.0, 0.0],
+            50.0,
+            &LineSegment::new([1.0, -50.0], [1.0, 50.0]),
+        )
+        .unwrap();
+        assert!(close.len() > 4);
+        for angle in [-1.4_f32, -0.7, 0.0, 0.7, 1.4] {
+            assert!(contains(&close, [49.0 * angle.cos(), 49.0 * angle.sin()]));
+        }
+
+        assert_eq!(shadow_polygon([0.0, 0.0], 5.0, &wall), None);
+        let through_light = LineSegment::new([5.0, 0.0], [10.0, 0.0]);
+        assert_eq!(shadow_polygon([0.0, 0.0], 50.0, &through_light), None);
+    }
+
+    #[test]
+    fn light_map_orders_lights_by_depth() {
+        let view = BoundingBox {
+            anchor: Vector::new(0.0, 0.0, 0.0),
+            size: PhysicalSize::new(200.0, 100.0),
+        };
+        let lights = [
+            Light::point(Vector::new(0.0, 0.0, 0.0), 50.0, [1.0, 0.8, 0.6]),
+            Light::cone(
+                Vector::new(30.0, 0.0, 0.0),
+                40.0,
+                [1.0, 1.0, 1.0],
+                0.0,
+                PI / 2.0,
+            )
+            .with_intensity(0.5),
+        ];
+        let occluders = LineSegment::from_bounding_box(&BoundingBox {
+            anchor: Vector::new(-20.0, 0.0, 0.0),
+            size: PhysicalSize::new(4.0, 4.0),
+        });
+        let mut vertices = VertexBuffer::new();
+        let mut indices = IndexBuffer::new();
+        write_light_map(
+            [0.1, 0.1, 0.2],
+            &view,
+            &lights,
+            &occluders,
+            0.0,
+            &mut vertices,
+            &mut indices,
+        );
+        let vertices: &[LightVertex] = bytemuck::cast_slice(vertices.buffer_data().unwrap());
+        assert_eq!(vertices[0].position, [-100.0, 50.0]);
+        assert_eq!(vertices[0].color, [0.1, 0.1, 0.2, 1.0]);
+
+        // Every side of the box casts a shadow from the first light, the cone cannot reach it
+        let shadows = vertices
+            .iter()
+            .filter(|v| v.color == [0.0; 4])
+            .collect::<Vec<_>>();
+        assert_eq!(shadows.len(), 4 * 4);
+        assert!(shadows.iter().all(|v| v.depth == 2.0 / 6.0));
+        let first = vertices
+            .iter()
+            .filter(|v| v.color[0] == 1.0)
+            .collect::<Vec<_>>();
+        assert_eq!(first.len(), LIGHT_SEGMENTS + 1);
+        assert!(first.iter().all(|v| v.depth == 1.0 / 6.0));
+        let second = vertices
+            .iter()
+            .filter(|v| v.color[0] == 0.5)
+            .collect::<Vec<_>>();
+        assert_eq!(second.len(), LIGHT_SEGMENTS / 4 + 2);
+        assert!(second
+            .iter()
+            .all(|v| (v.depth - 0.5).abs() < 1e-6 && v.radius == 40.0));
+        assert_eq!(
+            indices.len() as usize,
+            3 * (2 + 4 * 2 + LIGHT_SEGMENTS + LIGHT_SEGMENTS / 4)
+        );
+    }
+
+    #[test]
+    fn flicker_stays_in_range() {
+        let light = Light::point(Vector::scalar(0.0), 10.0, [1.0; 3]).with_flicker(0.4, 3.0);
+        for i in 0..100 {
+            let intensity = light.intensity_at(i as f32 * 0.037, 1.0);
+            assert!(intensity >= 0.6 - 1e-6 && intensity <= 1.0 + 1e-6);
+        }
+        assert_eq!(
+            Light::point(Vector::scalar(0.0), 10.0, [1.0; 3]).intensity_at(3.0, 0.0),
+            1.0
+        );
+    }
+}
@@ -5,7 +5,12 @@ use std::{
 
 use crate::{
     app::{IndexBuffer, VertexBuffer},
-    graphics_provider::ShaderDescriptor,
+    audio_manager::{AudioBackend, AudioManager, SoundName},
+    font_loader::{register_font, vector_fonts, Font, VectorFont},
+    frame_profiler::{FrameMetrics, Stage},
+    graphics_provider::{
+        RenderSceneDescriptor, ShaderDescriptor, VirtualResolution, Visibility, LIGHTING_SHADER,
+    },
 };
 
 use super::{
@@ -13,15 +18,29 @@ use super::{
     graphics::{GraphicsProvider, RenderSceneName, UniformBufferName},
 };
 use log::{info, warn};
-use winit::{dpi::PhysicalSize, event::WindowEvent, window::WindowId};
+use threed::Vector;
+use winit::{
+    dpi::PhysicalSize,
+    event::{ElementState, WindowEvent},
+    keyboard::{KeyCode, PhysicalKey},
+    window::WindowId,
+};
 
-use self::camera::Camera;
+use self::{
+    camera::{scale_camera, Camera},
+    debug_overlay::DebugOverlay,
+    lighting::SceneLighting,
+};
 pub use self::{
     bounding_box::BoundingBox,
     camera::static_camera,
     camera::CameraDescriptor,
+    debug_overlay::{CameraDebugInfo, DebugInfo, EntityDebugInfo},
+    display_scale::DisplayScale,
     entity::{Entity, EntityName, EntityType},
     game_event::{ExternalEvent, GameEvent},
+    lighting::{shadow_polygon, write_light_map, Light, LightKind, LineSegment},
+    particle_emitter::{EmissionMode, EmissionShape, ParticleEmitter, ParticleVertex},
     ressource_descriptor::{
         RessourceDescriptor, RessourceDescriptorBuilder, SpriteSheetName, WindowName,
     },
@@ -94,8 +113,12 @@ pub mod example {
 
 mod bounding_box;
 mod camera;
+mod debug_overlay;
+mod display_scale;
 mod entity;
 mod game_event;
+mod lighting;
+mod particle_emitter;
 mod ressource_descriptor;
 mod scene;
 mod sprite_sheet;
@@ -113,14 +136,40 @@ pub struct Game<E: ExternalEvent, S: State<E>> {
     suspended_scenes: Vec<Scene<E>>,
     window_ids: Vec<(WindowName, WindowId)>,
     window_sizes: Vec<(WindowId, PhysicalSize<u32>)>,
+    scale_factors: Vec<(WindowId, f64)>,
+    ///Windows whose cameras, safe areas and display scales have to follow their new size or
+    ///scale factor on the next update
+    resized_windows: Vec<WindowId>,
     sprite_sheets: Vec<(SpriteSheetName, SpriteSheet)>,
     cameras: Vec<(SceneName, Camera, UniformBufferName)>,
+    ///Buffers of the last render of every scene and whether the scene has to be rendered again
+    render_caches: Vec<(SceneName, VertexBuffer, IndexBuffer, bool)>,
+    debug_overlay: Option<DebugOverlay>,
+    lighting: Vec<SceneLighting>,
+    audio: AudioManager,
+    scene_music: Vec<(SceneName, SoundName, Duration)>,
     target_fps: u8,
     state: S,
 }
 impl<E: ExternalEvent, S: State<E>> Game<E, S> {
-    pub fn new(ressources: RessourceDescriptor, target_fps: u8, state: S) -> Self {
+    pub fn new(mut ressources: RessourceDescriptor, target_fps: u8, state: S) -> Self {
+        for (name, path) in ressources.fonts.clone() {
+            let font = Font::load(&path)
+                .unwrap_or_else(|err| panic!("Could not load font {:?}: {}", name, err));
+            if let Font::Bitmap(bitmap) = &font {
+                let pages = font.sprite_sheets(&name).into_iter().zip(&bitmap.pages);
+                for (sprite_sheet, page_path) in pages {
+                    ressources.sprite_sheets.push((
+                        sprite_sheet,
+                        page_path.clone(),
+                        SpriteSheetDimensions::new(1, 1),
+                    ));
+                }
+            }
+            register_font(name, font);
+        }
         let (initial_scenes, state) = state.start_scenes();
+        let audio = AudioManager::with_default_backend(ressources.sounds.clone());
         Self {
             ressources,
             pending_scenes: initial_scenes,
@@ -128,13 +177,134 @@ impl<E: ExternalEvent, S: State<E>> Game<E, S> {
             suspended_scenes: Vec::new(),
             window_ids: Vec::new(),
             window_sizes: Vec::new(),
+            scale_factors: Vec::new(),
+            resized_windows: Vec::new(),
             sprite_sheets: Vec::new(),
             cameras: Vec::new(),
+            render_caches: Vec::new(),
+            debug_overlay: None,
+            lighting: Vec::new(),
+            audio,
+            scene_music: Vec::new(),
             target_fps,
             state,
         }
     }
 
+    ///Adds a scene that is hidden until `toggle_key` is pressed. While shown, its entities get
+    ///the bounding boxes of all other active scenes, the camera boxes and the frame rate through
+    ///`Entity::debug_info`. `view_size` has to match the static camera the scene is rendered with
+    pub fn with_debug_overlay(
+        mut self,
+        scene: Scene<E>,
+        toggle_key: KeyCode,
+        view_size: PhysicalSize<f32>,
+    ) -> Self {
+        self.debug_overlay = Some(DebugOverlay::new(
+            scene.name.clone(),
+            scene.render_scene.clone(),
+            PhysicalKey::Code(toggle_key),
+            view_size,
+        ));
+        self.pending_scenes.push(scene);
+        self
+    }
+
+    ///Lights `scene` with the `Entity::lights` of its entities, blocked by their
+    ///`Entity::light_occluders`. The lights are drawn into `render_scene`, which is created
+    ///right after the scene and darkens everything drawn before it. `ambient` is the light where
+    ///no light reaches, black for complete darkness. The scene needs a camera
+    pub fn with_lighting(
+        mut self,
+        scene: SceneName,
+        render_scene: RenderSceneName,
+        ambient: [f32; 3],
+    ) -> Self {
+        self.lighting.push(SceneLighting::new(scene, render_scene, ambient));
+        self
+    }
+
+    ///Plays the sounds somewhere else than the default output device, like a `RecordingBackend`
+    ///in tests
+    pub fn with_audio_backend(mut self, backend: Box<dyn AudioBackend>) -> Self {
+        self.audio.set_backend(backend);
+        self
+    }
+
+    ///Plays `track` while `scene` is the topmost active scene with music, crossfading from the
+    ///music before. Suspending the scene keeps the track's position, so it resumes from there
+    pub fn with_scene_music(
+        mut self,
+        scene: SceneName,
+        track: SoundName,
+        crossfade: Duration,
+    ) -> Self {
+        self.scene_music.push((scene, track, crossfade));
+        self
+    }
+
+    fn update_scene_music(&mut self) {
+        let music = self.active_scenes.iter().rev().find_map(|scene| {
+            self.scene_music
+                .iter()
+                .find(|(music_scene, _, _)| *music_scene == scene.name)
+        });
+        if let Some((_, track, crossfade)) = music {
+            self.audio.play_music(track, *crossfade);
+        }
+    }
+
+    fn debug_info(&self, overlay: &DebugOverlay, metrics: &FrameMetrics) -> DebugInfo {
+        let mut entities = Vec::new();
+        let mut cameras = Vec::new();
+        for scene in self
+            .active_scenes
+            .iter()
+            .filter(|scene| scene.name != overlay.scene)
+        {
+            let camera = self
+                .cameras
+                .iter()
+                .find(|(n, _, _)| n == &scene.name)
+                .map(|(_, camera, _)| camera);
+            for entity in scene.entities.iter() {
+                let bounding_box = entity.bounding_box();
+                entities.push(EntityDebugInfo {
+                    scene: scene.name.clone(),
+                    name: entity.name().clone(),
+                    bounding_box: match camera {
+                        Some(camera) => camera.project(&bounding_box, &overlay.view_size),
+                        None => bounding_box,
+                    },
+                    z: entity.z(),
+                });
+            }
+            if let Some(camera) = camera {
+                cameras.push(camera.debug_info(&scene.name, &scene.entities, &overlay.view_size));
+            }
+        }
+        let (frame_time, fps) = overlay.frame_statistics();
+        DebugInfo {
+            view_size: overlay.view_size,
+            entities,
+            cameras,
+            frame_time,
+            fps,
+            metrics: metrics.clone(),
+        }
+    }
+
+    ///Takes the caches instead of `self`, so scenes can stay borrowed while they are marked
+    fn mark_dirty(
+        render_caches: &mut [(SceneName, VertexBuffer, IndexBuffer, bool)],
+        scene: &SceneName,
+    ) {
+        if let Some((_, _, _, is_dirty)) = render_caches.iter_mut().find(|(n, _, _, _)| n == scene)
+        {
+            *is_dirty = true;
+        }
+    }
+
     fn activate_scenes(&mut self, window_manager: &mut WindowManager<GameEvent<E>>) {
         let mut needed_windows = Vec::new();
         let mut scenes_to_discard = Vec::new();
@@ -204,8 +374,10 @@ impl<E: ExternalEvent, S: State<E>> Game<E, S> {
                 .uniforms
                 .iter()
                 .map(|name| {
-                    self.ressources
-                        .get_uniform(&(*name).into())
+                    let name = (*name).into();
+                    self.static_camera(&name)
+                        .map(|bytes| (name.clone(), bytes, wgpu::ShaderStages::VERTEX))
+                        .or_else(|| self.ressources.get_uniform(&name))
                         .expect(&format!(
                             "Did not specify UniformBuffer {:?} in RessourceDescriptor",
                             name
@@ -213,7 +385,10 @@ impl<E: ExternalEvent, S: State<E>> Game<E, S> {
                 })
                 .collect();
         if let Some(camera_descriptor) = camera {
-            let camera: Camera = (&camera_descriptor).into();
+            let mut camera: Camera = (&camera_descriptor).into();
+            if let Some(window) = self.get_window_name(target_window) {
+                camera.set_ndc_scale(self.ndc_scale(window));
+            }
             let uniform_name = &format!("{:?} camera", render_scene.as_str());
             let bytes = camera.as_bytes();
             self.cameras
@@ -227,6 +402,29 @@ impl<E: ExternalEvent, S: State<E>> Game<E, S> {
             render_scene_descriptor,
             uniform_buffers,
         ));
+        if let Some(lighting) = self.lighting.iter().find(|l| l.scene == scene) {
+            let (_, camera, _) = self
+                .cameras
+                .iter()
+                .find(|(n, _, _)| n == &scene)
+                .expect(&format!("Lit scene {:?} has no camera", scene));
+            window_manager.send_event(GameEvent::RequestNewRenderScene(
+                target_window.clone(),
+                lighting.render_scene.clone(),
+                ShaderDescriptor {
+                    file: LIGHTING_SHADER,
+                    vertex_shader: "vs_main",
+                    fragment_shader: "fs_main",
+                    uniforms: &[],
+                },
+                RenderSceneDescriptor::lighting(),
+                vec![(
+                    lighting.camera.clone(),
+                    camera.as_bytes(),
+                    wgpu::ShaderStages::VERTEX,
+                )],
+            ));
+        }
     }
 
     fn request_sprite_sheet(
@@ -234,16 +432,203 @@ impl<E: ExternalEvent, S: State<E>> Game<E, S> {
         name: &SpriteSheetName,
         window_manager: &mut WindowManager<GameEvent<E>>,
     ) {
+        // Atlases of vector fonts are no files, `upload_font_atlases` creates them
+        if vector_fonts()
+            .iter()
+            .any(|(font, _)| VectorFont::atlas_sprite_sheet(font) == *name)
+        {
+            return;
+        }
         let path = &self.ressources.get_sprite_sheet(&name).0;
         window_manager.send_event(GameEvent::RequestNewSpriteSheet(name.clone(), path.clone()));
     }
 
+    ///Uploads the pixels vector fonts rasterized glyphs into since the last upload. Scenes are
+    ///rendered again once an atlas is new or its glyphs moved
+    fn upload_font_atlases(&mut self, graphics_provider: &mut GraphicsProvider) {
+        for (name, font) in vector_fonts() {
+            let Font::Vector(font) = &*font else {
+                continue;
+            };
+            let label = VectorFont::atlas_sprite_sheet(&name);
+            let is_new = !self.sprite_sheets.iter().any(|(l, _)| *l == label);
+            let Some(update) = font.take_atlas_update(is_new) else {
+                continue;
+            };
+            let id = if update.size == update.atlas_size {
+                graphics_provider.write_texture(label.as_str(), &update.pixels, update.size)
+            } else {
+                graphics_provider.write_texture_region(
+                    label.as_str(),
+                    &update.pixels,
+                    update.origin,
+                    update.size,
+                )
+            };
+            let Some(id) = id else {
+                warn!("Could not upload the glyph atlas of {:?}", name);
+                continue;
+            };
+            if is_new {
+                let sprite_sheet = SpriteSheet::new(id, &SpriteSheetDimensions::new(1, 1));
+                self.sprite_sheets.push((label, sprite_sheet));
+            }
+            if is_new || update.invalidated {
+                self.render_caches
+                    .iter_mut()
+                    .for_each(|(_, _, _, is_dirty)| *is_dirty = true);
+            }
+        }
+    }
+
     fn get_window_name(&self, id: &WindowId) -> Option<&WindowName> {
         self.window_ids
             .iter()
             .find(|(_, i)| i == id)
             .map(|(name, _)| name)
     }
+
+    ///Virtual resolution of the window with its current size, which is the virtual resolution
+    ///itself until the window reports a size
+    fn virtual_resolution(
+        &self,
+        window: &WindowName,
+    ) -> Option<(VirtualResolution, PhysicalSize<u32>)> {
+        let virtual_resolution = self
+            .ressources
+            .windows
+            .iter()
+            .find(|(name, _)| name == window)
+            .and_then(|(_, descriptor)| descriptor.virtual_resolution())?;
+        let size = self
+            .window_ids
+            .iter()
+            .find(|(name, _)| name == window)
+            .and_then(|(_, id)| self.window_sizes.iter().find(|(i, _)| i == id))
+            .map(|(_, size)| *size)
+            .unwrap_or(virtual_resolution.size.cast());
+        Some((virtual_resolution, size))
+    }
+
+    fn ndc_scale(&self, window: &WindowName) -> [f32; 2] {
+        self.virtual_resolution(window)
+            .map(|(virtual_resolution, size)| virtual_resolution.ndc_scale(&size))
+            .unwrap_or([1.0, 1.0])
+    }
+
+    fn safe_area(&self, window: &WindowName) -> Option<BoundingBox> {
+        self.virtual_resolution(window)
+            .map(|(virtual_resolution, size)| BoundingBox {
+                anchor: Vector::scalar(0.0),
+                size: virtual_resolution.safe_area(&size),
+            })
+    }
+
+    fn display_scale(&self, window: &WindowName) -> DisplayScale {
+        let scale_factor = self
+            .window_ids
+            .iter()
+            .find(|(name, _)| name == window)
+            .and_then(|(_, id)| self.scale_factors.iter().find(|(i, _)| i == id))
+            .map(|(_, scale_factor)| *scale_factor)
+            .unwrap_or(1.0);
+        let pixels_per_unit = self
+            .virtual_resolution(window)
+            .map(|(virtual_resolution, size)| {
+                let (x, y) = virtual_resolution.scale(&size);
+                x.min(y)
+            })
+            .unwrap_or(1.0);
+        DisplayScale {
+            scale_factor,
+            pixels_per_unit,
+        }
+    }
+
+    ///Content of a static camera uniform, scaled to the virtual resolution of its window
+    fn static_camera(&self, name: &UniformBufferName) -> Option<Vec<u8>> {
+        self.ressources
+            .static_cameras
+            .iter()
+            .find(|(n, _, _)| n == name)
+            .map(|(_, window, view_size)| {
+                let view = scale_camera(static_camera(*view_size), self.ndc_scale(window));
+                bytemuck::cast_slice(&view).to_vec()
+            })
+    }
+
+    ///Switches the language of the installed `Localization` and lets every entity look up its
+    ///strings again
+    fn set_language(&mut self, language: &str) {
+        info!("Switching language to {:?}", language);
+        crate::localization::set_language(language);
+        for scene in self
+            .active_scenes
+            .iter_mut()
+            .chain(self.suspended_scenes.iter_mut())
+            .chain(self.pending_scenes.iter_mut())
+        {
+            scene
+                .entities
+                .iter_mut()
+                .for_each(|entity| entity.language_changed());
+        }
+        self.render_caches
+            .iter_mut()
+            .for_each(|(_, _, _, is_dirty)| *is_dirty = true);
+    }
+
+    ///Scales the cameras of the window to its new size and tells the entities drawn to it their
+    ///new safe area and display scale
+    fn apply_virtual_resolution(
+        &mut self,
+        id: &WindowId,
+        graphics_provider: &mut GraphicsProvider,
+    ) {
+        let Some(window) = self.get_window_name(id).cloned() else {
+            return;
+        };
+        let display_scale = self.display_scale(&window);
+        for scene in self
+            .active_scenes
+            .iter_mut()
+            .chain(self.suspended_scenes.iter_mut())
+            .filter(|scene| scene.target_window == window)
+        {
+            scene
+                .entities
+                .iter_mut()
+                .for_each(|entity| entity.display_scale(&display_scale));
+        }
+        let Some(safe_area) = self.safe_area(&window) else {
+            return;
+        };
+        let ndc_scale = self.ndc_scale(&window);
+        for (name, _, _) in self
+            .ressources
+            .static_cameras
+            .iter()
+            .filter(|(_, w, _)| w == &window)
+        {
+            let bytes = self.static_camera(name).expect("Static camera vanished");
+            graphics_provider.update_uniform_buffer(name, &bytes);
+        }
+        for scene in self
+            .active_scenes
+            .iter_mut()
+            .chain(self.suspended_scenes.iter_mut())
+            .filter(|scene| scene.target_window == window)
+        {
+            if let Some((_, camera, _)) = self.cameras.iter_mut().find(|(n, _, _)| n == &scene.name)
+            {
+                camera.set_ndc_scale(ndc_scale);
+            }
+            scene
+                .entities
+                .iter_mut()
+                .for_each(|entity| entity.safe_area(&safe_area));
+        }
+    }
 }
 impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Game<E, S> {
     fn window_event(
@@ -264,8 +649,38 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                 } else {
                     self.window_sizes.push((id.clone(), *size));
                 }
+                if !self.resized_windows.contains(id) {
+                    self.resized_windows.push(id.clone());
+                }
+            }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                match self.scale_factors.iter_mut().find(|(i, _)| i == id) {
+                    Some((_, s)) => *s = *scale_factor,
+                    None => self.scale_factors.push((id.clone(), *scale_factor)),
+                }
+                if !self.resized_windows.contains(id) {
+                    self.resized_windows.push(id.clone());
+                }
             }
             WindowEvent::KeyboardInput { event, .. } => {
+                if let Some(overlay) = &mut self.debug_overlay {
+                    if event.state == ElementState::Pressed
+                        && !event.repeat
+                        && event.physical_key == overlay.toggle_key
+                    {
+                        overlay.enabled = !overlay.enabled;
+                        let visibility = if overlay.enabled {
+                            Visibility::Visible
+                        } else {
+                            Visibility::Hidden
+                        };
+                        window_manager.send_event(GameEvent::RequestSetVisibilityRenderScene(
+                            overlay.render_scene.clone(),
+                            visibility,
+                        ));
+                        return true;
+                    }
+                }
                 match self.get_window_name(id) {
                     Some(window_name) => {
                         let window_name = window_name.clone();
@@ -314,7 +729,9 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                 thread::spawn(move || {
                     let mut last_update = Instant::now();
                     loop {
-                        match timer_event_loop.send_event(GameEvent::Timer(last_update.elapsed())) {
+                        match timer_event_loop
+                            .send_event(GameEvent::Timer(last_update.elapsed(), Instant::now()))
+                        {
                             Ok(()) => {}
                             Err(_) => break,
                         };
@@ -323,8 +740,9 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                     }
                 });
             }
-            GameEvent::NewWindow(id, name) => {
+            GameEvent::NewWindow(id, name, scale_factor) => {
                 self.window_ids.push((name.clone(), id.clone()));
+                self.scale_factors.push((id.clone(), scale_factor));
                 for i in 0..self.pending_scenes.len() {
                     let scene = &self.pending_scenes[i];
                     if scene.target_window == name {
@@ -339,6 +757,12 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                 }
             }
             GameEvent::NewRenderScene(render_scene) => {
+                if self.lighting.iter().any(|l| l.render_scene == render_scene) {
+                    if let Some(overlay) = &self.debug_overlay {
+                        graphics_provider.bring_render_scene_to_front(&overlay.render_scene);
+                    }
+                    return;
+                }
                 let index = self
                     .pending_scenes
                     .iter()
@@ -352,7 +776,29 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                 {
                     self.request_sprite_sheet(&sprite_sheet, window_manager);
                 }
-                let scene = self.pending_scenes.remove(index);
+                if let Some(overlay) = &self.debug_overlay {
+                    if overlay.render_scene == render_scene {
+                        if !overlay.enabled {
+                            graphics_provider
+                                .set_visibility_render_scene(&render_scene, &Visibility::Hidden);
+                        }
+                    } else {
+                        // Render scenes are drawn in creation order, the overlay stays on top
+                        graphics_provider.bring_render_scene_to_front(&overlay.render_scene);
+                    }
+                }
+                let mut scene = self.pending_scenes.remove(index);
+                let display_scale = self.display_scale(&scene.target_window);
+                scene
+                    .entities
+                    .iter_mut()
+                    .for_each(|entity| entity.display_scale(&display_scale));
+                if let Some(safe_area) = self.safe_area(&scene.target_window) {
+                    scene
+                        .entities
+                        .iter_mut()
+                        .for_each(|entity| entity.safe_area(&safe_area));
+                }
                 window_manager.send_event(GameEvent::External(E::new_scene(&scene)));
                 self.active_scenes.push(scene);
                 self.active_scenes.sort_by_key(|s| s.z_index);
@@ -371,26 +817,126 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                     let dimensions = &self.ressources.get_sprite_sheet(&label).1;
                     let sprite_sheet = SpriteSheet::new(id, dimensions);
                     self.sprite_sheets.push((label.clone(), sprite_sheet));
+                    self.render_caches
+                        .iter_mut()
+                        .for_each(|(_, _, _, is_dirty)| *is_dirty = true);
                 }
             }
-            GameEvent::Timer(delta_t) => {
+            GameEvent::Timer(delta_t, sent_at) => {
+                graphics_provider.profiler_mut().begin_frame(sent_at);
+                for id in std::mem::take(&mut self.resized_windows) {
+                    self.apply_virtual_resolution(&id, graphics_provider);
+                }
+                self.update_scene_music();
+                self.audio.update(delta_t);
+                if let Some(overlay) = &mut self.debug_overlay {
+                    overlay.record_frame(&delta_t);
+                }
+                let debug_info = self
+                    .debug_overlay
+                    .as_ref()
+                    .filter(|overlay| overlay.enabled)
+                    .map(|overlay| self.debug_info(overlay, graphics_provider.profiler().last_frame()));
+                self.upload_font_atlases(graphics_provider);
                 for scene in self
                     .active_scenes
                     .iter_mut()
                     .chain(self.suspended_scenes.iter_mut())
                 {
-                    let mut vertices = VertexBuffer::new();
-                    let mut indices = IndexBuffer::new();
                     let entities = &mut scene.entities;
+                    if let Some(overlay) = &self.debug_overlay {
+                        if overlay.scene == scene.name {
+                            match &debug_info {
+                                Some(info) => entities
+                                    .iter_mut()
+                                    .for_each(|entity| entity.debug_info(info)),
+                                None => continue,
+                            }
+                        }
+                    }
+                    let update_start = Instant::now();
+                    let reordered = entities.windows(2).any(|pair| pair[0].z() > pair[1].z());
                     entities.sort_by(|a, b| a.z().partial_cmp(&b.z()).expect("NaN NaN NaN"));
                     for i in 0..entities.len() {
                         let (left, right) = entities.split_at_mut(i);
                         let (entity, right) = right.split_first_mut().expect("i out of bounds");
                         let interactions = left.iter().chain(right.iter()).map(|e| &*e).collect();
+                        let entity_start = Instant::now();
                         let events = entity.update(&interactions, &delta_t, &scene.name);
+                        graphics_provider.profiler_mut().record_entity_type(
+                            &format!("{:?}", entity.entity_type()),
+                            Stage::Update,
+                            entity_start.elapsed(),
+                        );
                         for event in events {
                             window_manager.send_event(GameEvent::External(event))
                         }
+                    }
+                    if let Some((_, camera, camera_name)) =
+                        self.cameras.iter_mut().find(|(n, _, _)| n == &scene.name)
+                    {
+                        match camera.update(entities.iter().map(|e| &*e).collect(), &delta_t) {
+                            Ok(()) => {}
+                            Err(err) => info!("Camera update failed: {}", err),
+                        };
+                        graphics_provider.update_uniform_buffer(camera_name, &camera.as_bytes());
+                        let view = camera.view();
+                        entities
+                            .iter_mut()
+                            .for_each(|entity| entity.camera_view(&view));
+                        if let Some(lighting) =
+                            self.lighting.iter_mut().find(|l| l.scene == scene.name)
+                        {
+                            graphics_provider
+                                .update_uniform_buffer(&lighting.camera, &camera.as_bytes());
+                            lighting.render(&delta_t, &view, entities);
+                            graphics_provider.update_scene(
+                                &lighting.render_scene,
+                                &lighting.vertices,
+                                &lighting.indices,
+                            );
+                        }
+                    }
+                    graphics_provider.profiler_mut().record_scene(
+                        scene.name.as_str(),
+                        Stage::Update,
+                        update_start,
+                    );
+
+                    let cache_index = match self
+                        .render_caches
+                        .iter()
+                        .position(|(n, _, _, _)| n == &scene.name)
+                    {
+                        Some(index) => index,
+                        None => {
+                            self.render_caches.push((
+                                scene.name.clone(),
+                                VertexBuffer::new(),
+                                IndexBuffer::new(),
+                                true,
+                            ));
+                            self.render_caches.len() - 1
+                        }
+                    };
+                    let (_, vertices, indices, is_dirty) = &mut self.render_caches[cache_index];
+                    // Every entity has to be asked, so all of them reset their flag
+                    let entities_dirty = entities
+                        .iter_mut()
+                        .fold(false, |dirty, entity| entity.is_dirty() || dirty);
+                    if !(*is_dirty || reordered || entities_dirty) {
+                        graphics_provider.profiler_mut().record_buffers(
+                            scene.name.as_str(),
+                            vertices.len(),
+                            indices.len(),
+                        );
+                        continue;
+                    }
+                    *is_dirty = false;
+                    let render_start = Instant::now();
+                    vertices.clear();
+                    indices.clear();
+                    for entity in entities.iter_mut() {
                         let sprite_sheets = entity
                             .sprite_sheets()
                             .iter()
@@ -401,23 +947,27 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                                     .map(|(_, s)| s)
                             })
                             .collect();
-                        entity.render(&mut vertices, &mut indices, sprite_sheets);
-                    }
-                    if let Some((_, camera, camera_name)) =
-                        self.cameras.iter_mut().find(|(n, _, _)| n == &scene.name)
-                    {
-                        match camera.update(entities.iter().map(|e| &*e).collect(), &delta_t) {
-                            Ok(()) => {}
-                            Err(err) => info!("Camera update failed: {}", err),
-                        };
-                        graphics_provider.update_uniform_buffer(camera_name, &camera.as_bytes());
+                        let entity_start = Instant::now();
+                        entity.render(vertices, indices, sprite_sheets);
+                        graphics_provider.profiler_mut().record_entity_type(
+                            &format!("{:?}", entity.entity_type()),
+                            Stage::Render,
+                            entity_start.elapsed(),
+                        );
                     }
-                    window_manager.send_event(GameEvent::RenderUpdate(
-                        scene.render_scene.clone(),
-                        vertices,
-                        indices,
-                    ));
+                    let profiler = graphics_provider.profiler_mut();
+                    profiler.record_scene(scene.name.as_str(), Stage::Render, render_start);
+                    profiler.record_buffers(scene.name.as_str(), vertices.len(), indices.len());
+                    let upload_start = Instant::now();
+                    graphics_provider.update_scene(&scene.render_scene, vertices, indices);
+                    graphics_provider.profiler_mut().record_scene(
+                        scene.name.as_str(),
+                        Stage::Upload,
+                        upload_start,
+                    );
                 }
+                // Glyphs rasterized while rendering have to reach the gpu before the frame
+                self.upload_font_atlases(graphics_provider);
             }
             GameEvent::External(event) => {
                 println!("EXTERN EVENT: {:?}", event);
@@ -446,6 +996,7 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                                 .expect(&format!("Found no active nor suspended scene {:?}", scene))
                         });
                     scene.entities.append(&mut entities);
+                    Self::mark_dirty(&mut self.render_caches, &scene.name);
                     return;
                 }
                 if let Some((scene, visibility)) = event.is_request_set_visibility_scene() {
@@ -511,6 +1062,11 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                     {
                         let scene = self.active_scenes.remove(active_index);
                         graphics_provider.remove_render_scene(&scene.render_scene);
+                        if let Some(lighting) =
+                            self.lighting.iter().find(|l| l.scene == scene.name)
+                        {
+                            graphics_provider.remove_render_scene(&lighting.render_scene);
+                        }
                     } else if let Some(suspended_index) = self
                         .suspended_scenes
                         .iter()
@@ -518,6 +1074,11 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                     {
                         let scene = self.suspended_scenes.remove(suspended_index);
                         graphics_provider.remove_render_scene(&scene.render_scene);
+                        if let Some(lighting) =
+                            self.lighting.iter().find(|l| l.scene == scene.name)
+                        {
+                            graphics_provider.remove_render_scene(&lighting.render_scene);
+                        }
                     } else {
                         warn!(
                             "Tried to delete Scene {:?}, but its neither active nor suspended",
@@ -526,6 +1087,25 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                     }
                     self.cameras
                         .retain(|(scene_name, _, _)| scene_name != deletable_scene);
+                    self.render_caches
+                        .retain(|(scene_name, _, _, _)| scene_name != deletable_scene);
+                }
+                if let Some((window, cursor)) = event.is_request_set_cursor() {
+                    if let Some((_, id)) = self.window_ids.iter().find(|(name, _)| name == window) {
+                        window_manager
+                            .send_event(GameEvent::RequestSetCursor(id.clone(), cursor.clone()));
+                    } else {
+                        warn!(
+                            "Tried to set the cursor of window {:?}, but it does not exist",
+                            window
+                        );
+                    }
+                }
+                if let Some(command) = event.is_audio_command() {
+                    self.audio.handle_command(command);
+                }
+                if let Some(language) = event.is_request_set_language() {
+                    self.set_language(language);
                 }
                 if let Some((uniform_name, contents)) = event.is_update_uniform_buffer() {
                     graphics_provider.update_uniform_buffer(uniform_name, contents);
@@ -546,6 +1126,7 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                     for e in scene.entities.iter_mut() {
                         e.delete_child_entity(entity);
                     }
+                    Self::mark_dirty(&mut self.render_caches, &scene.name);
                 }
                 if let Some(scene) = event.is_request_render_scene() {
                     if let Some(scene) = self.active_scenes.iter_mut().find(|s| s.name == *scene) {
@@ -564,6 +1145,7 @@ impl<E: ExternalEvent + 'static, S: State<E>> EventManager<GameEvent<E>> for Gam
                     for scene in &mut self.active_scenes {
                         match scene.entities.iter_mut().find(|e| e.name() == &target) {
                             Some(entity) => {
+                                Self::mark_dirty(&mut self.render_caches, &scene.name);
                                 target_entity = Some(entity);
                                 break;
                             }
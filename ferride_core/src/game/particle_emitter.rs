@@ -0,0 +1,586 @@
+use std::{f32::consts::TAU, fmt::Debug, marker::PhantomData, time::Duration};
+
+use threed::Vector;
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    app::{IndexBuffer, VertexBuffer},
+    graphics::{SpriteInstance, Vertex},
+};
+
+use super::{
+    BoundingBox, Entity, EntityName, EntityType, ExternalEvent, SceneName, SpritePosition,
+    SpriteSheet, SpriteSheetName, TextureCoordinates,
+};
+
+///Vertex types a `ParticleEmitter` can render into
+pub trait ParticleVertex: Vertex + Send {
+    ///Writes a square particle of `size` centered on `center`. The texture coordinates are the
+    ///corners in the order `SpriteSheet::get_sprite_coordinates` returns them
+    fn write_particle(
+        center: [f32; 2],
+        size: f32,
+        texture_coordinates: &[TextureCoordinates; 4],
+        texture: u32,
+        color: [u8; 4],
+        vertices: &mut VertexBuffer,
+        indices: &mut IndexBuffer,
+    );
+}
+impl ParticleVertex for SpriteInstance {
+    fn write_particle(
+        center: [f32; 2],
+        size: f32,
+        texture_coordinates: &[TextureCoordinates; 4],
+        texture: u32,
+        color: [u8; 4],
+        vertices: &mut VertexBuffer,
+        _indices: &mut IndexBuffer,
+    ) {
+        let [top_left, _, bottom_right, _] = texture_coordinates;
+        vertices.extend_from_slice(&[SpriteInstance::new(
+            center,
+            [size, size],
+            [
+                top_left.u,
+                top_left.v,
+                bottom_right.u - top_left.u,
+                bottom_right.v - top_left.v,
+            ],
+            texture,
+        )
+        .with_color(u32::from_be_bytes(color))]);
+    }
+}
+
+///Area new particles are spawned in, centered on the emitter
+#[derive(Debug, Clone)]
+pub enum EmissionShape {
+    Point,
+    Box(PhysicalSize<f32>),
+    Circle(f32),
+}
+
+#[derive(Debug, Clone)]
+pub enum EmissionMode {
+    ///Particles per second
+    Rate(f32),
+    ///`count` particles at once. Repeats every `interval`, or only once if there is none
+    Burst {
+        count: u32,
+        interval: Option<Duration>,
+    },
+}
+
+#[derive(Debug)]
+struct Particle {
+    position: Vector<f32>,
+    velocity: Vector<f32>,
+    age: Duration,
+    lifetime: Duration,
+}
+impl Particle {
+    fn life(&self) -> f32 {
+        (self.age.as_secs_f32() / self.lifetime.as_secs_f32()).min(1.0)
+    }
+}
+
+///SplitMix64, good enough for particles and reproducible across platforms
+#[derive(Debug)]
+struct Rng(u64);
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    ///Uniform in [0, 1)
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    ///Uniform in [-1, 1)
+    fn next_signed(&mut self) -> f32 {
+        self.next_f32() * 2.0 - 1.0
+    }
+}
+
+///Spawns, moves and renders short lived sprites. Velocities and gravity are in units per second.
+///All randomness comes from the seed, so two emitters with the same seed and updates behave the
+///same
+pub struct ParticleEmitter<T: EntityType + Clone, V: ParticleVertex> {
+    name: EntityName,
+    entity_type: T,
+    position: Vector<f32>,
+    shape: EmissionShape,
+    mode: EmissionMode,
+    lifetime: (Duration, Duration),
+    velocity: Vector<f32>,
+    velocity_spread: Vector<f32>,
+    gravity: Vector<f32>,
+    ///Keyframes evenly spread over the lifetime of a particle
+    colors: Vec<[u8; 4]>,
+    ///Keyframes evenly spread over the lifetime of a particle
+    sizes: Vec<f32>,
+    sprite_sheet: Option<SpriteSheetName>,
+    ///Animation frames evenly spread over the lifetime of a particle
+    frames: Vec<SpritePosition>,
+    max_particles: usize,
+    emitting: bool,
+    spawn_accumulator: f32,
+    until_burst: Option<Duration>,
+    particles: Vec<Particle>,
+    rng: Rng,
+    is_dirty: bool,
+    vertex: PhantomData<V>,
+}
+impl<T: EntityType + Clone, V: ParticleVertex> ParticleEmitter<T, V> {
+    pub fn new(name: EntityName, entity_type: T, position: Vector<f32>, seed: u64) -> Self {
+        Self {
+            name,
+            entity_type,
+            position,
+            shape: EmissionShape::Point,
+            mode: EmissionMode::Rate(10.0),
+            lifetime: (Duration::from_secs(1), Duration::from_secs(1)),
+            velocity: Vector::scalar(0.0),
+            velocity_spread: Vector::scalar(0.0),
+            gravity: Vector::scalar(0.0),
+            colors: vec![[255, 255, 255, 255]],
+            sizes: vec![8.0],
+            sprite_sheet: None,
+            frames: Vec::new(),
+            max_particles: 1024,
+            emitting: true,
+            spawn_accumulator: 0.0,
+            until_burst: Some(Duration::ZERO),
+            particles: Vec::new(),
+            rng: Rng(seed),
+            is_dirty: true,
+            vertex: PhantomData,
+        }
+    }
+
+    pub fn with_shape(mut self, shape: EmissionShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: EmissionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    ///Every particle lives for a random duration between `min` and `max`
+    pub fn with_lifetime(mut self, min: Duration, max: Duration) -> Self {
+        self.lifetime = (min, max);
+        self
+    }
+
+    ///Every axis of the start velocity is randomized by up to +-`spread`
+    pub fn with_velocity(mut self, velocity: Vector<f32>, spread: Vector<f32>) -> Self {
+        self.velocity = velocity;
+        self.velocity_spread = spread;
+        self
+    }
+
+    pub fn with_gravity(mut self, gravity: Vector<f32>) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    ///Rgba colors the particles fade through over their lifetime
+    pub fn with_colors(mut self, colors: Vec<[u8; 4]>) -> Self {
+        assert!(!colors.is_empty(), "A particle needs at least one color");
+        self.colors = colors;
+        self
+    }
+
+    ///Edge lengths the particles scale through over their lifetime
+    pub fn with_sizes(mut self, sizes: Vec<f32>) -> Self {
+        assert!(!sizes.is_empty(), "A particle needs at least one size");
+        self.sizes = sizes;
+        self
+    }
+
+    ///Without a sprite sheet the particles are plain colored squares
+    pub fn with_sprite_sheet(
+        mut self,
+        sprite_sheet: SpriteSheetName,
+        frames: Vec<SpritePosition>,
+    ) -> Self {
+        self.sprite_sheet = Some(sprite_sheet);
+        self.frames = frames;
+        self
+    }
+
+    pub fn with_max_particles(mut self, max_particles: usize) -> Self {
+        self.max_particles = max_particles;
+        self
+    }
+
+    pub fn set_position(&mut self, position: Vector<f32>) {
+        self.position = position;
+    }
+
+    ///Stops or resumes spawning. Living particles are not affected
+    pub fn set_emitting(&mut self, emitting: bool) {
+        self.emitting = emitting;
+    }
+
+    pub fn burst(&mut self, count: u32) {
+        for _ in 0..count {
+            self.spawn();
+        }
+    }
+
+    pub fn particle_count(&self) -> usize {
+        self.particles.len()
+    }
+
+    ///Whether no particles are alive and none will be spawned anymore
+    pub fn is_finished(&self) -> bool {
+        let spawns = self.emitting
+            && match self.mode {
+                EmissionMode::Rate(rate) => rate > 0.0,
+                EmissionMode::Burst { .. } => self.until_burst.is_some(),
+            };
+        !spawns && self.particles.is_empty()
+    }
+
+    fn spawn(&mut self) {
+        if self.particles.len() >= self.max_particles {
+            return;
+        }
+        let offset = match self.shape {
+            EmissionShape::Point => (0.0, 0.0),
+            EmissionShape::Box(size) => (
+                self.rng.next_signed() * size.width / 2.0,
+                self.rng.next_signed() * size.height / 2.0,
+            ),
+            EmissionShape::Circle(radius) => {
+                let angle = self.rng.next_f32() * TAU;
+                let distance = radius * self.rng.next_f32().sqrt();
+                (angle.cos() * distance, angle.sin() * distance)
+            }
+        };
+        let velocity = Vector::new(
+            self.velocity.x + self.rng.next_signed() * self.velocity_spread.x,
+            self.velocity.y + self.rng.next_signed() * self.velocity_spread.y,
+            0.0,
+        );
+        let (min, max) = self.lifetime;
+        let lifetime = min + max.saturating_sub(min).mul_f32(self.rng.next_f32());
+        self.particles.push(Particle {
+            position: Vector::new(
+                self.position.x + offset.0,
+                self.position.y + offset.1,
+                self.position.z,
+            ),
+            velocity,
+            age: Duration::ZERO,
+            lifetime,
+        });
+    }
+
+    fn emit(&mut self, delta_t: &Duration) {
+        if !self.emitting {
+            return;
+        }
+        match self.mode {
+            EmissionMode::Rate(rate) => {
+                self.spawn_accumulator += rate * delta_t.as_secs_f32();
+                let count = self.spawn_accumulator.floor();
+                self.spawn_accumulator -= count;
+                self.burst(count as u32);
+            }
+            EmissionMode::Burst { count, interval } => {
+                if let Some(until_burst) = self.until_burst {
+                    if until_burst <= *delta_t {
+                        self.burst(count);
+                        self.until_burst = interval;
+                    } else {
+                        self.until_burst = Some(until_burst - *delta_t);
+                    }
+                }
+            }
+        }
+    }
+
+    fn color(&self, life: f32) -> [u8; 4] {
+        let (from, to, t) = keyframes(self.colors.len(), life);
+        let (from, to) = (self.colors[from], self.colors[to]);
+        let mut color = [0; 4];
+        for i in 0..4 {
+            color[i] = (from[i] as f32 + (to[i] as f32 - from[i] as f32) * t).round() as u8;
+        }
+        color
+    }
+
+    fn size(&self, life: f32) -> f32 {
+        let (from, to, t) = keyframes(self.sizes.len(), life);
+        self.sizes[from] + (self.sizes[to] - self.sizes[from]) * t
+    }
+}
+
+///Neighbouring keyframes of `life` in [0, 1] and the interpolation factor between them
+fn keyframes(amount: usize, life: f32) -> (usize, usize, f32) {
+    if amount == 1 {
+        return (0, 0, 0.0);
+    }
+    let position = life * (amount - 1) as f32;
+    let from = (position.floor() as usize).min(amount - 2);
+    (from, from + 1, position - from as f32)
+}
+
+impl<T: EntityType + Clone, V: ParticleVertex> Debug for ParticleEmitter<T, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParticleEmitter")
+            .field("name", &self.name)
+            .field("position", &self.position)
+            .field("particles", &self.particles.len())
+            .finish()
+    }
+}
+impl<T, E, V> Entity<T, E> for ParticleEmitter<T, V>
+where
+    T: EntityType + Clone + Send,
+    E: ExternalEvent,
+    V: ParticleVertex,
+{
+    fn update(
+        &mut self,
+        _entities: &Vec<&Box<dyn Entity<T, E>>>,
+        delta_t: &Duration,
+        _scene: &SceneName,
+    ) -> Vec<E> {
+        let seconds = delta_t.as_secs_f32();
+        let had_particles = !self.particles.is_empty();
+        self.particles.retain_mut(|particle| {
+            particle.age += *delta_t;
+            particle.age < particle.lifetime
+        });
+        for particle in self.particles.iter_mut() {
+            particle.velocity.x += self.gravity.x * seconds;
+            particle.velocity.y += self.gravity.y * seconds;
+            particle.position.x += particle.velocity.x * seconds;
+            particle.position.y += particle.velocity.y * seconds;
+        }
+        self.emit(delta_t);
+        self.is_dirty |= had_particles || !self.particles.is_empty();
+        vec![]
+    }
+
+    fn render(
+        &mut self,
+        vertices: &mut VertexBuffer,
+        indices: &mut IndexBuffer,
+        sprite_sheet: Vec<Option<&SpriteSheet>>,
+    ) {
+        let default_sprite_sheet = SpriteSheet::default();
+        let sprite_sheet = match (&self.sprite_sheet, sprite_sheet.first()) {
+            (None, _) => &default_sprite_sheet,
+            (Some(_), Some(Some(sprite_sheet))) => *sprite_sheet,
+            (Some(_), _) => return,
+        };
+        let first_frame = SpritePosition::new(0, 0);
+        for particle in self.particles.iter() {
+            let life = particle.life();
+            let color = self.color(life);
+            let frame = if self.frames.is_empty() {
+                &first_frame
+            } else {
+                let index = (life * self.frames.len() as f32) as usize;
+                &self.frames[index.min(self.frames.len() - 1)]
+            };
+            V::write_particle(
+                [particle.position.x, particle.position.y],
+                self.size(life),
+                &sprite_sheet.get_sprite_coordinates(frame),
+                sprite_sheet.texture(),
+                color,
+                vertices,
+                indices,
+            );
+        }
+    }
+
+    fn sprite_sheets(&self) -> Vec<&SpriteSheetName> {
+        self.sprite_sheet.iter().collect()
+    }
+
+    fn name(&self) -> &EntityName {
+        &self.name
+    }
+
+    ///Covers all living particles, or just the emitter position if there are none
+    fn bounding_box(&self) -> BoundingBox {
+        if self.particles.is_empty() {
+            return BoundingBox {
+                anchor: self.position.clone(),
+                size: PhysicalSize::new(0.0, 0.0),
+            };
+        }
+        let (mut min_x, mut min_y) = (f32::INFINITY, f32::INFINITY);
+        let (mut max_x, mut max_y) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+        for particle in self.particles.iter() {
+            let half_size = self.size(particle.life()) / 2.0;
+            min_x = min_x.min(particle.position.x - half_size);
+            min_y = min_y.min(particle.position.y - half_size);
+            max_x = max_x.max(particle.position.x + half_size);
+            max_y = max_y.max(particle.position.y + half_size);
+        }
+        BoundingBox {
+            anchor: Vector::new(
+                (min_x + max_x) / 2.0,
+                (min_y + max_y) / 2.0,
+                self.position.z,
+            ),
+            size: PhysicalSize::new(max_x - min_x, max_y - min_y),
+        }
+    }
+
+    fn position(&self) -> Vector<f32> {
+        self.position.clone()
+    }
+
+    fn entity_type(&self) -> T {
+        self.entity_type.clone()
+    }
+
+    fn is_dirty(&mut self) -> bool {
+        let dirt = self.is_dirty;
+        self.is_dirty = false;
+        dirt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{game::example::EmptyExternalEvent, graphics_provider::BufferWriter};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Particles;
+    impl EntityType for Particles {}
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, repr_trait::C)]
+    struct ColorVertex {
+        position: [f32; 2],
+        color: [u8; 4],
+    }
+    const COLOR_VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 2] =
+        wgpu::vertex_attr_array![0 => Float32x2, 1 => Unorm8x4];
+    impl Vertex for ColorVertex {
+        fn attributes() -> &'static [wgpu::VertexAttribute] {
+            &COLOR_VERTEX_ATTRIBUTES
+        }
+    }
+    impl ParticleVertex for ColorVertex {
+        fn write_particle(
+            [x, y]: [f32; 2],
+            size: f32,
+            _texture_coordinates: &[TextureCoordinates; 4],
+            _texture: u32,
+            color: [u8; 4],
+            vertices: &mut VertexBuffer,
+            indices: &mut IndexBuffer,
+        ) {
+            let half_size = size / 2.0;
+            let start_index = vertices.len();
+            vertices.extend_from_slice(
+                &[
+                    [x - half_size, y + half_size],
+                    [x + half_size, y + half_size],
+                    [x + half_size, y - half_size],
+                    [x - half_size, y - half_size],
+                ]
+                .map(|position| Self { position, color }),
+            );
+            indices.extend_from_slice(&[
+                start_index,
+                start_index + 1,
+                start_index + 2,
+                start_index,
+                start_index + 2,
+                start_index + 3,
+            ]);
+        }
+    }
+
+    type Emitter = ParticleEmitter<Particles, ColorVertex>;
+
+    fn update(emitter: &mut Emitter, delta_t: Duration) {
+        Entity::<Particles, EmptyExternalEvent>::update(
+            emitter,
+            &vec![],
+            &delta_t,
+            &"Scene".into(),
+        );
+    }
+
+    fn render(emitter: &mut Emitter) -> Vec<u8> {
+        let mut vertices = VertexBuffer::new();
+        let mut indices = IndexBuffer::new();
+        Entity::<Particles, EmptyExternalEvent>::render(
+            emitter,
+            &mut vertices,
+            &mut indices,
+            vec![],
+        );
+        vertices.buffer_data().unwrap_or(&[]).to_vec()
+    }
+
+    fn sparks(seed: u64) -> Emitter {
+        ParticleEmitter::new("Sparks".into(), Particles, Vector::scalar(0.0), seed)
+            .with_shape(EmissionShape::Circle(20.0))
+            .with_mode(EmissionMode::Rate(50.0))
+            .with_lifetime(Duration::from_millis(200), Duration::from_millis(600))
+            .with_velocity(Vector::new(0.0, 100.0, 0.0), Vector::new(50.0, 20.0, 0.0))
+            .with_gravity(Vector::new(0.0, -200.0, 0.0))
+    }
+
+    #[test]
+    fn same_seed_is_deterministic() {
+        let mut a = sparks(7);
+        let mut b = sparks(7);
+        let mut c = sparks(8);
+        for _ in 0..30 {
+            update(&mut a, Duration::from_millis(16));
+            update(&mut b, Duration::from_millis(16));
+            update(&mut c, Duration::from_millis(16));
+        }
+        assert!(a.particle_count() > 0);
+        assert_eq!(render(&mut a), render(&mut b));
+        assert_ne!(render(&mut a), render(&mut c));
+    }
+
+    #[test]
+    fn burst_expires_and_fades() {
+        let mut emitter = ParticleEmitter::new("Dust".into(), Particles, Vector::scalar(0.0), 1)
+            .with_mode(EmissionMode::Burst {
+                count: 5,
+                interval: None,
+            })
+            .with_lifetime(Duration::from_secs(1), Duration::from_secs(1))
+            .with_colors(vec![[255, 0, 0, 255], [0, 0, 255, 0]]);
+        update(&mut emitter, Duration::from_millis(10));
+        assert_eq!(emitter.particle_count(), 5);
+        assert!(!emitter.is_finished());
+
+        update(&mut emitter, Duration::from_millis(500));
+        assert_eq!(emitter.particle_count(), 5);
+        let vertices = render(&mut emitter);
+        let stride = std::mem::size_of::<ColorVertex>();
+        assert_eq!(vertices.len(), 20 * stride);
+        assert_eq!(vertices[8..stride], [128, 0, 128, 128]);
+
+        update(&mut emitter, Duration::from_millis(500));
+        assert_eq!(emitter.particle_count(), 0);
+        assert!(emitter.is_finished());
+    }
+}
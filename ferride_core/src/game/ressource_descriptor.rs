@@ -1,13 +1,20 @@
 use std::path::PathBuf;
 
 use log::info;
+use winit::dpi::PhysicalSize;
 
 use crate::app::WindowDescriptor;
+use crate::audio::{Bus, SoundName};
 use crate::create_name_struct;
+use crate::fonts::FontName;
 
 use crate::game_engine::CameraDescriptor;
-use crate::graphics_provider::{RenderSceneDescriptor, RenderSceneName, UniformBufferName};
+use crate::graphics_provider::{
+    validate_pipeline, PipelineValidationError, RenderSceneDescriptor, RenderSceneName,
+    ShaderDescriptor, UniformBufferName,
+};
 
+use super::camera::{static_camera, Camera};
 use super::sprite_sheet::SpriteSheetDimensions;
 
 pub struct RessourceDescriptorBuilder {
@@ -21,6 +28,9 @@ impl RessourceDescriptorBuilder {
                 image_directory: PathBuf::from(""),
                 sprite_sheets: vec![],
                 uniforms: vec![],
+                static_cameras: vec![],
+                sounds: vec![],
+                fonts: vec![],
                 default_render_scene: (None, default_render_scene),
                 render_scenes: vec![],
             },
@@ -54,6 +64,21 @@ impl RessourceDescriptorBuilder {
         self.ressources.uniforms = uniforms;
         self
     }
+    pub fn with_static_cameras(
+        mut self,
+        static_cameras: Vec<(UniformBufferName, WindowName, PhysicalSize<f32>)>,
+    ) -> Self {
+        self.ressources.static_cameras = static_cameras;
+        self
+    }
+    pub fn with_sounds(mut self, sounds: Vec<(SoundName, PathBuf, Bus)>) -> Self {
+        self.ressources.sounds = sounds;
+        self
+    }
+    pub fn with_fonts(mut self, fonts: Vec<(FontName, PathBuf)>) -> Self {
+        self.ressources.fonts = fonts;
+        self
+    }
     pub fn with_default_render_scene(
         mut self,
         camera: Option<CameraDescriptor>,
@@ -76,6 +101,16 @@ pub struct RessourceDescriptor {
     pub sprite_sheets: Vec<(SpriteSheetName, PathBuf, SpriteSheetDimensions)>,
     ///describes UniformBuffers that are not Cameras, because of their elevated
     pub uniforms: Vec<(UniformBufferName, Vec<u8>, wgpu::ShaderStages)>,
+    ///Uniforms holding a `static_camera` of the view size. They are usable like `uniforms` and
+    ///follow the virtual resolution of the window when it is resized
+    pub static_cameras: Vec<(UniformBufferName, WindowName, PhysicalSize<f32>)>,
+    ///Sounds `ExternalEvent::is_audio_command` can play, read through the vfs
+    pub sounds: Vec<(SoundName, PathBuf, Bus)>,
+    ///BMFont `.fnt` and TrueType or OpenType `.ttf`/`.otf` files, registered by name when the
+    ///`Game` is created. Pages of bitmap fonts become SpriteSheets named by
+    ///`BitmapFont::page_sprite_sheet`, glyph atlases of vector fonts by
+    ///`VectorFont::atlas_sprite_sheet`
+    pub fonts: Vec<(FontName, PathBuf)>,
     pub default_render_scene: (Option<CameraDescriptor>, RenderSceneDescriptor),
     pub render_scenes: Vec<(
         Vec<RenderSceneName>,
@@ -98,6 +133,18 @@ impl RessourceDescriptor {
             .iter()
             .find(|(uniform_name, _, _)| uniform_name == name)
             .cloned()
+            .or_else(|| {
+                self.static_cameras
+                    .iter()
+                    .find(|(uniform_name, _, _)| uniform_name == name)
+                    .map(|(name, _, view_size)| {
+                        (
+                            name.clone(),
+                            bytemuck::cast_slice(&static_camera(*view_size)).to_vec(),
+                            wgpu::ShaderStages::VERTEX,
+                        )
+                    })
+            })
     }
     pub fn get_render_scene(
         &self,
@@ -115,6 +162,36 @@ impl RessourceDescriptor {
             self.default_render_scene.clone()
         }
     }
+    ///Validates the pipeline `Game` would request for a Scene with this RenderScene and shader.
+    ///Meant for tests, as no GPU is needed. Panics if a uniform of the shader is not declared,
+    ///like requesting the Scene would
+    pub fn validate_render_scene(
+        &self,
+        render_scene: &RenderSceneName,
+        shader_descriptor: &ShaderDescriptor,
+    ) -> Result<(), PipelineValidationError> {
+        let (camera, render_scene_descriptor) = self.get_render_scene(render_scene);
+        let mut uniforms: Vec<(UniformBufferName, usize)> = shader_descriptor
+            .uniforms
+            .iter()
+            .map(|name| {
+                self.get_uniform(&(*name).into())
+                    .map(|(name, content, _)| (name, content.len()))
+                    .expect(&format!(
+                        "Did not specify UniformBuffer {:?} in RessourceDescriptor",
+                        name
+                    ))
+            })
+            .collect();
+        if let Some(camera_descriptor) = camera {
+            let camera: Camera = (&camera_descriptor).into();
+            uniforms.push((
+                format!("{:?} camera", render_scene.as_str()).into(),
+                camera.as_bytes().len(),
+            ));
+        }
+        validate_pipeline(shader_descriptor, &render_scene_descriptor, &uniforms)
+    }
     pub fn get_sprite_sheet(&self, name: &SpriteSheetName) -> (PathBuf, SpriteSheetDimensions) {
         self.sprite_sheets
             .iter()
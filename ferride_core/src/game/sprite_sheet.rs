@@ -1,9 +1,14 @@
-#[derive(Debug)]
+use crate::graphics_provider::SpriteInstance;
+
+use super::BoundingBox;
+
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct TextureCoordinates {
     pub u: f32,
     pub v: f32,
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct SpritePosition {
     pub x: u8,
     pub y: u8,
@@ -75,4 +80,23 @@ impl SpriteSheet {
             },
         ]
     }
+    ///Instance for a `RenderSceneKind::SpriteBatch` covering the `bounding_box`
+    pub fn instance(
+        &self,
+        bounding_box: &BoundingBox,
+        position: &SpritePosition,
+    ) -> SpriteInstance {
+        let coordinates = self.get_sprite_coordinates(position);
+        SpriteInstance::new(
+            [bounding_box.anchor.x, bounding_box.anchor.y],
+            [bounding_box.size.width, bounding_box.size.height],
+            [
+                coordinates[0].u,
+                coordinates[0].v,
+                coordinates[2].u - coordinates[0].u,
+                coordinates[2].v - coordinates[0].v,
+            ],
+            self.texture,
+        )
+    }
 }
@@ -1,3 +1,4 @@
+use repr_trait::C;
 use std::fmt::Debug;
 
 pub trait Vertex:
@@ -15,15 +16,163 @@ pub trait Vertex:
 }
 pub trait Index: Debug + Clone + Copy + bytemuck::Pod + bytemuck::Zeroable {
     fn index_format() -> wgpu::IndexFormat;
+    fn as_u32(&self) -> u32;
 }
 impl Index for u16 {
     fn index_format() -> wgpu::IndexFormat {
         wgpu::IndexFormat::Uint16
     }
+    fn as_u32(&self) -> u32 {
+        *self as u32
+    }
 }
 impl Index for u32 {
     fn index_format() -> wgpu::IndexFormat {
         wgpu::IndexFormat::Uint32
     }
+    fn as_u32(&self) -> u32 {
+        *self
+    }
+}
+
+///Corner of the unit quad every instance of a sprite batch is drawn with. @location(0) is the
+///corner in [-0.5, 0.5], @location(1) the texture coordinates in [0, 1]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, repr_trait::C)]
+pub struct QuadVertex {
+    position: [f32; 2],
+    tex_coords: [f32; 2],
+}
+impl QuadVertex {
+    pub const QUAD: [QuadVertex; 4] = [
+        QuadVertex {
+            position: [-0.5, 0.5],
+            tex_coords: [0.0, 0.0],
+        },
+        QuadVertex {
+            position: [0.5, 0.5],
+            tex_coords: [1.0, 0.0],
+        },
+        QuadVertex {
+            position: [0.5, -0.5],
+            tex_coords: [1.0, 1.0],
+        },
+        QuadVertex {
+            position: [-0.5, -0.5],
+            tex_coords: [0.0, 1.0],
+        },
+    ];
+    pub const INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
+}
+const QUAD_VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 2] =
+    wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2];
+impl Vertex for QuadVertex {
+    fn attributes() -> &'static [wgpu::VertexAttribute] {
+        &QUAD_VERTEX_ATTRIBUTES
+    }
 }
 
+///One sprite of a sprite batch. Written into the `VertexBuffer` of a RenderScene of kind
+///`RenderSceneKind::SpriteBatch` instead of four vertices and six indices
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, repr_trait::C)]
+pub struct SpriteInstance {
+    ///Center of the sprite
+    pub position: [f32; 2],
+    pub size: [f32; 2],
+    ///Counterclockwise in radians
+    pub rotation: f32,
+    ///u, v of the top left corner, then width and height in texture coordinates
+    pub uv_rect: [f32; 4],
+    pub texture: u32,
+    ///RGBA, blended with the texture by `SPRITE_BATCH_SHADER`. 0 keeps the texture color
+    pub color: u32,
+}
+impl SpriteInstance {
+    pub fn new(position: [f32; 2], size: [f32; 2], uv_rect: [f32; 4], texture: u32) -> Self {
+        Self {
+            position,
+            size,
+            rotation: 0.0,
+            uv_rect,
+            texture,
+            color: 0,
+        }
+    }
+
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    pub fn with_color(mut self, color: u32) -> Self {
+        self.color = color;
+        self
+    }
+
+    ///Position and texture coordinates of the corners in the order of `QuadVertex::QUAD`,
+    ///transformed like the `SPRITE_BATCH_SHADER` does
+    pub(crate) fn corners(&self) -> [([f32; 2], [f32; 2]); 4] {
+        let (sin, cos) = self.rotation.sin_cos();
+        QuadVertex::QUAD.map(|corner| {
+            let x = corner.position[0] * self.size[0];
+            let y = corner.position[1] * self.size[1];
+            (
+                [
+                    self.position[0] + x * cos - y * sin,
+                    self.position[1] + x * sin + y * cos,
+                ],
+                [
+                    self.uv_rect[0] + corner.tex_coords[0] * self.uv_rect[2],
+                    self.uv_rect[1] + corner.tex_coords[1] * self.uv_rect[3],
+                ],
+            )
+        })
+    }
+}
+const SPRITE_INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 6] = wgpu::vertex_attr_array![
+    2 => Float32x2,
+    3 => Float32x2,
+    4 => Float32,
+    5 => Float32x4,
+    6 => Uint32,
+    7 => Uint32
+];
+impl Vertex for SpriteInstance {
+    fn describe_buffer_layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<Self>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: Self::attributes(),
+        }
+    }
+
+    fn attributes() -> &'static [wgpu::VertexAttribute] {
+        &SPRITE_INSTANCE_ATTRIBUTES
+    }
+}
+
+///Vertex of a RenderScene of kind `RenderSceneKind::Lighting`. The light fades quadratically from
+///`center` to `radius`, a radius of 0 lights with the full color. `depth` decides which shadows
+///cover the light, deeper vertices are drawn over shallower ones
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, repr_trait::C)]
+pub struct LightVertex {
+    pub position: [f32; 2],
+    pub center: [f32; 2],
+    pub color: [f32; 4],
+    pub radius: f32,
+    pub depth: f32,
+}
+const LIGHT_VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+    0 => Float32x2,
+    1 => Float32x2,
+    2 => Float32x4,
+    3 => Float32,
+    4 => Float32
+];
+impl Vertex for LightVertex {
+    fn attributes() -> &'static [wgpu::VertexAttribute] {
+        &LIGHT_VERTEX_ATTRIBUTES
+    }
+}
@@ -68,6 +68,11 @@ where
     }
 }
 
-pub trait IndexBufferWriter: BufferWriter {}
+pub trait IndexBufferWriter: BufferWriter {
+    ///Format of the written indices. `None` keeps the format of the RenderScene
+    fn index_format(&self) -> Option<wgpu::IndexFormat> {
+        None
+    }
+}
 
 pub trait VertexBufferWriter: BufferWriter {}
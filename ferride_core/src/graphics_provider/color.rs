@@ -0,0 +1,515 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug)]
+pub enum ColorParseError {
+    ///Not of the form `#rrggbb` or `#rrggbbaa`
+    InvalidHex(String),
+    UnknownName(String),
+}
+impl Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHex(hex) => write!(f, "'{}' is not a #rrggbb[aa] color", hex),
+            Self::UnknownName(name) => write!(f, "Unknown color name '{}'", name),
+        }
+    }
+}
+impl Error for ColorParseError {}
+
+///An sRGB color with straight alpha
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+impl Color {
+    pub const TRANSPARENT: Self = Self::new_rgba(0, 0, 0, 0);
+    pub const BLACK: Self = Self::new_rgb(0, 0, 0);
+    pub const WHITE: Self = Self::new_rgb(255, 255, 255);
+    pub const RED: Self = Self::new_rgb(255, 0, 0);
+    pub const BLUE: Self = Self::new_rgb(0, 0, 255);
+
+    pub const fn new_rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub const fn new_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::new_rgba(r, g, b, 255)
+    }
+
+    ///`0xrrggbbaa`
+    pub const fn from_rgba_u32(rgba: u32) -> Self {
+        let [r, g, b, a] = rgba.to_be_bytes();
+        Self::new_rgba(r, g, b, a)
+    }
+
+    pub const fn rgba_from_slice(color_slice: &[u8; 4]) -> Self {
+        Self::new_rgba(
+            color_slice[0],
+            color_slice[1],
+            color_slice[2],
+            color_slice[3],
+        )
+    }
+
+    pub const fn to_slice(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    pub const fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    ///Channels in 0..=1, values outside are clamped
+    pub fn from_f32(rgba: [f32; 4]) -> Self {
+        let [r, g, b, a] = rgba.map(unit_to_u8);
+        Self::new_rgba(r, g, b, a)
+    }
+
+    pub fn to_f32(&self) -> [f32; 4] {
+        self.to_slice().map(|c| c as f32 / 255.0)
+    }
+
+    ///`#rrggbb` or `#rrggbbaa`
+    pub fn from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        let invalid = || ColorParseError::InvalidHex(hex.to_string());
+        let digits = hex.strip_prefix('#').ok_or_else(invalid)?;
+        let is_hex = digits.chars().all(|c| c.is_ascii_hexdigit());
+        if !(digits.len() == 6 || digits.len() == 8) || !is_hex {
+            return Err(invalid());
+        }
+        let value = u32::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        Ok(match digits.len() {
+            6 => Self::from_rgba_u32(value << 8 | 0xff),
+            _ => Self::from_rgba_u32(value),
+        })
+    }
+
+    pub fn to_hex(&self) -> String {
+        match self.a {
+            255 => format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b),
+            a => format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, a),
+        }
+    }
+
+    ///One of the CSS named colors or `transparent`, ignoring case
+    pub fn from_name(name: &str) -> Result<Self, ColorParseError> {
+        let lowercase = name.to_ascii_lowercase();
+        if lowercase == "transparent" {
+            return Ok(Self::TRANSPARENT);
+        }
+        CSS_COLORS
+            .binary_search_by_key(&lowercase.as_str(), |(name, _)| name)
+            .map(|i| Self::from_rgba_u32(CSS_COLORS[i].1 << 8 | 0xff))
+            .map_err(|_| ColorParseError::UnknownName(name.to_string()))
+    }
+
+    ///Hue in degrees, saturation, value and alpha in 0..=1
+    pub fn from_hsva(h: f32, s: f32, v: f32, a: f32) -> Self {
+        let c = v * s;
+        let [r, g, b] = hue_to_rgb(h, c, v - c);
+        Self::from_f32([r, g, b, a])
+    }
+
+    pub fn to_hsva(&self) -> [f32; 4] {
+        let [r, g, b, a] = self.to_f32();
+        let max = r.max(g).max(b);
+        let chroma = max - r.min(g).min(b);
+        let s = if max > 0.0 { chroma / max } else { 0.0 };
+        [hue(r, g, b, max, chroma), s, max, a]
+    }
+
+    ///Hue in degrees, saturation, lightness and alpha in 0..=1
+    pub fn from_hsla(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let [r, g, b] = hue_to_rgb(h, c, l - c / 2.0);
+        Self::from_f32([r, g, b, a])
+    }
+
+    pub fn to_hsla(&self) -> [f32; 4] {
+        let [r, g, b, a] = self.to_f32();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+        let l = (max + min) / 2.0;
+        let s = if l > 0.0 && l < 1.0 {
+            chroma / (1.0 - (2.0 * l - 1.0).abs())
+        } else {
+            0.0
+        };
+        [hue(r, g, b, max, chroma), s, l, a]
+    }
+
+    ///From linear light, e.g. the output of a shader writing to an sRGB target
+    pub fn from_linear(rgba: [f32; 4]) -> Self {
+        let [r, g, b, a] = rgba;
+        Self::from_f32([linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b), a])
+    }
+
+    ///To linear light, alpha is left as is
+    pub fn to_linear(&self) -> [f32; 4] {
+        let [r, g, b, a] = self.to_f32();
+        [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a]
+    }
+
+    pub fn premultiplied(&self) -> Self {
+        let multiply = |c: u8| ((c as u16 * self.a as u16 + 127) / 255) as u8;
+        Self::new_rgba(multiply(self.r), multiply(self.g), multiply(self.b), self.a)
+    }
+
+    ///Inverse of `premultiplied`, precision lost for low alpha is not recovered
+    pub fn unpremultiplied(&self) -> Self {
+        if self.a == 0 {
+            return Self::TRANSPARENT;
+        }
+        let divide = |c: u8| ((c as u16 * 255 + self.a as u16 / 2) / self.a as u16).min(255) as u8;
+        Self::new_rgba(divide(self.r), divide(self.g), divide(self.b), self.a)
+    }
+
+    ///Interpolates in Oklab, so the midpoint of two colors looks halfway between them
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let from = linear_to_oklab(self.to_linear());
+        let to = linear_to_oklab(other.to_linear());
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        let [r, g, b] = oklab_to_linear([
+            mix(from[0], to[0]),
+            mix(from[1], to[1]),
+            mix(from[2], to[2]),
+        ]);
+        Self::from_linear([r, g, b, mix(self.a as f32, other.a as f32) / 255.0])
+    }
+
+    ///`self` composited over `other`
+    pub fn blend(&self, other: &Self) -> Self {
+        let [r_a, g_a, b_a, a_a] = self.to_slice();
+        let [r_b, g_b, b_b, a_b] = other.to_slice();
+        let a_a = a_a as f64 / 255.0;
+        let a_b = a_b as f64 / 255.0;
+        let a_c = a_a + (1.0 - a_a) * a_b;
+        if a_c == 0.0 {
+            return Self::TRANSPARENT;
+        }
+        let r_c = (a_a * r_a as f64 + (1.0 - a_a) * a_b * r_b as f64) / a_c;
+        let g_c = (a_a * g_a as f64 + (1.0 - a_a) * a_b * g_b as f64) / a_c;
+        let b_c = (a_a * b_a as f64 + (1.0 - a_a) * a_b * b_b as f64) / a_c;
+
+        Self::new_rgba(
+            r_c.round() as u8,
+            g_c.round() as u8,
+            b_c.round() as u8,
+            (a_c * 255.0).round() as u8,
+        )
+    }
+}
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    ///Either a hex color or a CSS name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.starts_with('#') {
+            true => Self::from_hex(s),
+            false => Self::from_name(s),
+        }
+    }
+}
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+fn unit_to_u8(c: f32) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn hue_to_rgb(h: f32, chroma: f32, m: f32) -> [f32; 3] {
+    let h_dash = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h_dash % 2.0 - 1.0).abs());
+    let [r, g, b] = match h_dash as u8 {
+        0 => [chroma, x, 0.0],
+        1 => [x, chroma, 0.0],
+        2 => [0.0, chroma, x],
+        3 => [0.0, x, chroma],
+        4 => [x, 0.0, chroma],
+        _ => [chroma, 0.0, x],
+    };
+    [r + m, g + m, b + m]
+}
+
+fn hue(r: f32, g: f32, b: f32, max: f32, chroma: f32) -> f32 {
+    if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        (60.0 * (g - b) / chroma).rem_euclid(360.0)
+    } else if max == g {
+        60.0 * (b - r) / chroma + 120.0
+    } else {
+        60.0 * (r - g) / chroma + 240.0
+    }
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn linear_to_oklab([r, g, b, _]: [f32; 4]) -> [f32; 3] {
+    let l = (0.41222147 * r + 0.53633254 * g + 0.05144599 * b).cbrt();
+    let m = (0.21190350 * r + 0.68069955 * g + 0.10739696 * b).cbrt();
+    let s = (0.08830246 * r + 0.28171884 * g + 0.62997870 * b).cbrt();
+    [
+        0.21045426 * l + 0.79361778 * m - 0.00407205 * s,
+        1.97799850 * l - 2.42859221 * m + 0.45059371 * s,
+        0.02590404 * l + 0.78277177 * m - 0.80867577 * s,
+    ]
+}
+
+fn oklab_to_linear([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = (l + 0.39633778 * a + 0.21580376 * b).powi(3);
+    let m_ = (l - 0.10556135 * a - 0.06385417 * b).powi(3);
+    let s_ = (l - 0.08948418 * a - 1.29148555 * b).powi(3);
+    [
+        4.07674166 * l_ - 3.30771159 * m_ + 0.23096993 * s_,
+        -1.26843800 * l_ + 2.60975740 * m_ - 0.34131940 * s_,
+        -0.00419609 * l_ - 0.70341861 * m_ + 1.70761470 * s_,
+    ]
+}
+
+///Sorted by name, `0xrrggbb`
+const CSS_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex_and_names() {
+        assert_eq!(
+            "#ff8000".parse::<Color>().unwrap(),
+            Color::new_rgb(255, 128, 0)
+        );
+        assert_eq!(
+            Color::from_hex("#1020307f").unwrap(),
+            Color::new_rgba(16, 32, 48, 127)
+        );
+        assert_eq!(Color::new_rgba(16, 32, 48, 127).to_hex(), "#1020307f");
+        assert!(Color::from_hex("#12345").is_err());
+        assert!(Color::from_hex("ff8000").is_err());
+        assert!(Color::from_hex("#ff80zz").is_err());
+        assert!(Color::from_hex("#+fffff").is_err());
+
+        assert!(CSS_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(
+            "RebeccaPurple".parse::<Color>().unwrap(),
+            Color::new_rgb(0x66, 0x33, 0x99)
+        );
+        assert_eq!(Color::from_name("transparent").unwrap(), Color::TRANSPARENT);
+        assert!(matches!(
+            Color::from_name("whine_red"),
+            Err(ColorParseError::UnknownName(_))
+        ));
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        for color in CSS_COLORS
+            .iter()
+            .map(|(_, c)| Color::from_rgba_u32(c << 8 | 0xff))
+        {
+            let [h, s, v, a] = color.to_hsva();
+            assert_eq!(Color::from_hsva(h, s, v, a), color);
+            let [h, s, l, a] = color.to_hsla();
+            assert_eq!(Color::from_hsla(h, s, l, a), color);
+            assert_eq!(Color::from_linear(color.to_linear()), color);
+        }
+        assert_eq!(
+            Color::from_hsva(120.0, 1.0, 1.0, 1.0),
+            Color::new_rgb(0, 255, 0)
+        );
+        assert_eq!(
+            Color::from_hsla(240.0, 1.0, 0.25, 1.0),
+            Color::new_rgb(0, 0, 128)
+        );
+        assert!((Color::new_rgb(188, 188, 188).to_linear()[0] - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn premultiplies_and_lerps() {
+        let color = Color::new_rgba(200, 100, 50, 102);
+        assert_eq!(color.premultiplied(), Color::new_rgba(80, 40, 20, 102));
+        assert_eq!(color.premultiplied().unpremultiplied(), color);
+
+        let (black, white) = (Color::BLACK, Color::WHITE);
+        assert_eq!(black.lerp(&white, 0.0), black);
+        assert_eq!(black.lerp(&white, 1.0), white);
+        // Halfway in linear light would be 188
+        let mid = black.lerp(&white, 0.5);
+        assert!(mid.r == mid.g && mid.g == mid.b && (95..110).contains(&mid.r));
+        assert_eq!(
+            Color::TRANSPARENT.lerp(&Color::RED.with_alpha(200), 0.5).a,
+            100
+        );
+    }
+}
@@ -1,12 +1,13 @@
 #![allow(deprecated)]
-use std::fs;
-use std::path::Path;
+use std::{path::Path, time::Instant};
 
 use wgpu::rwh::{HasRawDisplayHandle, HasRawWindowHandle};
 use winit::window::{Window, WindowId};
 
+use crate::frame_profiler::{FrameProfiler, Stage};
+
 mod buffer_primitives;
-pub use buffer_primitives::{Index, Vertex};
+pub use buffer_primitives::{Index, LightVertex, QuadVertex, SpriteInstance, Vertex};
 
 mod surface;
 use surface::{Surface, WindowSurface};
@@ -14,6 +15,35 @@ use surface::{Surface, WindowSurface};
 mod shader_descriptor;
 pub use shader_descriptor::ShaderDescriptor;
 
+mod shader_preprocessor;
+pub use shader_preprocessor::{
+    PreprocessedShader, ShaderPreprocessor, ShaderPreprocessorError, SourceLocation,
+};
+
+mod shader_validation;
+pub use shader_validation::{validate_pipeline, PipelineValidationError};
+
+mod post_process;
+pub use post_process::{
+    PostProcessDescriptor, PostProcessName, CHROMATIC_ABERRATION, COLOR_GRADING, CRT,
+    FILM_GRAIN, VIGNETTE,
+};
+use post_process::PostProcessChain;
+
+mod color;
+pub use color::{Color, ColorParseError};
+
+mod virtual_resolution;
+pub use virtual_resolution::{ScalingMode, Viewport, VirtualResolution};
+
+mod light_map;
+pub use light_map::LIGHT_COMPOSITE_SHADER;
+
+mod reference_rasterizer;
+pub use reference_rasterizer::{
+    compare_golden, GoldenMismatch, ReferenceRasterizer, UPDATE_GOLDENS_VARIABLE,
+};
+
 mod texture;
 pub use texture::DEFAULT_TEXTURE;
 use texture::TextureProvider;
@@ -23,7 +53,10 @@ pub use buffer_writer::{BufferWriter, IndexBufferWriter, VertexBufferWriter};
 
 mod render_scene;
 use render_scene::RenderScene;
-pub use render_scene::{RenderSceneDescriptor, RenderSceneName, UniformBufferName};
+pub use render_scene::{
+    RenderSceneDescriptor, RenderSceneKind, RenderSceneName, UniformBufferName, LIGHTING_SHADER,
+    SPRITE_BATCH_SHADER,
+};
 
 #[derive(Debug, Clone)]
 pub enum Visibility {
@@ -40,8 +73,11 @@ pub struct GraphicsProvider {
     surfaces: Vec<(WindowId, Box<dyn WindowSurface>)>,
     ///One to many relationship
     render_scenes: Vec<(WindowId, RenderScene, wgpu::ShaderModule, ShaderDescriptor)>,
+    ///One to one relationship, only for windows with post processing
+    post_process_chains: Vec<(WindowId, PostProcessChain)>,
     texture_provider: Option<TextureProvider>,
     uniform_buffers: Vec<(RenderSceneName, UniformBufferName)>,
+    profiler: FrameProfiler,
 }
 impl GraphicsProvider {
     pub fn new() -> Self {
@@ -56,17 +92,40 @@ impl GraphicsProvider {
             queue: None,
             surfaces: Vec::new(),
             render_scenes: Vec::new(),
+            post_process_chains: Vec::new(),
             uniform_buffers: Vec::new(),
             texture_provider: None,
+            profiler: FrameProfiler::new(),
         }
     }
 
+    pub fn profiler(&self) -> &FrameProfiler {
+        &self.profiler
+    }
+
+    pub fn profiler_mut(&mut self) -> &mut FrameProfiler {
+        &mut self.profiler
+    }
+
     pub fn set_visibility_render_scene(&mut self, render_scene: &RenderSceneName, visibility: &Visibility) {
         if let Some((_, scene, _, _)) = self.render_scenes.iter_mut().find(|(_, r, _, _)| r.name() == render_scene) {
             scene.set_visibility(visibility);
         }
     }
 
+    ///Render scenes of a window are drawn in the order they were added. Moves the render scene
+    ///to the end, so it is drawn above all others
+    pub fn bring_render_scene_to_front(&mut self, render_scene: &RenderSceneName) {
+        if let Some(index) = self
+            .render_scenes
+            .iter()
+            .position(|(_, r, _, _)| r.name() == render_scene)
+        {
+            let entry = self.render_scenes.remove(index);
+            self.render_scenes.push(entry);
+        }
+    }
+
     pub fn remove_render_scene(&mut self, render_scene: &RenderSceneName) {
         self.render_scenes
             .retain(|(_, r, _, _)| r.name() != render_scene);
@@ -160,6 +219,7 @@ impl GraphicsProvider {
             Box::new(Surface {
                 wgpu_surface: surface,
                 config,
+                virtual_resolution: None,
             }),
         ));
     }
@@ -168,8 +228,75 @@ impl GraphicsProvider {
         if let Some((_, surface)) = self.surfaces.iter_mut().find(|(i, _)| i == id) {
             if let Some(device) = &self.device {
                 surface.resize(new_size, device);
+                self.render_scenes
+                    .iter_mut()
+                    .filter(|(i, _, _, _)| i == id)
+                    .for_each(|(_, render_scene, _, _)| {
+                        render_scene.resize(device, surface.config())
+                    });
+                if let Some((_, chain)) = self.post_process_chains.iter_mut().find(|(i, _)| i == id)
+                {
+                    chain.resize(device, surface.config());
+                }
             }
         }
+        self.update_texture_filter();
+    }
+
+    ///Textures are shared by all windows, they are only sampled with nearest while that suits
+    ///every window
+    fn update_texture_filter(&mut self) {
+        if let (Some(device), Some(texture_provider)) = (&self.device, &mut self.texture_provider) {
+            let filter_mode = if self
+                .surfaces
+                .iter()
+                .all(|(_, surface)| surface.filter_mode() == wgpu::FilterMode::Nearest)
+            {
+                wgpu::FilterMode::Nearest
+            } else {
+                wgpu::FilterMode::Linear
+            };
+            texture_provider.set_filter_mode(device, filter_mode);
+        }
+    }
+
+    ///Maps the virtual resolution onto the window, drawing letterbox bars around it. `None`
+    ///draws over the whole window
+    pub fn set_virtual_resolution(
+        &mut self,
+        id: &WindowId,
+        virtual_resolution: Option<VirtualResolution>,
+    ) {
+        match self.surfaces.iter_mut().find(|(i, _)| i == id) {
+            Some((_, surface)) => surface.set_virtual_resolution(virtual_resolution),
+            None => panic!("No surface on window {:?}", id),
+        }
+        self.update_texture_filter();
+    }
+
+    ///Replaces the post process passes of the window. They are applied in order to everything the
+    ///RenderScenes of the window draw
+    pub fn set_post_processing(&mut self, id: &WindowId, passes: Vec<PostProcessDescriptor>) {
+        self.post_process_chains.retain(|(i, _)| i != id);
+        if passes.is_empty() {
+            return;
+        }
+        if let (Some((_, surface)), Some(device), Some(queue)) = (
+            self.surfaces.iter().find(|(i, _)| i == id),
+            &self.device,
+            &self.queue,
+        ) {
+            let chain = PostProcessChain::new(device, queue, surface.config(), passes);
+            self.post_process_chains.push((id.clone(), chain));
+        } else {
+            panic!("No surface on window {:?}", id)
+        }
+    }
+
+    pub fn set_post_process_enabled(&mut self, name: &PostProcessName, enabled: bool) {
+        for (_, chain) in self.post_process_chains.iter_mut() {
+            chain.set_enabled(name, enabled);
+        }
     }
 
     pub fn render_window(&mut self, id: &WindowId) {
@@ -184,7 +311,21 @@ impl GraphicsProvider {
                     .iter()
                     .filter_map(|(i, s, _, _)| if i == id { Some(s) } else { None })
                     .collect::<Vec<_>>();
-                surface.render(device, queue, &render_scenes, texture_bind_group);
+                let post_process_chain = self
+                    .post_process_chains
+                    .iter_mut()
+                    .find(|(i, _)| i == id)
+                    .map(|(_, chain)| chain);
+                let start = Instant::now();
+                surface.render(
+                    device,
+                    queue,
+                    &render_scenes,
+                    texture_bind_group,
+                    post_process_chain,
+                );
+                self.profiler
+                    .record_scene(&format!("{:?}", id), Stage::Draw, start);
             }
         }
     }
@@ -223,16 +364,20 @@ impl GraphicsProvider {
             self.surfaces.iter().find(|(id, _)| id == window_id),
             &self.texture_provider,
         ) {
+            let shader_source = ShaderPreprocessor::for_render_scene(&render_scene_descriptor)
+                .process(shader_descriptor.file)
+                .and_then(|shader| shader.validate().map(|_| shader.source))
+                .unwrap_or_else(|err| panic!("Invalid shader: {}\n", err));
             let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
                 label: Some(&format!("Shader Module {:?}", shader_descriptor.file)),
-                source: wgpu::ShaderSource::Wgsl(
-                    fs::read_to_string(shader_descriptor.file)
-                        .expect(&format!("Could not load '{}'\n", shader_descriptor.file))
-                        .into(),
-                ),
+                source: wgpu::ShaderSource::Wgsl(shader_source.into()),
             });
-            let mut render_scene =
-                RenderScene::new(render_scene_name.clone(), device, render_scene_descriptor);
+            let mut render_scene = RenderScene::new(
+                render_scene_name.clone(),
+                device,
+                surface.config(),
+                render_scene_descriptor,
+            );
             for (uniform, content, visibility) in initial_uniforms {
                 render_scene.create_uniform_buffer(
                     device,
@@ -254,7 +399,8 @@ impl GraphicsProvider {
                 &bind_groups_layouts,
                 &shader,
                 &shader_descriptor,
-                render_scene.vertex_buffer_layout().clone(),
+                render_scene.vertex_buffer_layouts(),
+                render_scene.kind(),
             );
             render_scene.update_pipeline(render_pipeline);
             self.render_scenes
@@ -266,6 +412,7 @@ impl GraphicsProvider {
 
     pub fn remove_window(&mut self, id: &WindowId) {
         self.surfaces.retain(|(i, _)| i != id);
+        self.post_process_chains.retain(|(i, _)| i != id);
         let render_scenes_to_delete = self
             .render_scenes
             .iter()
@@ -281,34 +428,81 @@ impl GraphicsProvider {
             (&self.device, &self.queue, &mut self.texture_provider)
         {
             let index = texture_provider.create_texture(device, queue, path, Some(label));
-            let texture_bind_group_layout = texture_provider
-                .bind_group_layout
-                .as_ref()
-                .expect("No texture bind group layout");
-            self.render_scenes
-                .iter_mut()
-                .filter(|(_, s, _, _)| s.use_textures())
-                .for_each(|(window_id, render_scene, shader, shader_descriptor)| {
-                    if let Some((_, surface)) = self.surfaces.iter().find(|(id, _)| id == window_id)
-                    {
-                        let bind_groups_layouts =
-                            render_scene.bind_group_layouts(texture_bind_group_layout);
-                        let render_pipeline = surface.create_render_pipeline(
-                            device,
-                            &bind_groups_layouts,
-                            shader,
-                            shader_descriptor,
-                            render_scene.vertex_buffer_layout().clone(),
-                        );
-                        render_scene.update_pipeline(render_pipeline);
-                    }
-                });
+            self.update_texture_pipelines();
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    ///Creates the texture `label` from RGBA8 `pixels` or overwrites it, for textures drawn at
+    ///runtime like glyph atlases. The texture keeps its index when its size changes
+    pub fn write_texture(
+        &mut self,
+        label: &str,
+        pixels: &[u8],
+        size: winit::dpi::PhysicalSize<u32>,
+    ) -> Option<u32> {
+        if let (Some(device), Some(queue), Some(texture_provider)) =
+            (&self.device, &self.queue, &mut self.texture_provider)
+        {
+            let is_new = texture_provider.get_texture_index(Some(label)).is_none();
+            let index = texture_provider.write_texture(device, queue, label, pixels, size);
+            if is_new {
+                self.update_texture_pipelines();
+            }
             Some(index)
         } else {
             None
         }
     }
 
+    ///Overwrites part of a texture `write_texture` created, `pixels` being the RGBA8 of the `size`
+    ///pixels at `origin`. None if the texture does not exist or is too small
+    pub fn write_texture_region(
+        &mut self,
+        label: &str,
+        pixels: &[u8],
+        origin: winit::dpi::PhysicalPosition<u32>,
+        size: winit::dpi::PhysicalSize<u32>,
+    ) -> Option<u32> {
+        let (Some(queue), Some(texture_provider)) = (&self.queue, &mut self.texture_provider)
+        else {
+            return None;
+        };
+        texture_provider.write_texture_region(queue, label, pixels, origin, size)
+    }
+
+    ///The texture bind group layout holds one entry per texture, so pipelines using textures
+    ///have to be recreated with every new texture
+    fn update_texture_pipelines(&mut self) {
+        let (Some(device), Some(texture_provider)) = (&self.device, &self.texture_provider) else {
+            return;
+        };
+        let texture_bind_group_layout = texture_provider
+            .bind_group_layout
+            .as_ref()
+            .expect("No texture bind group layout");
+        self.render_scenes
+            .iter_mut()
+            .filter(|(_, s, _, _)| s.use_textures())
+            .for_each(|(window_id, render_scene, shader, shader_descriptor)| {
+                if let Some((_, surface)) = self.surfaces.iter().find(|(id, _)| id == window_id) {
+                    let bind_groups_layouts =
+                        render_scene.bind_group_layouts(texture_bind_group_layout);
+                    let render_pipeline = surface.create_render_pipeline(
+                        device,
+                        &bind_groups_layouts,
+                        shader,
+                        shader_descriptor,
+                        render_scene.vertex_buffer_layouts(),
+                        render_scene.kind(),
+                    );
+                    render_scene.update_pipeline(render_pipeline);
+                }
+            });
+    }
+
     pub fn create_uniform_buffer(
         &mut self,
         label: impl Into<UniformBufferName>,
@@ -335,7 +529,13 @@ impl GraphicsProvider {
         }
     }
 
+    ///Also updates the uniforms of post process passes
     pub fn update_uniform_buffer(&self, label: &UniformBufferName, contents: &[u8]) {
+        if let Some(queue) = &self.queue {
+            for (_, chain) in &self.post_process_chains {
+                chain.update_uniform_buffer(queue, label, contents);
+            }
+        }
         if let Some((target_render_scene, _)) =
             self.uniform_buffers.iter().find(|(_, u)| u == label)
         {
@@ -0,0 +1,550 @@
+use std::{path::Path, time::Instant};
+
+use wgpu::util::DeviceExt;
+
+use crate::create_name_struct;
+
+use super::{texture::Texture, ShaderPreprocessor, UniformBufferName};
+
+create_name_struct!(PostProcessName);
+
+pub const VIGNETTE: &str = "Vignette";
+pub const FILM_GRAIN: &str = "FilmGrain";
+pub const CHROMATIC_ABERRATION: &str = "ChromaticAberration";
+pub const CRT: &str = "Crt";
+pub const COLOR_GRADING: &str = "ColorGrading";
+
+///A full screen pass applied to everything a window renders. The shader is drawn as one
+///triangle, include `ferride/shader/post_process/common.wgsl` for the vertex shader `vs_main`,
+///the input texture and the `PostProcessGlobals`
+#[derive(Debug, Clone)]
+pub struct PostProcessDescriptor {
+    pub name: PostProcessName,
+    pub file: &'static str,
+    pub fragment_shader: &'static str,
+    ///Bound as @group(2 + index) @binding(0). Update them with
+    ///`GraphicsProvider::update_uniform_buffer`
+    pub uniforms: Vec<(UniformBufferName, Vec<u8>)>,
+    ///Additional textures like color grading lookup tables. Bound as
+    ///@group(0) @binding(2 + index)
+    pub textures: Vec<&'static str>,
+    pub shader_defines: &'static [&'static str],
+}
+impl PostProcessDescriptor {
+    pub fn new(name: impl Into<PostProcessName>, file: &'static str) -> Self {
+        Self {
+            name: name.into(),
+            file,
+            fragment_shader: "fs_main",
+            uniforms: Vec::new(),
+            textures: Vec::new(),
+            shader_defines: &[],
+        }
+    }
+
+    pub fn with_uniform(mut self, name: impl Into<UniformBufferName>, contents: &[u8]) -> Self {
+        self.uniforms.push((name.into(), contents.to_vec()));
+        self
+    }
+
+    pub fn with_texture(mut self, path: &'static str) -> Self {
+        self.textures.push(path);
+        self
+    }
+
+    pub fn with_shader_defines(mut self, shader_defines: &'static [&'static str]) -> Self {
+        self.shader_defines = shader_defines;
+        self
+    }
+
+    ///Fades the edges of the screen into `color`. `radius` and `softness` are measured from the
+    ///center, where 0.5 touches the sides of the screen. The uniform is named `VIGNETTE`
+    pub fn vignette(color: [f32; 4], intensity: f32, radius: f32, softness: f32) -> Self {
+        Self::new(VIGNETTE, "ferride/shader/post_process/vignette.wgsl").with_uniform(
+            VIGNETTE,
+            bytemuck::cast_slice(&[
+                color[0], color[1], color[2], color[3], intensity, radius, softness, 0.0,
+            ]),
+        )
+    }
+
+    ///Animated noise. `speed` is the number of new grain patterns per second and `grain_size`
+    ///the size of one grain in pixels. The uniform is named `FILM_GRAIN`
+    pub fn film_grain(intensity: f32, speed: f32, grain_size: f32) -> Self {
+        Self::new(FILM_GRAIN, "ferride/shader/post_process/film_grain.wgsl").with_uniform(
+            FILM_GRAIN,
+            bytemuck::cast_slice(&[intensity, speed, grain_size, 0.0]),
+        )
+    }
+
+    ///Splits red and blue towards the edges of the screen by up to `offset` pixels. The uniform
+    ///is named `CHROMATIC_ABERRATION`
+    pub fn chromatic_aberration(offset: f32) -> Self {
+        Self::new(
+            CHROMATIC_ABERRATION,
+            "ferride/shader/post_process/chromatic_aberration.wgsl",
+        )
+        .with_uniform(
+            CHROMATIC_ABERRATION,
+            bytemuck::cast_slice(&[offset, 0.0, 0.0, 0.0]),
+        )
+    }
+
+    ///Curved screen with scanlines. A `scanline_count` of 0 draws one scanline per pixel row.
+    ///The uniform is named `CRT`
+    pub fn crt(curvature: f32, scanline_intensity: f32, scanline_count: f32) -> Self {
+        Self::new(CRT, "ferride/shader/post_process/crt.wgsl").with_uniform(
+            CRT,
+            bytemuck::cast_slice(&[curvature, scanline_intensity, scanline_count, 0.0]),
+        )
+    }
+
+    ///Maps colors through a lookup table, a horizontal strip of N slices with N*N pixels each
+    ///(e.g. 256x16). The uniform is named `COLOR_GRADING`
+    pub fn color_grading(lut: &'static str, intensity: f32) -> Self {
+        Self::new(
+            COLOR_GRADING,
+            "ferride/shader/post_process/color_grading.wgsl",
+        )
+        .with_texture(lut)
+        .with_uniform(
+            COLOR_GRADING,
+            bytemuck::cast_slice(&[intensity, 0.0, 0.0, 0.0]),
+        )
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct PostProcessGlobals {
+    resolution: [f32; 2],
+    time: f32,
+    frame: u32,
+}
+
+struct RenderTarget {
+    view: wgpu::TextureView,
+}
+impl RenderTarget {
+    fn new(device: &wgpu::Device, format: wgpu::TextureFormat, width: u32, height: u32) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Post Process Target"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        Self {
+            view: texture.create_view(&wgpu::TextureViewDescriptor::default()),
+        }
+    }
+}
+
+struct PostProcessPass {
+    name: PostProcessName,
+    enabled: bool,
+    render_pipeline: wgpu::RenderPipeline,
+    textures: Vec<Texture>,
+    input_bind_group_layout: wgpu::BindGroupLayout,
+    ///One per RenderTarget it can read from
+    input_bind_groups: Vec<wgpu::BindGroup>,
+    uniform_buffers: Vec<(UniformBufferName, wgpu::Buffer, wgpu::BindGroup)>,
+}
+
+///The post-process passes of one window and the offscreen targets they read from and write to
+pub struct PostProcessChain {
+    format: wgpu::TextureFormat,
+    size: (u32, u32),
+    sampler: wgpu::Sampler,
+    targets: [RenderTarget; 2],
+    globals_buffer: wgpu::Buffer,
+    globals_bind_group_layout: wgpu::BindGroupLayout,
+    globals_bind_group: wgpu::BindGroup,
+    passes: Vec<PostProcessPass>,
+    start: Instant,
+    frame: u32,
+}
+impl PostProcessChain {
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        descriptors: Vec<PostProcessDescriptor>,
+    ) -> Self {
+        let size = (config.width, config.height);
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Post Process Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+        let globals_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Post Process Globals"),
+            contents: bytemuck::cast_slice(&[PostProcessGlobals {
+                resolution: [size.0 as f32, size.1 as f32],
+                time: 0.0,
+                frame: 0,
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let globals_bind_group_layout = uniform_bind_group_layout(device, "Post Process Globals");
+        let globals_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Post Process Globals"),
+            layout: &globals_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: globals_buffer.as_entire_binding(),
+            }],
+        });
+        let mut chain = Self {
+            format: config.format,
+            size,
+            sampler,
+            targets: [
+                RenderTarget::new(device, config.format, size.0, size.1),
+                RenderTarget::new(device, config.format, size.0, size.1),
+            ],
+            globals_buffer,
+            globals_bind_group_layout,
+            globals_bind_group,
+            passes: Vec::new(),
+            start: Instant::now(),
+            frame: 0,
+        };
+        for descriptor in descriptors {
+            let pass = chain.create_pass(device, queue, descriptor);
+            chain.passes.push(pass);
+        }
+        chain
+    }
+
+    fn create_pass(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        descriptor: PostProcessDescriptor,
+    ) -> PostProcessPass {
+        let source = ShaderPreprocessor::new()
+            .with_defines(descriptor.shader_defines)
+            .process(descriptor.file)
+            .and_then(|shader| shader.validate().map(|_| shader.source))
+            .unwrap_or_else(|err| panic!("Invalid post process shader: {}\n", err));
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(&format!("Post Process Shader {:?}", descriptor.name)),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
+        });
+
+        let textures = descriptor
+            .textures
+            .iter()
+            .map(|path| {
+                Texture::new(
+                    device,
+                    queue,
+                    Path::new(path),
+                    Some(path),
+                    wgpu::FilterMode::Nearest,
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut input_entries = vec![
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    multisampled: false,
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            },
+        ];
+        for i in 0..textures.len() {
+            input_entries.push(wgpu::BindGroupLayoutEntry {
+                binding: 2 + i as u32,
+                ..input_entries[0]
+            });
+        }
+        let input_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some(&format!("Post Process Input {:?}", descriptor.name)),
+                entries: &input_entries,
+            });
+
+        let uniform_buffers = descriptor
+            .uniforms
+            .iter()
+            .map(|(name, contents)| {
+                let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(name.as_str()),
+                    contents,
+                    usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                });
+                let layout = uniform_bind_group_layout(device, name.as_str());
+                let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some(name.as_str()),
+                    layout: &layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: buffer.as_entire_binding(),
+                    }],
+                });
+                (name.clone(), buffer, layout, bind_group)
+            })
+            .collect::<Vec<_>>();
+
+        let mut bind_group_layouts =
+            vec![&input_bind_group_layout, &self.globals_bind_group_layout];
+        bind_group_layouts.extend(uniform_buffers.iter().map(|(_, _, layout, _)| layout));
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Post Process Pipeline Layout"),
+            bind_group_layouts: &bind_group_layouts,
+            push_constant_ranges: &[],
+        });
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some(&format!("Post Process Pipeline {:?}", descriptor.name)),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: descriptor.fragment_shader,
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: self.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let mut pass = PostProcessPass {
+            name: descriptor.name,
+            enabled: true,
+            render_pipeline,
+            textures,
+            input_bind_group_layout,
+            input_bind_groups: Vec::new(),
+            uniform_buffers: uniform_buffers
+                .into_iter()
+                .map(|(name, buffer, _, bind_group)| (name, buffer, bind_group))
+                .collect(),
+        };
+        pass.input_bind_groups = self.input_bind_groups(device, &pass);
+        pass
+    }
+
+    fn input_bind_groups(
+        &self,
+        device: &wgpu::Device,
+        pass: &PostProcessPass,
+    ) -> Vec<wgpu::BindGroup> {
+        self.targets
+            .iter()
+            .map(|target| {
+                let mut entries = vec![
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&target.view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&self.sampler),
+                    },
+                ];
+                for (i, texture) in pass.textures.iter().enumerate() {
+                    entries.push(wgpu::BindGroupEntry {
+                        binding: 2 + i as u32,
+                        resource: wgpu::BindingResource::TextureView(&texture.view),
+                    });
+                }
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some(&format!("Post Process Input {:?}", pass.name)),
+                    layout: &pass.input_bind_group_layout,
+                    entries: &entries,
+                })
+            })
+            .collect()
+    }
+
+    pub fn resize(&mut self, device: &wgpu::Device, config: &wgpu::SurfaceConfiguration) {
+        if (config.width, config.height) == self.size || config.width == 0 || config.height == 0 {
+            return;
+        }
+        self.size = (config.width, config.height);
+        self.targets = [
+            RenderTarget::new(device, self.format, self.size.0, self.size.1),
+            RenderTarget::new(device, self.format, self.size.0, self.size.1),
+        ];
+        let input_bind_groups = self
+            .passes
+            .iter()
+            .map(|pass| self.input_bind_groups(device, pass))
+            .collect::<Vec<_>>();
+        for (pass, bind_groups) in self.passes.iter_mut().zip(input_bind_groups) {
+            pass.input_bind_groups = bind_groups;
+        }
+    }
+
+    pub fn set_enabled(&mut self, name: &PostProcessName, enabled: bool) {
+        for pass in self.passes.iter_mut().filter(|p| &p.name == name) {
+            pass.enabled = enabled;
+        }
+    }
+
+    ///Returns false if the chain has no uniform with this name
+    pub fn update_uniform_buffer(
+        &self,
+        queue: &wgpu::Queue,
+        name: &UniformBufferName,
+        data: &[u8],
+    ) -> bool {
+        let mut found = false;
+        for (_, buffer, _) in self
+            .passes
+            .iter()
+            .flat_map(|p| p.uniform_buffers.iter())
+            .filter(|(n, _, _)| n == name)
+        {
+            queue.write_buffer(buffer, 0, data);
+            found = true;
+        }
+        found
+    }
+
+    ///Where the RenderScenes should draw to. None if every pass is disabled, in which case they
+    ///draw to the window directly
+    pub fn scene_target(&self) -> Option<&wgpu::TextureView> {
+        if self.passes.iter().any(|p| p.enabled) {
+            Some(&self.targets[0].view)
+        } else {
+            None
+        }
+    }
+
+    ///Runs every enabled pass, starting with the output of the RenderScenes in the first target
+    ///and ending in `output`
+    pub fn render(
+        &mut self,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        output: &wgpu::TextureView,
+    ) {
+        queue.write_buffer(
+            &self.globals_buffer,
+            0,
+            bytemuck::cast_slice(&[PostProcessGlobals {
+                resolution: [self.size.0 as f32, self.size.1 as f32],
+                time: self.start.elapsed().as_secs_f32(),
+                frame: self.frame,
+            }]),
+        );
+        self.frame = self.frame.wrapping_add(1);
+
+        let passes = self.passes.iter().filter(|p| p.enabled).collect::<Vec<_>>();
+        for (i, pass) in passes.iter().enumerate() {
+            let target = if i + 1 == passes.len() {
+                output
+            } else {
+                &self.targets[(i + 1) % 2].view
+            };
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some(&format!("Post Process Pass {:?}", pass.name)),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            render_pass.set_pipeline(&pass.render_pipeline);
+            render_pass.set_bind_group(0, &pass.input_bind_groups[i % 2], &[]);
+            render_pass.set_bind_group(1, &self.globals_bind_group, &[]);
+            for (j, (_, _, bind_group)) in pass.uniform_buffers.iter().enumerate() {
+                render_pass.set_bind_group(2 + j as u32, bind_group, &[]);
+            }
+            render_pass.draw(0..3, 0..1);
+        }
+    }
+}
+
+fn uniform_bind_group_layout(device: &wgpu::Device, label: &str) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some(label),
+        entries: &[wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_passes_are_valid() {
+        for pass in [
+            PostProcessDescriptor::vignette([0.0, 0.0, 0.0, 1.0], 0.5, 0.75, 0.4),
+            PostProcessDescriptor::film_grain(0.1, 24.0, 1.0),
+            PostProcessDescriptor::chromatic_aberration(2.0),
+            PostProcessDescriptor::crt(0.05, 0.3, 0.0),
+            PostProcessDescriptor::color_grading("lut.png", 1.0),
+        ] {
+            let module = ShaderPreprocessor::new()
+                .with_defines(pass.shader_defines)
+                .process(pass.file)
+                .and_then(|shader| shader.validate())
+                .unwrap_or_else(|err| panic!("{:?}: {}", pass.name, err));
+            for (_, variable) in module.global_variables.iter() {
+                let Some(binding) = &variable.binding else {
+                    continue;
+                };
+                if binding.group < 2 {
+                    continue;
+                }
+                let (uniform, contents) = &pass.uniforms[binding.group as usize - 2];
+                assert_eq!(
+                    module.types[variable.ty].inner.size(module.to_ctx()) as usize,
+                    contents.len(),
+                    "Size of {:?}",
+                    uniform
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,524 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+use image::{Rgba, RgbaImage};
+use winit::dpi::PhysicalSize;
+
+use super::{IndexBufferWriter, QuadVertex, SpriteInstance, VertexBufferWriter};
+
+///Set to create or regenerate golden images instead of comparing against them
+pub const UPDATE_GOLDENS_VARIABLE: &str = "FERRIDE_UPDATE_GOLDENS";
+
+///Software stand in for the GPU to test what entities render without a device. Draws sprite
+///batch instances like `SPRITE_BATCH_SHADER`, with nearest sampling and alpha blending
+pub struct ReferenceRasterizer {
+    size: PhysicalSize<u32>,
+    camera: [[f32; 2]; 3],
+    textures: Vec<(u32, RgbaImage)>,
+    clear_color: [u8; 4],
+}
+impl ReferenceRasterizer {
+    ///Starts with an identity camera and the white default texture as texture 0
+    pub fn new(size: PhysicalSize<u32>) -> Self {
+        Self {
+            size,
+            camera: [[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]],
+            textures: vec![(0, RgbaImage::from_pixel(1, 1, Rgba([255; 4])))],
+            clear_color: [0; 4],
+        }
+    }
+
+    ///Contents of the camera uniform buffer, a `mat3x2<f32>`
+    pub fn with_camera(mut self, camera: &[u8]) -> Self {
+        let values: Vec<f32> = camera
+            .chunks_exact(4)
+            .map(|v| f32::from_ne_bytes([v[0], v[1], v[2], v[3]]))
+            .collect();
+        assert!(values.len() >= 6, "A camera uniform has six floats");
+        self.camera = [
+            [values[0], values[1]],
+            [values[2], values[3]],
+            [values[4], values[5]],
+        ];
+        self
+    }
+
+    ///`id` is the texture index the vertices reference, e.g. `SpriteSheet::texture()`
+    pub fn with_texture(mut self, id: u32, texture: RgbaImage) -> Self {
+        self.textures.retain(|(i, _)| *i != id);
+        self.textures.push((id, texture));
+        self
+    }
+
+    ///Loads the texture through the virtual file system
+    pub fn with_texture_file(self, id: u32, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let bytes = crate::file_system::read(path)
+            .unwrap_or_else(|err| panic!("Could not read texture {:?}: {}", path, err));
+        let texture = image::load_from_memory(&bytes)
+            .unwrap_or_else(|err| panic!("Could not load texture {:?}: {}", path, err))
+            .to_rgba8();
+        self.with_texture(id, texture)
+    }
+
+    pub fn with_clear_color(mut self, clear_color: [u8; 4]) -> Self {
+        self.clear_color = clear_color;
+        self
+    }
+
+    pub fn render(
+        &self,
+        vertices: &impl VertexBufferWriter,
+        indices: &impl IndexBufferWriter,
+        vertex_buffer_layout: &wgpu::VertexBufferLayout,
+    ) -> RgbaImage {
+        let mut target =
+            RgbaImage::from_pixel(self.size.width, self.size.height, Rgba(self.clear_color));
+        self.draw(&mut target, vertices, indices, vertex_buffer_layout);
+        target
+    }
+
+    ///Draws on top of `target`, like another RenderScene of the same window would
+    pub fn draw(
+        &self,
+        target: &mut RgbaImage,
+        vertices: &impl VertexBufferWriter,
+        indices: &impl IndexBufferWriter,
+        vertex_buffer_layout: &wgpu::VertexBufferLayout,
+    ) {
+        let vertex_data = vertices.buffer_data().unwrap_or(&[]);
+        if vertex_buffer_layout.step_mode == wgpu::VertexStepMode::Instance {
+            self.draw_instances(target, vertex_data);
+            return;
+        }
+        let index_data = indices.buffer_data().unwrap_or(&[]);
+        let indices: Vec<u32> = match indices.index_format() {
+            Some(wgpu::IndexFormat::Uint32) => index_data
+                .chunks_exact(4)
+                .map(|i| u32::from_ne_bytes([i[0], i[1], i[2], i[3]]))
+                .collect(),
+            _ => index_data
+                .chunks_exact(2)
+                .map(|i| u16::from_ne_bytes([i[0], i[1]]) as u32)
+                .collect(),
+        };
+        let stride = vertex_buffer_layout.array_stride as usize;
+        let vertices: Vec<ShadedVertex> = vertex_data
+            .chunks_exact(stride)
+            .map(|vertex| ShadedVertex::new(vertex, vertex_buffer_layout.attributes))
+            .map(|vertex| ShadedVertex {
+                position: self.project(vertex.position, target.dimensions()),
+                ..vertex
+            })
+            .collect();
+        for triangle in indices.chunks_exact(3) {
+            let corners = [
+                &vertices[triangle[0] as usize],
+                &vertices[triangle[1] as usize],
+                &vertices[triangle[2] as usize],
+            ];
+            self.draw_triangle(target, corners);
+        }
+    }
+
+    fn draw_instances(&self, target: &mut RgbaImage, instance_data: &[u8]) {
+        let stride = std::mem::size_of::<SpriteInstance>();
+        for data in instance_data.chunks_exact(stride) {
+            let instance: SpriteInstance = bytemuck::pod_read_unaligned(data);
+            let corners = instance
+                .corners()
+                .map(|(position, tex_coords)| ShadedVertex {
+                    position: self.project(position, target.dimensions()),
+                    tex_coords,
+                    texture: instance.texture,
+                    blend_color: instance.color,
+                    color: None,
+                });
+            for triangle in QuadVertex::INDICES.chunks_exact(3) {
+                self.draw_triangle(
+                    target,
+                    [
+                        &corners[triangle[0] as usize],
+                        &corners[triangle[1] as usize],
+                        &corners[triangle[2] as usize],
+                    ],
+                );
+            }
+        }
+    }
+
+    ///Camera transform followed by the viewport transform into pixel coordinates
+    fn project(&self, position: [f32; 2], (width, height): (u32, u32)) -> [f32; 2] {
+        let c = &self.camera;
+        let x = c[0][0] * position[0] + c[1][0] * position[1] + c[2][0];
+        let y = c[0][1] * position[0] + c[1][1] * position[1] + c[2][1];
+        [
+            (x + 1.0) / 2.0 * width as f32,
+            (1.0 - y) / 2.0 * height as f32,
+        ]
+    }
+
+    fn draw_triangle(&self, target: &mut RgbaImage, [a, b, c]: [&ShadedVertex; 3]) {
+        let edge = |p: [f32; 2], q: [f32; 2], r: [f32; 2]| {
+            (q[0] - p[0]) * (r[1] - p[1]) - (q[1] - p[1]) * (r[0] - p[0])
+        };
+        let area = edge(a.position, b.position, c.position);
+        if area == 0.0 {
+            return;
+        }
+        // Nothing is culled, so both windings are brought into the same orientation
+        let (b, c) = if area < 0.0 { (c, b) } else { (b, c) };
+        let area = area.abs();
+        // Pixels on an edge shared by two triangles belong to only one of them, which traverse
+        // the edge in opposite directions
+        let owns_edge = |p: [f32; 2], q: [f32; 2]| q[1] > p[1] || (q[1] == p[1] && q[0] > p[0]);
+        let owned = [
+            owns_edge(b.position, c.position),
+            owns_edge(c.position, a.position),
+            owns_edge(a.position, b.position),
+        ];
+        let min_x = a.position[0]
+            .min(b.position[0])
+            .min(c.position[0])
+            .floor()
+            .max(0.0) as u32;
+        let min_y = a.position[1]
+            .min(b.position[1])
+            .min(c.position[1])
+            .floor()
+            .max(0.0) as u32;
+        let max_x =
+            (a.position[0].max(b.position[0]).max(c.position[0]).ceil() as u32).min(target.width());
+        let max_y = (a.position[1].max(b.position[1]).max(c.position[1]).ceil() as u32)
+            .min(target.height());
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let p = [x as f32 + 0.5, y as f32 + 0.5];
+                let weights = [
+                    edge(b.position, c.position, p) / area,
+                    edge(c.position, a.position, p) / area,
+                    edge(a.position, b.position, p) / area,
+                ];
+                if weights
+                    .iter()
+                    .zip(owned)
+                    .any(|(w, owned)| *w < 0.0 || (*w == 0.0 && !owned))
+                {
+                    continue;
+                }
+                let tex_coords = [
+                    weights[0] * a.tex_coords[0]
+                        + weights[1] * b.tex_coords[0]
+                        + weights[2] * c.tex_coords[0],
+                    weights[0] * a.tex_coords[1]
+                        + weights[1] * b.tex_coords[1]
+                        + weights[2] * c.tex_coords[1],
+                ];
+                // Integer attributes are flat and taken from the first vertex, like in wgpu
+                let color = self.shade(a, tex_coords);
+                blend(target.get_pixel_mut(x, y), color);
+            }
+        }
+    }
+
+    fn shade(&self, vertex: &ShadedVertex, tex_coords: [f32; 2]) -> [f32; 4] {
+        if let Some(color) = vertex.color {
+            return unpack_color(color);
+        }
+        let mut sample = self.sample(vertex.texture, tex_coords);
+        if vertex.blend_color != 0 {
+            let alpha = sample[3];
+            let blend_color = unpack_color(vertex.blend_color);
+            for i in 0..4 {
+                sample[i] *= blend_color[i];
+            }
+            if alpha == 0.0 {
+                sample[3] = alpha;
+            }
+        }
+        sample
+    }
+
+    fn sample(&self, texture: u32, [u, v]: [f32; 2]) -> [f32; 4] {
+        let Some((_, texture)) = self.textures.iter().find(|(id, _)| *id == texture) else {
+            panic!(
+                "Texture {} is not known to the ReferenceRasterizer",
+                texture
+            )
+        };
+        let x = ((u * texture.width() as f32) as u32).min(texture.width() - 1);
+        let y = ((v * texture.height() as f32) as u32).min(texture.height() - 1);
+        texture.get_pixel(x, y).0.map(|c| c as f32 / 255.0)
+    }
+}
+
+struct ShadedVertex {
+    position: [f32; 2],
+    tex_coords: [f32; 2],
+    texture: u32,
+    blend_color: u32,
+    ///Flat color of vertices without texture coordinates
+    color: Option<u32>,
+}
+impl ShadedVertex {
+    fn new(data: &[u8], attributes: &[wgpu::VertexAttribute]) -> Self {
+        let mut vertex = Self {
+            position: [0.0; 2],
+            tex_coords: [0.0; 2],
+            texture: 0,
+            blend_color: 0,
+            color: None,
+        };
+        for attribute in attributes {
+            let offset = attribute.offset as usize;
+            let word = |i: usize| {
+                let start = offset + 4 * i;
+                [
+                    data[start],
+                    data[start + 1],
+                    data[start + 2],
+                    data[start + 3],
+                ]
+            };
+            let float2 = || [f32::from_ne_bytes(word(0)), f32::from_ne_bytes(word(1))];
+            match (attribute.shader_location, attribute.format) {
+                (0, wgpu::VertexFormat::Float32x2) => vertex.position = float2(),
+                (1, wgpu::VertexFormat::Float32x2) => vertex.tex_coords = float2(),
+                (1, wgpu::VertexFormat::Uint32) => vertex.color = Some(u32::from_ne_bytes(word(0))),
+                (2, wgpu::VertexFormat::Uint32) => vertex.texture = u32::from_ne_bytes(word(0)),
+                (3, wgpu::VertexFormat::Uint32) => vertex.blend_color = u32::from_ne_bytes(word(0)),
+                (location, format) => panic!(
+                    "The ReferenceRasterizer does not know @location({}) as {:?}",
+                    location, format
+                ),
+            }
+        }
+        vertex
+    }
+}
+
+///RGBA with red in the most significant byte
+fn unpack_color(color: u32) -> [f32; 4] {
+    color.to_be_bytes().map(|c| c as f32 / 255.0)
+}
+
+///`wgpu::BlendState::ALPHA_BLENDING`
+fn blend(destination: &mut Rgba<u8>, source: [f32; 4]) {
+    let alpha = source[3];
+    let destination_color = destination.0.map(|c| c as f32 / 255.0);
+    for i in 0..3 {
+        let color = source[i] * alpha + destination_color[i] * (1.0 - alpha);
+        destination.0[i] = (color.clamp(0.0, 1.0) * 255.0).round() as u8;
+    }
+    let alpha = alpha + destination_color[3] * (1.0 - alpha);
+    destination.0[3] = (alpha.clamp(0.0, 1.0) * 255.0).round() as u8;
+}
+
+#[derive(Debug)]
+pub enum GoldenMismatch {
+    ///There is no golden at the path, it is only written if `UPDATE_GOLDENS_VARIABLE` is set
+    Missing(PathBuf),
+    Io(io::Error),
+    Image(image::ImageError),
+    Size {
+        expected: (u32, u32),
+        actual: (u32, u32),
+    },
+    Pixels {
+        count: usize,
+        first: (u32, u32),
+    },
+}
+impl Display for GoldenMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(golden) => write!(
+                f,
+                "Golden {:?} does not exist. Set {} to create it",
+                golden, UPDATE_GOLDENS_VARIABLE
+            ),
+            Self::Io(err) => write!(f, "{}", err),
+            Self::Image(err) => write!(f, "{}", err),
+            Self::Size { expected, actual } => write!(
+                f,
+                "Golden is {}x{}, but the image is {}x{}",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            Self::Pixels { count, first } => write!(
+                f,
+                "{} pixels differ from the golden, the first at {:?}. Set {} to update it",
+                count, first, UPDATE_GOLDENS_VARIABLE
+            ),
+        }
+    }
+}
+impl Error for GoldenMismatch {}
+impl From<io::Error> for GoldenMismatch {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+impl From<image::ImageError> for GoldenMismatch {
+    fn from(err: image::ImageError) -> Self {
+        Self::Image(err)
+    }
+}
+
+///Compares `image` with the PNG at `golden`. Channels may differ by `tolerance`. Writes the
+///golden instead, if `UPDATE_GOLDENS_VARIABLE` is set
+pub fn compare_golden(
+    image: &RgbaImage,
+    golden: impl AsRef<Path>,
+    tolerance: u8,
+) -> Result<(), GoldenMismatch> {
+    let golden = golden.as_ref();
+    if env::var_os(UPDATE_GOLDENS_VARIABLE).is_some() {
+        if let Some(parent) = golden.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        image.save(golden)?;
+        return Ok(());
+    }
+    if !golden.exists() {
+        return Err(GoldenMismatch::Missing(golden.to_path_buf()));
+    }
+    let expected = image::open(golden)?.to_rgba8();
+    if expected.dimensions() != image.dimensions() {
+        return Err(GoldenMismatch::Size {
+            expected: expected.dimensions(),
+            actual: image.dimensions(),
+        });
+    }
+    let mismatches: Vec<(u32, u32)> = expected
+        .enumerate_pixels()
+        .zip(image.pixels())
+        .filter(|((_, _, e), a)| e.0.iter().zip(a.0).any(|(e, a)| e.abs_diff(a) > tolerance))
+        .map(|((x, y, _), _)| (x, y))
+        .collect();
+    match mismatches.first() {
+        Some(first) => Err(GoldenMismatch::Pixels {
+            count: mismatches.len(),
+            first: *first,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::{IndexBuffer, VertexBuffer},
+        graphics_provider::Vertex,
+    };
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, repr_trait::C)]
+    struct TestVertex {
+        position: [f32; 2],
+        tex_coords: [f32; 2],
+        texture: u32,
+        blend_color: u32,
+    }
+    const ATTRIBUTES: [wgpu::VertexAttribute; 4] =
+        wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Uint32, 3 => Uint32];
+    impl Vertex for TestVertex {
+        fn attributes() -> &'static [wgpu::VertexAttribute] {
+            &ATTRIBUTES
+        }
+    }
+
+    fn quad(min: [f32; 2], max: [f32; 2], texture: u32, blend_color: u32) -> [TestVertex; 4] {
+        let vertex = |position, tex_coords| TestVertex {
+            position,
+            tex_coords,
+            texture,
+            blend_color,
+        };
+        [
+            vertex([min[0], max[1]], [0.0, 0.0]),
+            vertex([max[0], max[1]], [1.0, 0.0]),
+            vertex([max[0], min[1]], [1.0, 1.0]),
+            vertex([min[0], min[1]], [0.0, 1.0]),
+        ]
+    }
+
+    #[test]
+    fn rasterizes_textured_and_blended_quads() {
+        let mut texture = RgbaImage::new(2, 1);
+        texture.put_pixel(0, 0, Rgba([255, 0, 0, 255]));
+        texture.put_pixel(1, 0, Rgba([0, 0, 255, 255]));
+        let rasterizer = ReferenceRasterizer::new(PhysicalSize::new(8, 8))
+            .with_camera(bytemuck::cast_slice(&[
+                [0.25f32, 0.0],
+                [0.0, 0.25],
+                [0.0, 0.0],
+            ]))
+            .with_texture(1, texture);
+
+        let mut vertices = VertexBuffer::new();
+        let mut indices = IndexBuffer::new();
+        vertices.extend_from_slice(&quad([-4.0, -4.0], [4.0, 0.0], 1, 0));
+        vertices.extend_from_slice(&quad([-4.0, 0.0], [0.0, 4.0], 0, 0x00FF_0080));
+        indices.extend_from_slice(&[0u16, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7]);
+        let image = rasterizer.render(&vertices, &indices, &TestVertex::describe_buffer_layout());
+
+        assert_eq!(image.get_pixel(1, 6).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(6, 6).0, [0, 0, 255, 255]);
+        assert_eq!(image.get_pixel(1, 1).0, [0, 128, 0, 128]);
+        assert_eq!(image.get_pixel(6, 1).0, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn rasterizes_sprite_instances() {
+        let mut texture = RgbaImage::new(2, 1);
+        texture.put_pixel(0, 0, Rgba([255, 0, 0, 255]));
+        texture.put_pixel(1, 0, Rgba([0, 0, 255, 255]));
+        let rasterizer = ReferenceRasterizer::new(PhysicalSize::new(8, 8))
+            .with_camera(bytemuck::cast_slice(&[
+                [0.25f32, 0.0],
+                [0.0, 0.25],
+                [0.0, 0.0],
+            ]))
+            .with_texture(1, texture);
+        let render = |rotation: f32| {
+            let mut vertices = VertexBuffer::new();
+            vertices.extend_from_slice(&[
+                SpriteInstance::new([0.0, -2.0], [8.0, 4.0], [0.0, 0.0, 1.0, 1.0], 1)
+                    .with_rotation(rotation),
+                SpriteInstance::new([-2.0, 2.0], [4.0, 4.0], [0.0, 0.0, 1.0, 1.0], 0)
+                    .with_color(0x00FF_0080),
+            ]);
+            rasterizer.render(
+                &vertices,
+                &IndexBuffer::new(),
+                &SpriteInstance::describe_buffer_layout(),
+            )
+        };
+
+        let image = render(0.0);
+        assert_eq!(image.get_pixel(1, 6).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(6, 6).0, [0, 0, 255, 255]);
+        assert_eq!(image.get_pixel(1, 1).0, [0, 128, 0, 128]);
+        assert_eq!(image.get_pixel(6, 1).0, [0, 0, 0, 0]);
+        let turned = render(std::f32::consts::PI);
+        assert_eq!(turned.get_pixel(1, 6).0, [0, 0, 255, 255]);
+        assert_eq!(turned.get_pixel(6, 6).0, [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn missing_golden_is_an_error() {
+        if env::var_os(UPDATE_GOLDENS_VARIABLE).is_some() {
+            return;
+        }
+        let golden = env::temp_dir().join("ferride_missing_golden.png");
+        let result = compare_golden(&RgbaImage::new(1, 1), &golden, 0);
+        assert!(matches!(result, Err(GoldenMismatch::Missing(path)) if path == golden));
+        assert!(!golden.exists());
+    }
+}
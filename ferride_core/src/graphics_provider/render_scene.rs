@@ -2,28 +2,96 @@ use wgpu::util::DeviceExt;
 
 use crate::create_name_struct;
 
-use super::{IndexBufferWriter, VertexBufferWriter, Visibility};
+use super::{
+    light_map::LightMap, IndexBufferWriter, LightVertex, QuadVertex, SpriteInstance, Vertex,
+    VertexBufferWriter, Visibility,
+};
 
 create_name_struct!(RenderSceneName);
 create_name_struct!(UniformBufferName);
 
+///Built-in shader for `RenderSceneKind::SpriteBatch`. Expects the camera as first uniform
+pub const SPRITE_BATCH_SHADER: &str = "ferride/shader/sprite_batch.wgsl";
+///Built-in shader for `RenderSceneKind::Lighting`. Expects the camera as first uniform
+pub const LIGHTING_SHADER: &str = "ferride/shader/lighting.wgsl";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenderSceneKind {
+    ///Entities write vertices and indices
+    Vertices,
+    ///Entities write one `SpriteInstance` per sprite into the `VertexBuffer`, which are drawn as
+    ///instances of a static unit quad. The `vertex_buffer_layout` describes the instances
+    SpriteBatch,
+    ///Entities write `LightVertex`es into an offscreen light map, which is multiplied onto
+    ///everything the window drew before this RenderScene
+    Lighting,
+}
+
 #[derive(Debug, Clone)]
 pub struct RenderSceneDescriptor {
+    ///Format until the first update. An `IndexBuffer` promotes itself to `Uint32` as soon as an
+    ///index does not fit into an u16 and the RenderScene follows its format
     pub index_format: wgpu::IndexFormat,
     pub vertex_buffer_layout: wgpu::VertexBufferLayout<'static>,
     pub use_textures: bool,
+    ///Defines for the ShaderPreprocessor, either `NAME` or `NAME=value`. `USE_TEXTURES` is
+    ///defined automatically if `use_textures` is set
+    pub shader_defines: &'static [&'static str],
+    pub kind: RenderSceneKind,
+}
+impl RenderSceneDescriptor {
+    ///Textured sprites drawn as instances. Use with the `SPRITE_BATCH_SHADER`
+    pub fn sprite_batch() -> Self {
+        Self {
+            index_format: wgpu::IndexFormat::Uint16,
+            vertex_buffer_layout: SpriteInstance::describe_buffer_layout(),
+            use_textures: true,
+            shader_defines: &[],
+            kind: RenderSceneKind::SpriteBatch,
+        }
+    }
+
+    ///Lights and shadows drawn into a light map. Use with the `LIGHTING_SHADER`
+    pub fn lighting() -> Self {
+        Self {
+            index_format: wgpu::IndexFormat::Uint16,
+            vertex_buffer_layout: LightVertex::describe_buffer_layout(),
+            use_textures: false,
+            shader_defines: &[],
+            kind: RenderSceneKind::Lighting,
+        }
+    }
+
+    ///All buffers the pipeline reads. The unit quad comes first for sprite batches
+    pub fn vertex_buffer_layouts(&self) -> Vec<wgpu::VertexBufferLayout<'static>> {
+        match self.kind {
+            RenderSceneKind::Vertices | RenderSceneKind::Lighting => {
+                vec![self.vertex_buffer_layout.clone()]
+            }
+            RenderSceneKind::SpriteBatch => vec![
+                QuadVertex::describe_buffer_layout(),
+                self.vertex_buffer_layout.clone(),
+            ],
+        }
+    }
 }
 
 pub struct RenderScene {
     name: RenderSceneName,
+    kind: RenderSceneKind,
     render_pipeline: Option<wgpu::RenderPipeline>,
     vertex_buffer: wgpu::Buffer,
     index_buffer: wgpu::Buffer,
     num_indices: u32,
     num_vertices: u32,
+    ///Vertices the vertex buffer has room for, sprite batches only draw `num_vertices` of them
+    vertex_capacity: u32,
     index_format: wgpu::IndexFormat,
-    vertex_buffer_layout: wgpu::VertexBufferLayout<'static>,
+    vertex_buffer_layouts: Vec<wgpu::VertexBufferLayout<'static>>,
     use_textures: bool,
+    ///Vertex and index buffer of the unit quad for sprite batches
+    quad: Option<(wgpu::Buffer, wgpu::Buffer)>,
+    light_map: Option<LightMap>,
     uniform_buffers: Vec<(
         UniformBufferName,
         wgpu::Buffer,
@@ -36,6 +104,7 @@ impl RenderScene {
     pub fn new(
         name: RenderSceneName,
         device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
         descriptor: RenderSceneDescriptor,
     ) -> Self {
         let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
@@ -52,17 +121,40 @@ impl RenderScene {
         });
         let num_vertices = 0;
         let num_indices = 0;
+        let quad = match descriptor.kind {
+            RenderSceneKind::Vertices | RenderSceneKind::Lighting => None,
+            RenderSceneKind::SpriteBatch => Some((
+                device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(&format!("Quad Vertex Buffer {:?}", name)),
+                    contents: bytemuck::cast_slice(&QuadVertex::QUAD),
+                    usage: wgpu::BufferUsages::VERTEX,
+                }),
+                device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(&format!("Quad Index Buffer {:?}", name)),
+                    contents: bytemuck::cast_slice(&QuadVertex::INDICES),
+                    usage: wgpu::BufferUsages::INDEX,
+                }),
+            )),
+        };
+        let light_map = match descriptor.kind {
+            RenderSceneKind::Lighting => Some(LightMap::new(device, config)),
+            RenderSceneKind::Vertices | RenderSceneKind::SpriteBatch => None,
+        };
 
         Self {
             name,
+            kind: descriptor.kind.clone(),
             render_pipeline: None,
             vertex_buffer,
             index_buffer,
             num_indices,
             num_vertices,
+            vertex_capacity: num_vertices,
             index_format: descriptor.index_format,
-            vertex_buffer_layout: descriptor.vertex_buffer_layout,
+            vertex_buffer_layouts: descriptor.vertex_buffer_layouts(),
             use_textures: descriptor.use_textures,
+            quad,
+            light_map,
             uniform_buffers: Vec::new(),
             visibility: Visibility::Visible,
         }
@@ -76,6 +168,16 @@ impl RenderScene {
         self.use_textures
     }
 
+    pub fn kind(&self) -> &RenderSceneKind {
+        &self.kind
+    }
+
+    pub fn resize(&mut self, device: &wgpu::Device, config: &wgpu::SurfaceConfiguration) {
+        if let Some(light_map) = &mut self.light_map {
+            light_map.resize(device, config);
+        }
+    }
+
     fn bind_groups<'a>(
         &'a self,
         texture_bind_group: &'a wgpu::BindGroup,
@@ -102,8 +204,8 @@ impl RenderScene {
         bind_group_layouts
     }
 
-    pub fn vertex_buffer_layout(&self) -> &wgpu::VertexBufferLayout {
-        &self.vertex_buffer_layout
+    pub fn vertex_buffer_layouts(&self) -> &[wgpu::VertexBufferLayout<'static>] {
+        &self.vertex_buffer_layouts
     }
 
     pub fn update_pipeline(&mut self, render_pipeline: wgpu::RenderPipeline) {
@@ -121,28 +223,33 @@ impl RenderScene {
         vertices: &impl VertexBufferWriter,
         indices: &impl IndexBufferWriter,
     ) {
+        let index_format = indices.index_format().unwrap_or(self.index_format);
+        let format_changed = index_format != self.index_format;
+        self.index_format = index_format;
         if let Some((index_buffer, num_indices)) = indices.write_buffer(
             device,
             queue,
             &self.index_buffer,
             self.num_indices,
             wgpu::BufferUsages::INDEX,
-            self.num_indices > indices.buffer_len(),
+            self.num_indices > indices.buffer_len() || format_changed,
         ) {
             self.index_buffer = index_buffer;
             self.num_indices = num_indices;
         };
-        if let Some((vertex_buffer, num_vertices)) = vertices.write_buffer(
+        if let Some((vertex_buffer, vertex_capacity)) = vertices.write_buffer(
             device,
             queue,
             &self.vertex_buffer,
-            self.num_vertices,
+            self.vertex_capacity,
             wgpu::BufferUsages::VERTEX,
             false,
         ) {
             self.vertex_buffer = vertex_buffer;
-            self.num_vertices = num_vertices;
+            self.vertex_capacity = vertex_capacity;
         };
+        // Sprite batches draw one instance per vertex, fewer instances reuse the larger buffer
+        self.num_vertices = vertices.buffer_len();
     }
 
     pub fn write_render_pass<'a>(
@@ -154,20 +261,56 @@ impl RenderScene {
             Visibility::Hidden => return,
             Visibility::Visible => (),
         };
+        if let Some(light_map) = &self.light_map {
+            light_map.write_composite(render_pass);
+            return;
+        }
         if let Some(render_pipeline) = &self.render_pipeline {
             render_pass.set_pipeline(render_pipeline);
             let bind_groups = self.bind_groups(texture_bind_group);
             for (i, bind_group) in bind_groups.iter().enumerate() {
                 render_pass.set_bind_group(i as u32, bind_group, &[]);
             }
-            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-            render_pass.set_index_buffer(self.index_buffer.slice(..), self.index_format);
-            render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
+            if let Some((quad_vertices, quad_indices)) = &self.quad {
+                if self.num_vertices == 0 {
+                    return;
+                }
+                render_pass.set_vertex_buffer(0, quad_vertices.slice(..));
+                render_pass.set_vertex_buffer(1, self.vertex_buffer.slice(..));
+                render_pass.set_index_buffer(quad_indices.slice(..), wgpu::IndexFormat::Uint16);
+                render_pass.draw_indexed(
+                    0..QuadVertex::INDICES.len() as u32,
+                    0,
+                    0..self.num_vertices,
+                );
+            } else {
+                render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+                render_pass.set_index_buffer(self.index_buffer.slice(..), self.index_format);
+                render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
+            }
         } else {
             log::warn!("Render pipeline not set for render scene {:?}", self.name);
         }
     }
 
+    ///Draws the lights into the light map. Has to happen before the main render pass, which
+    ///only multiplies the light map onto the window
+    pub fn write_light_map(&self, encoder: &mut wgpu::CommandEncoder) {
+        let (Some(light_map), Some(render_pipeline), Visibility::Visible) =
+            (&self.light_map, &self.render_pipeline, &self.visibility)
+        else {
+            return;
+        };
+        let mut render_pass = light_map.begin_render_pass(encoder);
+        render_pass.set_pipeline(render_pipeline);
+        for (i, (_, _, _, bind_group)) in self.uniform_buffers.iter().enumerate() {
+            render_pass.set_bind_group(i as u32, bind_group, &[]);
+        }
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.set_index_buffer(self.index_buffer.slice(..), self.index_format);
+        render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
+    }
+
     pub fn create_uniform_buffer(
         &mut self,
         device: &wgpu::Device,
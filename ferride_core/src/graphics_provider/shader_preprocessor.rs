@@ -0,0 +1,436 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use super::RenderSceneDescriptor;
+
+///Define set for every RenderScene with `use_textures: true`
+pub const USE_TEXTURES_DEFINE: &str = "USE_TEXTURES";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceLocation {
+    pub file: PathBuf,
+    ///1 based, like the line numbers of an editor
+    pub line: usize,
+}
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.file.display(), self.line)
+    }
+}
+
+#[derive(Debug)]
+pub enum ShaderPreprocessorError {
+    FileNotFound(PathBuf, Option<SourceLocation>),
+    RecursiveInclude(PathBuf, SourceLocation),
+    MalformedDirective(String, SourceLocation),
+    UnknownDirective(String, SourceLocation),
+    UnexpectedDirective(String, SourceLocation),
+    UnterminatedConditional(SourceLocation),
+    ///WGSL errors of the processed source, with the location mapped back to the original file
+    InvalidShader(String, Option<SourceLocation>),
+}
+impl Display for ShaderPreprocessorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(file, Some(location)) => {
+                write!(f, "{}: Could not include {:?}", location, file)
+            }
+            Self::FileNotFound(file, None) => write!(f, "Could not load shader {:?}", file),
+            Self::RecursiveInclude(file, location) => {
+                write!(f, "{}: {:?} includes itself", location, file)
+            }
+            Self::MalformedDirective(directive, location) => {
+                write!(f, "{}: Malformed directive '{}'", location, directive)
+            }
+            Self::UnknownDirective(directive, location) => {
+                write!(f, "{}: Unknown directive '{}'", location, directive)
+            }
+            Self::UnexpectedDirective(directive, location) => {
+                write!(f, "{}: '{}' without matching #ifdef", location, directive)
+            }
+            Self::UnterminatedConditional(location) => {
+                write!(f, "{}: #ifdef is never closed by #endif", location)
+            }
+            Self::InvalidShader(message, Some(location)) => write!(f, "{}: {}", location, message),
+            Self::InvalidShader(message, None) => write!(f, "{}", message),
+        }
+    }
+}
+impl Error for ShaderPreprocessorError {}
+
+#[derive(Debug)]
+pub struct PreprocessedShader {
+    pub source: String,
+    ///Origin of every line in `source`
+    lines: Vec<SourceLocation>,
+}
+impl PreprocessedShader {
+    ///Maps a 1 based line of the processed source back to the file and line it came from
+    pub fn original_location(&self, line: usize) -> Option<&SourceLocation> {
+        line.checked_sub(1).and_then(|line| self.lines.get(line))
+    }
+
+    ///Parses and validates the processed source with naga, so mistakes are reported with their
+    ///original location instead of as a wgpu panic
+    pub fn validate(&self) -> Result<naga::Module, ShaderPreprocessorError> {
+        let module = naga::front::wgsl::parse_str(&self.source).map_err(|err| {
+            let location = err
+                .location(&self.source)
+                .and_then(|l| self.original_location(l.line_number as usize))
+                .cloned();
+            ShaderPreprocessorError::InvalidShader(err.message().to_string(), location)
+        })?;
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::all(),
+        )
+        .validate(&module)
+        .map_err(|err| {
+            let location = err
+                .spans()
+                .next()
+                .map(|(span, _)| span.location(&self.source))
+                .and_then(|l| self.original_location(l.line_number as usize))
+                .cloned();
+            ShaderPreprocessorError::InvalidShader(err.into_inner().to_string(), location)
+        })?;
+        Ok(module)
+    }
+}
+
+///Supports `#include "file.wgsl"` (relative to the including file, every file is only included
+///once), `#define NAME [value]`, `#undef NAME`, `#ifdef NAME`, `#ifndef NAME`, `#else` and
+///`#endif`. Defined values replace whole words in the following lines
+pub struct ShaderPreprocessor {
+    defines: Vec<(String, String)>,
+}
+impl ShaderPreprocessor {
+    pub fn new() -> Self {
+        Self {
+            defines: Vec::new(),
+        }
+    }
+
+    ///Defines `USE_TEXTURES` and all `shader_defines` of the descriptor
+    pub fn for_render_scene(descriptor: &RenderSceneDescriptor) -> Self {
+        let mut preprocessor = Self::new();
+        if descriptor.use_textures {
+            preprocessor = preprocessor.with_define(USE_TEXTURES_DEFINE);
+        }
+        for define in descriptor.shader_defines {
+            preprocessor = preprocessor.with_define(define);
+        }
+        preprocessor
+    }
+
+    ///`NAME` or `NAME=value`
+    pub fn with_define(mut self, define: &str) -> Self {
+        let (name, value) = define.split_once('=').unwrap_or((define, ""));
+        self.define(name.trim(), value.trim());
+        self
+    }
+
+    pub fn with_defines(mut self, defines: &[&str]) -> Self {
+        for define in defines {
+            self = self.with_define(define);
+        }
+        self
+    }
+
+    fn define(&mut self, name: &str, value: &str) {
+        self.defines.retain(|(n, _)| n != name);
+        self.defines.push((name.to_string(), value.to_string()));
+    }
+
+    fn is_defined(&self, name: &str) -> bool {
+        self.defines.iter().any(|(n, _)| n == name)
+    }
+
+    pub fn process(
+        mut self,
+        file: impl AsRef<Path>,
+    ) -> Result<PreprocessedShader, ShaderPreprocessorError> {
+        let mut shader = PreprocessedShader {
+            source: String::new(),
+            lines: Vec::new(),
+        };
+        let mut included = Vec::new();
+        let mut stack = Vec::new();
+        self.process_file(file.as_ref(), None, &mut shader, &mut included, &mut stack)?;
+        Ok(shader)
+    }
+
+    fn process_file(
+        &mut self,
+        file: &Path,
+        included_from: Option<SourceLocation>,
+        shader: &mut PreprocessedShader,
+        included: &mut Vec<PathBuf>,
+        stack: &mut Vec<PathBuf>,
+    ) -> Result<(), ShaderPreprocessorError> {
+        let file = if file.is_absolute() {
+            file.to_path_buf()
+        } else {
+            PathBuf::from(crate::vfs::normalize_path(file))
+        };
+        if stack.contains(&file) {
+            return Err(ShaderPreprocessorError::RecursiveInclude(
+                file,
+                included_from.expect("The root file cannot be included recursively"),
+            ));
+        }
+        if included.contains(&file) {
+            return Ok(());
+        }
+        let source = crate::vfs::read_to_string(&file)
+            .map_err(|_| ShaderPreprocessorError::FileNotFound(file.clone(), included_from))?;
+        included.push(file.clone());
+        stack.push(file.clone());
+
+        struct Conditional {
+            active: bool,
+            parent_active: bool,
+            has_else: bool,
+            location: SourceLocation,
+        }
+        let mut conditionals: Vec<Conditional> = Vec::new();
+        for (index, line) in source.lines().enumerate() {
+            let location = SourceLocation {
+                file: file.clone(),
+                line: index + 1,
+            };
+            let active = conditionals.last().map_or(true, |c| c.active);
+            let trimmed = line.trim_start();
+            if !trimmed.starts_with('#') {
+                if active {
+                    shader.source += &self.substitute(line);
+                    shader.source.push('\n');
+                    shader.lines.push(location);
+                }
+                continue;
+            }
+            let mut parts = trimmed[1..].splitn(2, char::is_whitespace);
+            let directive = parts.next().unwrap_or("");
+            let argument = parts.next().unwrap_or("").trim();
+            match directive {
+                "ifdef" | "ifndef" => {
+                    if argument.is_empty() {
+                        return Err(ShaderPreprocessorError::MalformedDirective(
+                            trimmed.to_string(),
+                            location,
+                        ));
+                    }
+                    let condition = self.is_defined(argument) == (directive == "ifdef");
+                    conditionals.push(Conditional {
+                        active: active && condition,
+                        parent_active: active,
+                        has_else: false,
+                        location,
+                    });
+                }
+                "else" => match conditionals.last_mut() {
+                    Some(conditional) if !conditional.has_else => {
+                        conditional.has_else = true;
+                        conditional.active = conditional.parent_active && !conditional.active;
+                    }
+                    _ => {
+                        return Err(ShaderPreprocessorError::UnexpectedDirective(
+                            trimmed.to_string(),
+                            location,
+                        ))
+                    }
+                },
+                "endif" => {
+                    if conditionals.pop().is_none() {
+                        return Err(ShaderPreprocessorError::UnexpectedDirective(
+                            trimmed.to_string(),
+                            location,
+                        ));
+                    }
+                }
+                _ if !active => {}
+                "define" => {
+                    let mut parts = argument.splitn(2, char::is_whitespace);
+                    let name = parts.next().unwrap_or("");
+                    if name.is_empty() {
+                        return Err(ShaderPreprocessorError::MalformedDirective(
+                            trimmed.to_string(),
+                            location,
+                        ));
+                    }
+                    let value = parts.next().unwrap_or("").trim().to_string();
+                    self.define(name, &value);
+                }
+                "undef" => {
+                    self.defines.retain(|(n, _)| n != argument);
+                }
+                "include" => {
+                    let include = argument
+                        .strip_prefix('"')
+                        .and_then(|a| a.strip_suffix('"'))
+                        .ok_or_else(|| {
+                            ShaderPreprocessorError::MalformedDirective(
+                                trimmed.to_string(),
+                                location.clone(),
+                            )
+                        })?;
+                    let include = file
+                        .parent()
+                        .map(|p| p.join(include))
+                        .unwrap_or_else(|| PathBuf::from(include));
+                    self.process_file(&include, Some(location), shader, included, stack)?;
+                }
+                _ => {
+                    return Err(ShaderPreprocessorError::UnknownDirective(
+                        trimmed.to_string(),
+                        location,
+                    ))
+                }
+            }
+        }
+        if let Some(conditional) = conditionals.pop() {
+            return Err(ShaderPreprocessorError::UnterminatedConditional(
+                conditional.location,
+            ));
+        }
+        stack.pop();
+        Ok(())
+    }
+
+    fn substitute(&self, line: &str) -> String {
+        if self.defines.iter().all(|(_, value)| value.is_empty()) {
+            return line.to_string();
+        }
+        let mut result = String::with_capacity(line.len());
+        let mut word = String::new();
+        let flush = |word: &mut String, result: &mut String| {
+            match self
+                .defines
+                .iter()
+                .find(|(name, value)| name == word && !value.is_empty())
+            {
+                Some((_, value)) => result.push_str(value),
+                None => result.push_str(word),
+            }
+            word.clear();
+        };
+        for c in line.chars() {
+            if c.is_alphanumeric() || c == '_' {
+                word.push(c);
+            } else {
+                flush(&mut word, &mut result);
+                result.push(c);
+            }
+        }
+        flush(&mut word, &mut result);
+        result
+    }
+}
+impl Default for ShaderPreprocessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+    use crate::graphics_provider::SPRITE_BATCH_SHADER;
+
+    fn write_shaders(directory: &str, files: &[(&str, &str)]) -> PathBuf {
+        let directory = env::temp_dir().join(directory);
+        fs::create_dir_all(&directory).unwrap();
+        for (name, content) in files {
+            fs::write(directory.join(name), content).unwrap();
+        }
+        directory
+    }
+
+    #[test]
+    fn sprite_batch_shader_is_valid() {
+        let shader = ShaderPreprocessor::for_render_scene(&RenderSceneDescriptor::sprite_batch())
+            .process(SPRITE_BATCH_SHADER)
+            .unwrap();
+        if let Err(err) = shader.validate() {
+            panic!("{}", err);
+        }
+    }
+
+    #[test]
+    fn includes_defines_and_conditionals() {
+        let directory = write_shaders(
+            "ferride_shader_preprocessor_test",
+            &[
+                (
+                    "common.wgsl",
+                    "#ifdef FEATURE\nconst a: f32 = SCALE;\n#else\nconst a: f32 = 0.0;\n#endif\n",
+                ),
+                (
+                    "main.wgsl",
+                    "#include \"common.wgsl\"\n#include \"common.wgsl\"\nconst b: f32 = a;\n",
+                ),
+            ],
+        );
+        let shader = ShaderPreprocessor::new()
+            .with_define("FEATURE")
+            .with_define("SCALE=2.0")
+            .process(directory.join("main.wgsl"))
+            .unwrap();
+        assert_eq!(shader.source, "const a: f32 = 2.0;\nconst b: f32 = a;\n");
+        assert_eq!(
+            shader.original_location(2),
+            Some(&SourceLocation {
+                file: directory.join("main.wgsl"),
+                line: 3
+            })
+        );
+
+        let shader = ShaderPreprocessor::new()
+            .process(directory.join("main.wgsl"))
+            .unwrap();
+        assert_eq!(shader.source, "const a: f32 = 0.0;\nconst b: f32 = a;\n");
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn errors_point_to_original_file() {
+        let directory = write_shaders(
+            "ferride_shader_preprocessor_error_test",
+            &[
+                ("common.wgsl", "const a: f32 = 1.0;\nconst b: f32 = ;\n"),
+                ("main.wgsl", "#include \"common.wgsl\"\n#ifdef A\n"),
+                ("recursive.wgsl", "#include \"recursive.wgsl\"\n"),
+            ],
+        );
+        match ShaderPreprocessor::new().process(directory.join("main.wgsl")) {
+            Err(ShaderPreprocessorError::UnterminatedConditional(location)) => {
+                assert_eq!(location.line, 2)
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+        match ShaderPreprocessor::new().process(directory.join("recursive.wgsl")) {
+            Err(ShaderPreprocessorError::RecursiveInclude(_, _)) => {}
+            other => panic!("Unexpected result {:?}", other),
+        }
+        let shader = ShaderPreprocessor::new()
+            .with_define("A")
+            .process(directory.join("common.wgsl"))
+            .unwrap();
+        match shader.validate() {
+            Err(ShaderPreprocessorError::InvalidShader(_, Some(location))) => assert_eq!(
+                location,
+                SourceLocation {
+                    file: directory.join("common.wgsl"),
+                    line: 2
+                }
+            ),
+            other => panic!("Unexpected result {:?}", other),
+        }
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
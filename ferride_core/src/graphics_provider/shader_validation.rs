@@ -0,0 +1,371 @@
+use std::{error::Error, fmt::Display};
+
+use naga::{AddressSpace, Binding, ScalarKind, ShaderStage, TypeInner};
+
+use super::{
+    RenderSceneDescriptor, ShaderDescriptor, ShaderPreprocessor, ShaderPreprocessorError,
+    UniformBufferName,
+};
+
+#[derive(Debug)]
+pub enum PipelineValidationError {
+    Shader(ShaderPreprocessorError),
+    MissingEntryPoint {
+        name: &'static str,
+        stage: ShaderStage,
+    },
+    MissingVertexAttribute {
+        location: u32,
+    },
+    VertexAttributeMismatch {
+        location: u32,
+        format: wgpu::VertexFormat,
+        shader_type: String,
+    },
+    ///The shader uses a bind group the RenderScene will not provide
+    UnknownBindGroup {
+        group: u32,
+        binding: u32,
+    },
+    ///A uniform group must consist of exactly one `var<uniform>` at binding 0
+    InvalidUniformBinding {
+        uniform: UniformBufferName,
+        group: u32,
+        binding: u32,
+    },
+    UniformSizeMismatch {
+        uniform: UniformBufferName,
+        group: u32,
+        buffer_size: usize,
+        shader_size: u32,
+    },
+    ///The RenderScene binds a uniform in a group the shader never declares
+    UnusedUniform {
+        uniform: UniformBufferName,
+        group: u32,
+    },
+}
+impl Display for PipelineValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shader(err) => write!(f, "{}", err),
+            Self::MissingEntryPoint { name, stage } => {
+                write!(f, "No {:?} entry point named '{}'", stage, name)
+            }
+            Self::MissingVertexAttribute { location } => write!(
+                f,
+                "@location({}) of the vertex input is missing in the vertex_buffer_layout",
+                location
+            ),
+            Self::VertexAttributeMismatch {
+                location,
+                format,
+                shader_type,
+            } => write!(
+                f,
+                "@location({}) is {} in the shader, but {:?} in the vertex_buffer_layout",
+                location, shader_type, format
+            ),
+            Self::UnknownBindGroup { group, binding } => write!(
+                f,
+                "@group({}) @binding({}) is not provided by the RenderScene. Declare it in ShaderDescriptor::uniforms",
+                group, binding
+            ),
+            Self::InvalidUniformBinding {
+                uniform,
+                group,
+                binding,
+            } => write!(
+                f,
+                "@group({}) @binding({}) should be the var<uniform> {:?} at binding 0",
+                group, binding, uniform
+            ),
+            Self::UniformSizeMismatch {
+                uniform,
+                group,
+                buffer_size,
+                shader_size,
+            } => write!(
+                f,
+                "Uniform {:?} at @group({}) has {} bytes, but the shader expects {}",
+                uniform, group, buffer_size, shader_size
+            ),
+            Self::UnusedUniform { uniform, group } => write!(
+                f,
+                "Uniform {:?} is bound at @group({}), which the shader never declares",
+                uniform, group
+            ),
+        }
+    }
+}
+impl Error for PipelineValidationError {}
+impl From<ShaderPreprocessorError> for PipelineValidationError {
+    fn from(err: ShaderPreprocessorError) -> Self {
+        Self::Shader(err)
+    }
+}
+
+///Checks without a GPU what wgpu would otherwise only report by panicking at pipeline creation:
+///the shader compiles, the entry points exist, every vertex input `@location` has a matching
+///attribute in the `vertex_buffer_layout` and every bind group is provided by the RenderScene
+///and used by the shader. `uniforms` are the buffers bound after the texture group in order,
+///including cameras, with their size in bytes
+pub fn validate_pipeline(
+    shader_descriptor: &ShaderDescriptor,
+    render_scene_descriptor: &RenderSceneDescriptor,
+    uniforms: &[(UniformBufferName, usize)],
+) -> Result<(), PipelineValidationError> {
+    let module = ShaderPreprocessor::for_render_scene(render_scene_descriptor)
+        .process(shader_descriptor.file)?
+        .validate()?;
+
+    let entry_point = |name: &'static str, stage: ShaderStage| {
+        module
+            .entry_points
+            .iter()
+            .find(|e| e.name == name && e.stage == stage)
+            .ok_or(PipelineValidationError::MissingEntryPoint { name, stage })
+    };
+    let vertex_entry_point = entry_point(shader_descriptor.vertex_shader, ShaderStage::Vertex)?;
+    entry_point(shader_descriptor.fragment_shader, ShaderStage::Fragment)?;
+
+    let vertex_buffer_layouts = render_scene_descriptor.vertex_buffer_layouts();
+    let mut inputs = Vec::new();
+    for argument in &vertex_entry_point.function.arguments {
+        match (&argument.binding, &module.types[argument.ty].inner) {
+            (Some(Binding::Location { location, .. }), inner) => inputs.push((*location, inner)),
+            (None, TypeInner::Struct { members, .. }) => {
+                for member in members {
+                    if let Some(Binding::Location { location, .. }) = member.binding {
+                        inputs.push((location, &module.types[member.ty].inner));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    for (location, inner) in inputs {
+        let attribute = vertex_buffer_layouts
+            .iter()
+            .flat_map(|layout| layout.attributes.iter())
+            .find(|a| a.shader_location == location)
+            .ok_or(PipelineValidationError::MissingVertexAttribute { location })?;
+        let shader_signature = match inner {
+            TypeInner::Scalar(scalar) => Some((scalar.kind, 1)),
+            TypeInner::Vector { size, scalar } => Some((scalar.kind, *size as u32)),
+            _ => None,
+        };
+        if shader_signature != Some(format_signature(attribute.format)) {
+            return Err(PipelineValidationError::VertexAttributeMismatch {
+                location,
+                format: attribute.format,
+                shader_type: format!("{:?}", inner),
+            });
+        }
+    }
+
+    let first_uniform_group = if render_scene_descriptor.use_textures {
+        1
+    } else {
+        0
+    };
+    let mut used_groups = Vec::new();
+    for (_, variable) in module.global_variables.iter() {
+        let Some(binding) = &variable.binding else {
+            continue;
+        };
+        used_groups.push(binding.group);
+        if binding.group < first_uniform_group {
+            continue;
+        }
+        let (uniform, buffer_size) = uniforms
+            .get((binding.group - first_uniform_group) as usize)
+            .ok_or(PipelineValidationError::UnknownBindGroup {
+                group: binding.group,
+                binding: binding.binding,
+            })?;
+        if binding.binding != 0 || variable.space != AddressSpace::Uniform {
+            return Err(PipelineValidationError::InvalidUniformBinding {
+                uniform: uniform.clone(),
+                group: binding.group,
+                binding: binding.binding,
+            });
+        }
+        let shader_size = module.types[variable.ty].inner.size(module.to_ctx());
+        if shader_size as usize != *buffer_size {
+            return Err(PipelineValidationError::UniformSizeMismatch {
+                uniform: uniform.clone(),
+                group: binding.group,
+                buffer_size: *buffer_size,
+                shader_size,
+            });
+        }
+    }
+    for (index, (uniform, _)) in uniforms.iter().enumerate() {
+        let group = first_uniform_group + index as u32;
+        if !used_groups.contains(&group) {
+            return Err(PipelineValidationError::UnusedUniform {
+                uniform: uniform.clone(),
+                group,
+            });
+        }
+    }
+    Ok(())
+}
+
+///Scalar kind and component count, as seen by the vertex shader
+fn format_signature(format: wgpu::VertexFormat) -> (ScalarKind, u32) {
+    use wgpu::VertexFormat::*;
+    match format {
+        Uint8x2 | Uint16x2 | Uint32x2 => (ScalarKind::Uint, 2),
+        Uint8x4 | Uint16x4 | Uint32x4 => (ScalarKind::Uint, 4),
+        Uint32 => (ScalarKind::Uint, 1),
+        Uint32x3 => (ScalarKind::Uint, 3),
+        Sint8x2 | Sint16x2 | Sint32x2 => (ScalarKind::Sint, 2),
+        Sint8x4 | Sint16x4 | Sint32x4 => (ScalarKind::Sint, 4),
+        Sint32 => (ScalarKind::Sint, 1),
+        Sint32x3 => (ScalarKind::Sint, 3),
+        Float32 | Float64 => (ScalarKind::Float, 1),
+        Unorm8x2 | Snorm8x2 | Unorm16x2 | Snorm16x2 | Float16x2 | Float32x2 | Float64x2 => {
+            (ScalarKind::Float, 2)
+        }
+        Float32x3 | Float64x3 => (ScalarKind::Float, 3),
+        Unorm8x4 | Snorm8x4 | Unorm16x4 | Snorm16x4 | Float16x4 | Float32x4 | Float64x4 => {
+            (ScalarKind::Float, 4)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics_provider::{LIGHTING_SHADER, LIGHT_COMPOSITE_SHADER, SPRITE_BATCH_SHADER};
+
+    const INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 6] = wgpu::vertex_attr_array![
+        2 => Float32x2,
+        3 => Float32x2,
+        4 => Float32,
+        5 => Float32x4,
+        6 => Uint32,
+        7 => Uint32
+    ];
+
+    fn sprite_batch_descriptor(
+        attributes: &'static [wgpu::VertexAttribute],
+    ) -> RenderSceneDescriptor {
+        let mut descriptor = RenderSceneDescriptor::sprite_batch();
+        descriptor.vertex_buffer_layout.attributes = attributes;
+        descriptor
+    }
+
+    const SPRITE_BATCH: ShaderDescriptor = ShaderDescriptor {
+        file: SPRITE_BATCH_SHADER,
+        vertex_shader: "vs_main",
+        fragment_shader: "fs_main",
+        uniforms: &[],
+    };
+
+    #[test]
+    fn sprite_batch_pipeline() {
+        validate_pipeline(
+            &SPRITE_BATCH,
+            &RenderSceneDescriptor::sprite_batch(),
+            &[("camera".into(), 24)],
+        )
+        .unwrap();
+        validate_pipeline(
+            &SPRITE_BATCH,
+            &sprite_batch_descriptor(&INSTANCE_ATTRIBUTES),
+            &[("camera".into(), 24)],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn detects_mismatches() {
+        const MISSING_ATTRIBUTES: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+            2 => Float32x2,
+            3 => Float32x2,
+            4 => Float32,
+            5 => Float32x4,
+            6 => Uint32
+        ];
+        const WRONG_ATTRIBUTES: [wgpu::VertexAttribute; 6] = wgpu::vertex_attr_array![
+            2 => Float32x2,
+            3 => Float32x2,
+            4 => Float32,
+            5 => Float32x4,
+            6 => Uint32,
+            7 => Float32
+        ];
+        let descriptor = sprite_batch_descriptor(&INSTANCE_ATTRIBUTES);
+        assert!(matches!(
+            validate_pipeline(
+                &SPRITE_BATCH,
+                &sprite_batch_descriptor(&MISSING_ATTRIBUTES),
+                &[("camera".into(), 24)]
+            ),
+            Err(PipelineValidationError::MissingVertexAttribute { location: 7 })
+        ));
+        assert!(matches!(
+            validate_pipeline(
+                &SPRITE_BATCH,
+                &sprite_batch_descriptor(&WRONG_ATTRIBUTES),
+                &[("camera".into(), 24)]
+            ),
+            Err(PipelineValidationError::VertexAttributeMismatch { location: 7, .. })
+        ));
+        assert!(matches!(
+            validate_pipeline(&SPRITE_BATCH, &descriptor, &[]),
+            Err(PipelineValidationError::UnknownBindGroup {
+                group: 1,
+                binding: 0
+            })
+        ));
+        assert!(matches!(
+            validate_pipeline(
+                &SPRITE_BATCH,
+                &descriptor,
+                &[("UICamera".into(), 24), ("camera".into(), 24)]
+            ),
+            Err(PipelineValidationError::UnusedUniform { group: 2, .. })
+        ));
+        assert!(matches!(
+            validate_pipeline(&SPRITE_BATCH, &descriptor, &[("camera".into(), 4)]),
+            Err(PipelineValidationError::UniformSizeMismatch {
+                shader_size: 24,
+                ..
+            })
+        ));
+        assert!(matches!(
+            validate_pipeline(
+                &ShaderDescriptor {
+                    vertex_shader: "main",
+                    ..SPRITE_BATCH
+                },
+                &descriptor,
+                &[("camera".into(), 24)]
+            ),
+            Err(PipelineValidationError::MissingEntryPoint { name: "main", .. })
+        ));
+    }
+
+    #[test]
+    fn lighting_pipeline() {
+        validate_pipeline(
+            &ShaderDescriptor {
+                file: LIGHTING_SHADER,
+                vertex_shader: "vs_main",
+                fragment_shader: "fs_main",
+                uniforms: &[],
+            },
+            &RenderSceneDescriptor::lighting(),
+            &[("camera".into(), 24)],
+        )
+        .unwrap();
+        ShaderPreprocessor::new()
+            .process(LIGHT_COMPOSITE_SHADER)
+            .and_then(|shader| shader.validate())
+            .unwrap();
+    }
+}
@@ -1,7 +1,10 @@
 use super::ShaderDescriptor;
 use std::fmt::Debug;
 
-use super::RenderScene;
+use super::{
+    light_map::{LIGHT_BLENDING, LIGHT_MAP_DEPTH_FORMAT, LIGHT_MAP_FORMAT},
+    PostProcessChain, RenderScene, RenderSceneKind, VirtualResolution,
+};
 
 pub trait WindowSurface: Debug {
     fn surface<'a, 'b: 'a>(&'b self) -> &'a wgpu::Surface<'a>;
@@ -15,13 +18,17 @@ pub trait WindowSurface: Debug {
         self.config_mut().height = new_size.height;
         self.surface().configure(device, self.config());
     }
+    fn set_virtual_resolution(&mut self, virtual_resolution: Option<VirtualResolution>);
+    ///How textures drawn to the window should be sampled
+    fn filter_mode(&self) -> wgpu::FilterMode;
     fn create_render_pipeline<'a>(
         &self,
         device: &wgpu::Device,
         bind_group_layout: &[&wgpu::BindGroupLayout],
         shader: &wgpu::ShaderModule,
         shader_descriptor: &ShaderDescriptor,
-        vertex_buffer_layout: wgpu::VertexBufferLayout<'a>,
+        vertex_buffer_layouts: &[wgpu::VertexBufferLayout<'a>],
+        kind: &RenderSceneKind,
     ) -> wgpu::RenderPipeline;
     fn render(
         &mut self,
@@ -29,17 +36,20 @@ pub trait WindowSurface: Debug {
         queue: &wgpu::Queue,
         render_scenes: &[&RenderScene],
         texture_bind_group: &wgpu::BindGroup,
+        post_process_chain: Option<&mut PostProcessChain>,
     );
 }
 
 pub struct Surface<'a> {
     pub wgpu_surface: wgpu::Surface<'a>,
     pub config: wgpu::SurfaceConfiguration,
+    pub virtual_resolution: Option<VirtualResolution>,
 }
 impl Debug for Surface<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Surface")
             .field("config", &self.config)
+            .field("virtual_resolution", &self.virtual_resolution)
             .finish()
     }
 }
@@ -56,14 +66,47 @@ impl<'a> WindowSurface for Surface<'a> {
         &mut self.config
     }
 
+    fn set_virtual_resolution(&mut self, virtual_resolution: Option<VirtualResolution>) {
+        self.virtual_resolution = virtual_resolution;
+    }
+
+    fn filter_mode(&self) -> wgpu::FilterMode {
+        // Without a virtual resolution, cameras lay out in window pixels
+        match &self.virtual_resolution {
+            Some(virtual_resolution) => virtual_resolution.filter_mode(
+                &winit::dpi::PhysicalSize::new(self.config.width, self.config.height),
+            ),
+            None => wgpu::FilterMode::Nearest,
+        }
+    }
+
     fn create_render_pipeline<'b>(
         &self,
         device: &wgpu::Device,
         bind_group_layouts: &[&wgpu::BindGroupLayout],
         shader: &wgpu::ShaderModule,
         shader_descriptor: &ShaderDescriptor,
-        vertex_buffer_layout: wgpu::VertexBufferLayout<'b>,
+        vertex_buffer_layouts: &[wgpu::VertexBufferLayout<'b>],
+        kind: &RenderSceneKind,
     ) -> wgpu::RenderPipeline {
+        // Lighting scenes draw into their light map, deeper lights pass over the shadows of
+        // the lights before them
+        let (format, blend, depth_stencil) = match kind {
+            RenderSceneKind::Vertices | RenderSceneKind::SpriteBatch => {
+                (self.config.format, wgpu::BlendState::ALPHA_BLENDING, None)
+            }
+            RenderSceneKind::Lighting => (
+                LIGHT_MAP_FORMAT,
+                LIGHT_BLENDING,
+                Some(wgpu::DepthStencilState {
+                    format: LIGHT_MAP_DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::GreaterEqual,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+            ),
+        };
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Pipeline Layout"),
             bind_group_layouts,
@@ -75,14 +118,14 @@ impl<'a> WindowSurface for Surface<'a> {
             vertex: wgpu::VertexState {
                 module: shader,
                 entry_point: shader_descriptor.vertex_shader,
-                buffers: &[vertex_buffer_layout],
+                buffers: vertex_buffer_layouts,
             },
             fragment: Some(wgpu::FragmentState {
                 module: shader,
                 entry_point: shader_descriptor.fragment_shader,
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: self.config.format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    format,
+                    blend: Some(blend),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
             }),
@@ -96,7 +139,7 @@ impl<'a> WindowSurface for Surface<'a> {
                 unclipped_depth: false,
                 conservative: false,
             },
-            depth_stencil: None,
+            depth_stencil,
             multisample: wgpu::MultisampleState {
                 count: 1,
                 mask: !0,
@@ -114,6 +157,7 @@ impl<'a> WindowSurface for Surface<'a> {
         queue: &wgpu::Queue,
         render_scenes: &[&RenderScene],
         texture_bind_group: &wgpu::BindGroup,
+        post_process_chain: Option<&mut PostProcessChain>,
     ) {
         let output = self
             .surface()
@@ -126,14 +170,31 @@ impl<'a> WindowSurface for Surface<'a> {
             label: Some("Render Encoder"),
         });
 
+        for render_scene in render_scenes {
+            render_scene.write_light_map(&mut encoder);
+        }
+        let post_process_chain = post_process_chain.filter(|c| c.scene_target().is_some());
+        let window_size = winit::dpi::PhysicalSize::new(self.config.width, self.config.height);
+        // Everything outside of the scissor rect stays the color of the letterbox bars
+        let (clear_color, scissor_rect) = match &self.virtual_resolution {
+            Some(virtual_resolution) => (
+                wgpu::Color::BLACK,
+                virtual_resolution.scissor_rect(&window_size),
+            ),
+            None => (wgpu::Color::WHITE, None),
+        };
         {
+            let scene_target = post_process_chain
+                .as_ref()
+                .and_then(|c| c.scene_target())
+                .unwrap_or(&view);
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Render Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
+                    view: scene_target,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                        load: wgpu::LoadOp::Clear(clear_color),
                         store: wgpu::StoreOp::Store,
                     },
                 })],
@@ -141,11 +202,17 @@ impl<'a> WindowSurface for Surface<'a> {
                 occlusion_query_set: None,
                 timestamp_writes: None,
             });
+            if let Some([x, y, width, height]) = scissor_rect {
+                render_pass.set_scissor_rect(x, y, width, height);
+            }
 
             for render_scene in render_scenes {
                 render_scene.write_render_pass(&mut render_pass, texture_bind_group);
             }
         }
+        if let Some(post_process_chain) = post_process_chain {
+            post_process_chain.render(queue, &mut encoder, &view);
+        }
 
         queue.submit(std::iter::once(encoder.finish()));
         output.present();
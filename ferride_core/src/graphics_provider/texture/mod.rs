@@ -1,6 +1,7 @@
-use std::{fs, num::NonZeroU32, path::Path};
+use std::{num::NonZeroU32, path::Path};
 
 use image::GenericImageView;
+use winit::dpi::{PhysicalPosition, PhysicalSize};
 pub const DEFAULT_TEXTURE: &str = "Default Texture Provider Texture";
 
 pub struct TextureProvider {
@@ -8,6 +9,7 @@ pub struct TextureProvider {
     pub bind_group: Option<wgpu::BindGroup>,
     textures: Vec<Texture>,
     current_id: u32,
+    filter_mode: wgpu::FilterMode,
 }
 impl TextureProvider {
     pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
@@ -17,12 +19,21 @@ impl TextureProvider {
             height: 1,
             depth_or_array_layers: 1,
         };
-        let texture = Texture::from_bytes(device, queue, &bytes, size, Some(DEFAULT_TEXTURE));
+        let filter_mode = wgpu::FilterMode::Nearest;
+        let texture = Texture::from_bytes(
+            device,
+            queue,
+            &bytes,
+            size,
+            Some(DEFAULT_TEXTURE),
+            filter_mode,
+        );
         let mut provider = Self {
             bind_group_layout: None,
             bind_group: None,
             textures: Vec::new(),
             current_id: 0,
+            filter_mode,
         };
         provider.register_texture(device, texture);
         provider
@@ -66,8 +77,31 @@ impl TextureProvider {
                 },
             ],
         });
+        self.bind_group_layout = Some(bind_group_layout);
+        self.rebuild_bind_group(device);
+        self.current_id += 1;
+        self.current_id - 1
+    }
+
+    ///Samples all textures with `filter_mode` from now on
+    pub fn set_filter_mode(&mut self, device: &wgpu::Device, filter_mode: wgpu::FilterMode) {
+        if self.filter_mode == filter_mode {
+            return;
+        }
+        self.filter_mode = filter_mode;
+        for texture in self.textures.iter_mut() {
+            texture.sampler = create_sampler(device, filter_mode);
+        }
+        self.rebuild_bind_group(device);
+    }
+
+    fn rebuild_bind_group(&mut self, device: &wgpu::Device) {
+        let bind_group_layout = self
+            .bind_group_layout
+            .as_ref()
+            .expect("No bind group layout");
         let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            layout: &bind_group_layout,
+            layout: bind_group_layout,
             entries: &[
                 wgpu::BindGroupEntry {
                     binding: 0,
@@ -92,10 +126,7 @@ impl TextureProvider {
             ],
             label: Some(self.current_id.to_string().as_str()),
         });
-        self.bind_group_layout = Some(bind_group_layout);
         self.bind_group = Some(bind_group);
-        self.current_id += 1;
-        self.current_id - 1
     }
 
     pub fn create_texture(
@@ -108,10 +139,92 @@ impl TextureProvider {
         if let Some(index) = self.get_texture_index(label) {
             return index as u32;
         }
-        let texture = Texture::new(device, queue, path, label);
+        let texture = Texture::new(device, queue, path, label, self.filter_mode);
 
         self.register_texture(device, texture)
     }
+
+    ///Creates the texture or overwrites it. A texture of another size replaces the old one at
+    ///its index
+    pub fn write_texture(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        label: &str,
+        pixels: &[u8],
+        size: PhysicalSize<u32>,
+    ) -> u32 {
+        let extent = wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: 1,
+        };
+        let Some(index) = self.get_texture_index(Some(label)) else {
+            let texture =
+                Texture::from_bytes(device, queue, pixels, extent, Some(label), self.filter_mode);
+            return self.register_texture(device, texture);
+        };
+        let texture = &mut self.textures[index as usize];
+        if texture.texture.size() == extent {
+            queue.write_texture(
+                texture.texture.as_image_copy(),
+                pixels,
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(4 * size.width),
+                    rows_per_image: Some(size.height),
+                },
+                extent,
+            );
+        } else {
+            *texture =
+                Texture::from_bytes(device, queue, pixels, extent, Some(label), self.filter_mode);
+            self.rebuild_bind_group(device);
+        }
+        index
+    }
+
+    ///Overwrites the `size` pixels at `origin` of the texture. None if there is no texture
+    ///`label` or the region does not fit into it
+    pub fn write_texture_region(
+        &mut self,
+        queue: &wgpu::Queue,
+        label: &str,
+        pixels: &[u8],
+        origin: PhysicalPosition<u32>,
+        size: PhysicalSize<u32>,
+    ) -> Option<u32> {
+        let index = self.get_texture_index(Some(label))?;
+        let texture = &self.textures[index as usize].texture;
+        let extent = texture.size();
+        if origin.x + size.width > extent.width || origin.y + size.height > extent.height {
+            return None;
+        }
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: origin.x,
+                    y: origin.y,
+                    z: 0,
+                },
+                aspect: wgpu::TextureAspect::All,
+            },
+            pixels,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * size.width),
+                rows_per_image: Some(size.height),
+            },
+            wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+        );
+        Some(index)
+    }
 }
 
 pub struct Texture {
@@ -128,6 +241,7 @@ impl Texture {
         bytes: &[u8],
         size: wgpu::Extent3d,
         label: Option<&str>,
+        filter_mode: wgpu::FilterMode,
     ) -> Self {
         let texture = device.create_texture(&wgpu::TextureDescriptor {
             label,
@@ -157,15 +271,7 @@ impl Texture {
         );
 
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Nearest,
-            min_filter: wgpu::FilterMode::Nearest,
-            mipmap_filter: wgpu::FilterMode::Nearest,
-            ..Default::default()
-        });
+        let sampler = create_sampler(device, filter_mode);
 
         Self {
             texture,
@@ -175,8 +281,15 @@ impl Texture {
         }
     }
 
-    fn new(device: &wgpu::Device, queue: &wgpu::Queue, path: &Path, label: Option<&str>) -> Self {
-        let bytes = fs::read(path).expect(&format!("Could not read: '{:?}' for texture {:?}", path, label));
+    pub(super) fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        path: &Path,
+        label: Option<&str>,
+        filter_mode: wgpu::FilterMode,
+    ) -> Self {
+        let bytes = crate::vfs::read(path)
+            .expect(&format!("Could not read: '{:?}' for texture {:?}", path, label));
         let img =
             image::load_from_memory(&bytes).expect(&format!("Could not load image: '{:?}", path));
 
@@ -188,6 +301,18 @@ impl Texture {
             height: dimensions.1,
             depth_or_array_layers: 1,
         };
-        Self::from_bytes(device, queue, &rgba, size, label)
+        Self::from_bytes(device, queue, &rgba, size, label, filter_mode)
     }
 }
+
+fn create_sampler(device: &wgpu::Device, filter_mode: wgpu::FilterMode) -> wgpu::Sampler {
+    device.create_sampler(&wgpu::SamplerDescriptor {
+        address_mode_u: wgpu::AddressMode::ClampToEdge,
+        address_mode_v: wgpu::AddressMode::ClampToEdge,
+        address_mode_w: wgpu::AddressMode::ClampToEdge,
+        mag_filter: filter_mode,
+        min_filter: filter_mode,
+        mipmap_filter: wgpu::FilterMode::Nearest,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,174 @@
+use winit::dpi::PhysicalSize;
+
+///How a virtual resolution is mapped onto a window of a different size
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScalingMode {
+    ///Largest scale that shows all of the virtual resolution, the rest of the window is
+    ///letterboxed
+    Fit,
+    ///Smallest scale that covers the whole window, cropping the virtual resolution
+    Fill,
+    ///Largest whole number scale that fits, so pixel art stays crisp. Windows smaller than the
+    ///virtual resolution fall back to `Fit`
+    IntegerScale,
+    ///Scales both axes independently to the window, distorting the image
+    Stretch,
+}
+
+///Area of the window the virtual resolution is drawn to, in pixels from the top left corner.
+///Reaches outside of the window for `ScalingMode::Fill`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+///Fixed size the cameras of a window lay their scenes out in, independent of the window size
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualResolution {
+    pub size: PhysicalSize<f32>,
+    pub mode: ScalingMode,
+}
+impl VirtualResolution {
+    pub fn new(size: PhysicalSize<f32>, mode: ScalingMode) -> Self {
+        Self { size, mode }
+    }
+
+    ///Window pixels per virtual pixel along x and y
+    pub fn scale(&self, window: &PhysicalSize<u32>) -> (f32, f32) {
+        let scale_x = window.width as f32 / self.size.width;
+        let scale_y = window.height as f32 / self.size.height;
+        match self.mode {
+            ScalingMode::Fit => (scale_x.min(scale_y), scale_x.min(scale_y)),
+            ScalingMode::Fill => (scale_x.max(scale_y), scale_x.max(scale_y)),
+            ScalingMode::IntegerScale => {
+                let fit = scale_x.min(scale_y);
+                let scale = if fit >= 1.0 { fit.floor() } else { fit };
+                (scale, scale)
+            }
+            ScalingMode::Stretch => (scale_x, scale_y),
+        }
+    }
+
+    ///Centered in the window
+    pub fn viewport(&self, window: &PhysicalSize<u32>) -> Viewport {
+        let (scale_x, scale_y) = self.scale(window);
+        let width = self.size.width * scale_x;
+        let height = self.size.height * scale_y;
+        Viewport {
+            x: (window.width as f32 - width) / 2.0,
+            y: (window.height as f32 - height) / 2.0,
+            width,
+            height,
+        }
+    }
+
+    ///Factor a camera covering the virtual resolution has to be scaled by in normalized device
+    ///coordinates to end up in the viewport
+    pub fn ndc_scale(&self, window: &PhysicalSize<u32>) -> [f32; 2] {
+        if window.width == 0 || window.height == 0 {
+            return [1.0, 1.0];
+        }
+        let viewport = self.viewport(window);
+        [
+            viewport.width / window.width as f32,
+            viewport.height / window.height as f32,
+        ]
+    }
+
+    ///Part of the virtual resolution that ends up inside the window, centered on the origin
+    pub fn safe_area(&self, window: &PhysicalSize<u32>) -> PhysicalSize<f32> {
+        let [scale_x, scale_y] = self.ndc_scale(window);
+        PhysicalSize::new(
+            self.size.width * scale_x.min(1.0) / scale_x,
+            self.size.height * scale_y.min(1.0) / scale_y,
+        )
+    }
+
+    ///Nearest keeps textures crisp while every texel covers a whole number of pixels, any other
+    ///scale, like 1080p on a 1440p display, is smoothed instead of drawing uneven texels
+    pub(super) fn filter_mode(&self, window: &PhysicalSize<u32>) -> wgpu::FilterMode {
+        let (scale_x, scale_y) = self.scale(window);
+        let is_whole = |scale: f32| scale >= 1.0 && (scale - scale.round()).abs() < 1e-3;
+        if is_whole(scale_x) && is_whole(scale_y) {
+            wgpu::FilterMode::Nearest
+        } else {
+            wgpu::FilterMode::Linear
+        }
+    }
+
+    ///Viewport clipped to the window as `[x, y, width, height]`, everything outside of it are
+    ///letterbox bars
+    pub(super) fn scissor_rect(&self, window: &PhysicalSize<u32>) -> Option<[u32; 4]> {
+        let viewport = self.viewport(window);
+        let x = viewport.x.max(0.0).round() as u32;
+        let y = viewport.y.max(0.0).round() as u32;
+        let right = ((viewport.x + viewport.width).round() as u32).min(window.width);
+        let bottom = ((viewport.y + viewport.height).round() as u32).min(window.height);
+        if right <= x || bottom <= y {
+            return None;
+        }
+        Some([x, y, right - x, bottom - y])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_1080p_onto_other_windows() {
+        let virtual_size = PhysicalSize::new(1920.0, 1080.0);
+        let fit = VirtualResolution::new(virtual_size, ScalingMode::Fit);
+        let ultrawide = PhysicalSize::new(2560, 1080);
+        assert_eq!(fit.ndc_scale(&ultrawide), [0.75, 1.0]);
+        assert_eq!(fit.scissor_rect(&ultrawide), Some([320, 0, 1920, 1080]));
+        assert_eq!(fit.safe_area(&ultrawide), virtual_size);
+
+        let fill = VirtualResolution::new(virtual_size, ScalingMode::Fill);
+        let square = PhysicalSize::new(1080, 1080);
+        assert_eq!(fill.ndc_scale(&square), [16.0 / 9.0, 1.0]);
+        assert_eq!(fill.scissor_rect(&square), Some([0, 0, 1080, 1080]));
+        assert_eq!(fill.safe_area(&square), PhysicalSize::new(1080.0, 1080.0));
+
+        let pixel_art =
+            VirtualResolution::new(PhysicalSize::new(320.0, 180.0), ScalingMode::IntegerScale);
+        let laptop = PhysicalSize::new(1366, 768);
+        assert_eq!(pixel_art.scale(&laptop), (4.0, 4.0));
+        assert_eq!(
+            pixel_art.viewport(&laptop),
+            Viewport {
+                x: 43.0,
+                y: 24.0,
+                width: 1280.0,
+                height: 720.0
+            }
+        );
+
+        let stretch = VirtualResolution::new(virtual_size, ScalingMode::Stretch);
+        assert_eq!(stretch.ndc_scale(&laptop), [1.0, 1.0]);
+        assert_eq!(stretch.safe_area(&laptop), virtual_size);
+    }
+
+    #[test]
+    fn filters_uneven_scales() {
+        let fit = VirtualResolution::new(PhysicalSize::new(1920.0, 1080.0), ScalingMode::Fit);
+        let uhd = PhysicalSize::new(3840, 2160);
+        assert_eq!(fit.filter_mode(&uhd), wgpu::FilterMode::Nearest);
+        assert_eq!(
+            fit.filter_mode(&PhysicalSize::new(2560, 1440)),
+            wgpu::FilterMode::Linear
+        );
+        assert_eq!(
+            fit.filter_mode(&PhysicalSize::new(1366, 768)),
+            wgpu::FilterMode::Linear
+        );
+        let pixel_art = VirtualResolution::new(fit.size, ScalingMode::IntegerScale);
+        assert_eq!(
+            pixel_art.filter_mode(&PhysicalSize::new(4000, 2300)),
+            wgpu::FilterMode::Nearest
+        );
+    }
+}
@@ -1,26 +1,75 @@
 mod graphics_provider;
 pub mod graphics {
     pub use super::graphics_provider::{
-        GraphicsProvider, Index, RenderSceneDescriptor, RenderSceneName, ShaderDescriptor,
-        UniformBufferName, Vertex, Visibility, DEFAULT_TEXTURE,
+        compare_golden, validate_pipeline, Color, ColorParseError, GoldenMismatch,
+        GraphicsProvider, Index, LightVertex, PipelineValidationError, PostProcessDescriptor,
+        PostProcessName, PreprocessedShader, QuadVertex, ReferenceRasterizer,
+        RenderSceneDescriptor, RenderSceneKind, RenderSceneName, ScalingMode, ShaderDescriptor,
+        ShaderPreprocessor, ShaderPreprocessorError, SourceLocation, SpriteInstance,
+        UniformBufferName, Vertex, Viewport, VirtualResolution, Visibility, CHROMATIC_ABERRATION,
+        COLOR_GRADING, CRT, DEFAULT_TEXTURE, FILM_GRAIN, LIGHTING_SHADER, LIGHT_COMPOSITE_SHADER,
+        SPRITE_BATCH_SHADER, UPDATE_GOLDENS_VARIABLE, VIGNETTE,
     };
 }
 
 mod manager_application;
 pub mod app {
     pub use super::manager_application::{
-        ApplicationEvent, EventManager, IndexBuffer, ManagerApplication, VertexBuffer,
-        WindowDescriptor, WindowManager,
+        ApplicationEvent, CursorDescriptor, CursorName, CursorSource, EventManager, IndexBuffer,
+        ManagerApplication, VertexBuffer, WindowDescriptor, WindowManager, CURSOR_SIZE,
+        DEFAULT_CURSOR,
+    };
+}
+
+mod file_system;
+pub mod vfs {
+    pub use super::file_system::{
+        file_system, normalize_path, pack_directory, read, read_to_string, write_archive,
+        PackedArchive, VirtualFileSystem, OVERRIDE_DIRECTORY_VARIABLE,
+    };
+}
+
+mod audio_manager;
+pub mod audio {
+    pub use super::audio_manager::{
+        AudioBackend, AudioCommand, AudioManager, AudioRecord, Bus, NullBackend, PlayRequest,
+        PlaybackId, RecordingBackend, RodioBackend, SoundName,
+    };
+}
+
+mod font_loader;
+pub mod fonts {
+    pub use super::font_loader::{
+        font, font_sprite_sheets, register_font, BitmapFont, BitmapGlyph, Font, FontError,
+        FontGlyph, FontName, VectorFont, REPLACEMENT_CHARACTER,
+    };
+}
+
+mod localizer;
+pub mod localization {
+    pub use super::localizer::{
+        language, localization, plural_category, set_language, tr, tr_plural, tr_with,
+        Localization, LocalizationError, PluralCategory, StringTable, FALLBACK_LANGUAGE,
+    };
+}
+
+mod frame_profiler;
+pub mod profiling {
+    pub use super::frame_profiler::{
+        EntityTypeMetrics, FrameMetrics, FrameProfiler, SceneMetrics, Stage, TRACE_FILE_VARIABLE,
     };
 }
 
 mod game;
 pub mod game_engine {
     pub use super::game::{
-        example, static_camera, BoundingBox, CameraDescriptor, Direction, Entity, EntityName,
-        EntityType, ExternalEvent, Game, RessourceDescriptor, RessourceDescriptorBuilder, Scene,
-        SceneName, SpritePosition, SpriteSheet, SpriteSheetDimensions, SpriteSheetName, State,
-        TextureCoordinates, VelocityController,
+        example, shadow_polygon, static_camera, write_light_map, BoundingBox, CameraDebugInfo,
+        CameraDescriptor, DebugInfo, Direction, DisplayScale, EmissionMode, EmissionShape, Entity,
+        EntityDebugInfo, EntityName, EntityType, ExternalEvent, Game, Light, LightKind,
+        LineSegment, ParticleEmitter, ParticleVertex, RessourceDescriptor,
+        RessourceDescriptorBuilder, Scene, SceneName, SpritePosition, SpriteSheet,
+        SpriteSheetDimensions, SpriteSheetName, State, TextureCoordinates, VelocityController,
+        WindowName,
     };
 }
 
@@ -32,6 +81,9 @@ pub mod reexports {
     pub mod wgpu {
         pub use wgpu::{vertex_attr_array, ShaderStages, VertexAttribute};
     }
+    pub mod image {
+        pub use image::{Rgba, RgbaImage};
+    }
     // pub use wgpu;
     // pub use threed;
 }
@@ -0,0 +1,346 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    path::Path,
+    sync::{OnceLock, RwLock},
+};
+
+use log::warn;
+
+mod plural;
+pub use plural::{plural_category, PluralCategory};
+
+///Language every lookup falls back to, when the current language has no string for a key
+pub const FALLBACK_LANGUAGE: &str = "en";
+
+#[derive(Debug)]
+pub enum LocalizationError {
+    Io(String, std::io::Error),
+    ///File, line and what is wrong with it
+    Syntax(String, usize, String),
+}
+impl Display for LocalizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalizationError::Io(file, err) => write!(f, "Could not read {}: {}", file, err),
+            LocalizationError::Syntax(file, line, message) => {
+                write!(f, "{}:{}: {}", file, line, message)
+            }
+        }
+    }
+}
+impl std::error::Error for LocalizationError {}
+
+///Strings of one language by key. Files hold one `key = value` per line, `#` starts a comment
+///line and `\n` in a value is a line break. Values interpolate arguments written as `{name}`,
+///`{{` and `}}` are literal braces. Plural forms are keys suffixed with their `PluralCategory`,
+///like `enemies_left.one` and `enemies_left.other`
+#[derive(Debug, Clone)]
+pub struct StringTable {
+    language: String,
+    strings: HashMap<String, String>,
+}
+impl StringTable {
+    ///`file` only names the source in errors
+    pub fn parse(language: &str, file: &str, source: &str) -> Result<Self, LocalizationError> {
+        let mut strings = HashMap::new();
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error =
+                |message: String| LocalizationError::Syntax(file.to_string(), index + 1, message);
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("Expected 'key = value', got '{}'", line)))?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(error("Empty key".to_string()));
+            }
+            let value = value.trim().replace("\\n", "\n");
+            if strings.insert(key.to_string(), value).is_some() {
+                return Err(error(format!("Duplicate key '{}'", key)));
+            }
+        }
+        Ok(Self {
+            language: language.to_string(),
+            strings,
+        })
+    }
+
+    ///Reads `<directory>/<language>.lang` through the vfs
+    pub fn load(directory: &Path, language: &str) -> Result<Self, LocalizationError> {
+        let path = directory.join(language).with_extension("lang");
+        let file = path.to_string_lossy().to_string();
+        let source = crate::vfs::read_to_string(&path)
+            .map_err(|err| LocalizationError::Io(file.clone(), err))?;
+        Self::parse(language, &file, &source)
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.strings.get(key).map(|value| value.as_str())
+    }
+}
+
+///String tables of every language and the one that is currently shown
+#[derive(Debug, Clone)]
+pub struct Localization {
+    tables: Vec<StringTable>,
+    language: String,
+}
+impl Localization {
+    pub fn new(language: &str) -> Self {
+        Self {
+            tables: Vec::new(),
+            language: language.to_string(),
+        }
+    }
+
+    ///Loads `<directory>/<language>.lang` for every language, starting with the first one
+    pub fn load(directory: &Path, languages: &[&str]) -> Result<Self, LocalizationError> {
+        let mut localization = Self::new(languages.first().unwrap_or(&FALLBACK_LANGUAGE));
+        for language in languages {
+            localization = localization.with_table(StringTable::load(directory, language)?);
+        }
+        Ok(localization)
+    }
+
+    ///Replaces an earlier table of the same language
+    pub fn with_table(mut self, table: StringTable) -> Self {
+        self.tables.retain(|t| t.language != table.language);
+        self.tables.push(table);
+        self
+    }
+
+    ///Makes this the localization used by `tr`, `tr_with` and `tr_plural`
+    pub fn install(self) {
+        *localization().write().expect("Localization lock poisoned") = self;
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn languages(&self) -> Vec<&str> {
+        self.tables.iter().map(|table| table.language()).collect()
+    }
+
+    pub fn set_language(&mut self, language: &str) {
+        if !self.tables.iter().any(|table| table.language == language) {
+            warn!(
+                "No strings for language {:?}, falling back to {:?}",
+                language, FALLBACK_LANGUAGE
+            );
+        }
+        self.language = language.to_string();
+    }
+
+    fn lookup(&self, key: &str) -> Option<&str> {
+        [self.language.as_str(), FALLBACK_LANGUAGE]
+            .into_iter()
+            .filter_map(|language| self.tables.iter().find(|table| table.language == language))
+            .find_map(|table| table.get(key))
+    }
+
+    ///The key itself if no language has a string for it
+    pub fn get(&self, key: &str) -> String {
+        self.format(key, &[])
+    }
+
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        match self.lookup(key) {
+            Some(value) => interpolate(value, args),
+            None => {
+                warn!("No string for key {:?}", key);
+                key.to_string()
+            }
+        }
+    }
+
+    ///Picks the plural form of `key` for `count` in the current language, falling back to the
+    ///`other` form. `count` is available as `{count}`
+    pub fn plural(&self, key: &str, count: u64, args: &[(&str, &dyn Display)]) -> String {
+        let category = plural_category(&self.language, count);
+        let forms = [category, PluralCategory::Other].map(|c| format!("{}.{}", key, c.as_str()));
+        let key = forms
+            .iter()
+            .find(|form| self.lookup(form).is_some())
+            .unwrap_or(&forms[1]);
+        let mut args = args.to_vec();
+        args.push(("count", &count));
+        self.format(key, &args)
+    }
+}
+impl Default for Localization {
+    fn default() -> Self {
+        Self::new(FALLBACK_LANGUAGE)
+    }
+}
+
+///Replaces `{name}` with the argument of that name. Unknown arguments are kept as they are
+fn interpolate(value: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find(['{', '}']) {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            result.push_str(&rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+        let argument = rest[1..]
+            .find('}')
+            .filter(|_| rest.starts_with('{'))
+            .and_then(|end| {
+                let name = &rest[1..end + 1];
+                args.iter()
+                    .find(|(arg, _)| *arg == name)
+                    .map(|(_, value)| (value.to_string(), end + 2))
+            });
+        match argument {
+            Some((value, length)) => {
+                result.push_str(&value);
+                rest = &rest[length..];
+            }
+            None => {
+                result.push_str(&rest[..1]);
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+static LOCALIZATION: OnceLock<RwLock<Localization>> = OnceLock::new();
+
+///The installed Localization, or an empty one that returns every key as it is
+pub fn localization() -> &'static RwLock<Localization> {
+    LOCALIZATION.get_or_init(|| RwLock::new(Localization::default()))
+}
+
+pub fn set_language(language: &str) {
+    localization()
+        .write()
+        .expect("Localization lock poisoned")
+        .set_language(language);
+}
+
+pub fn language() -> String {
+    localization()
+        .read()
+        .expect("Localization lock poisoned")
+        .language()
+        .to_string()
+}
+
+pub fn tr(key: &str) -> String {
+    tr_with(key, &[])
+}
+
+pub fn tr_with(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    localization()
+        .read()
+        .expect("Localization lock poisoned")
+        .format(key, args)
+}
+
+pub fn tr_plural(key: &str, count: u64, args: &[(&str, &dyn Display)]) -> String {
+    localization()
+        .read()
+        .expect("Localization lock poisoned")
+        .plural(key, count, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EN: &str = "
+# Main menu
+menu.new_game = New Game
+battle.enemies_left.one = {count} enemy left
+battle.enemies_left.other = {count} enemies left
+character.stats = {name}\\nHP: {hp}/{max_hp}
+set = {{literal}} {unknown}
+";
+    const DE: &str = "
+menu.new_game = Neues Spiel
+battle.enemies_left.one = {count} Gegner übrig
+battle.enemies_left.other = {count} Gegner übrig
+";
+    const RU: &str = "
+battle.enemies_left.one = Остался {count} враг
+battle.enemies_left.few = Осталось {count} врага
+battle.enemies_left.many = Осталось {count} врагов
+";
+
+    fn localization() -> Localization {
+        Localization::new("en")
+            .with_table(StringTable::parse("en", "en.lang", EN).unwrap())
+            .with_table(StringTable::parse("de", "de.lang", DE).unwrap())
+            .with_table(StringTable::parse("ru", "ru.lang", RU).unwrap())
+    }
+
+    #[test]
+    fn looks_up_interpolates_and_falls_back() {
+        let mut localization = localization();
+        assert_eq!(localization.get("menu.new_game"), "New Game");
+        assert_eq!(
+            localization.format(
+                "character.stats",
+                &[("name", &"Bia"), ("hp", &7), ("max_hp", &10)]
+            ),
+            "Bia\nHP: 7/10"
+        );
+        assert_eq!(localization.get("set"), "{literal} {unknown}");
+        assert_eq!(localization.get("missing"), "missing");
+
+        localization.set_language("de");
+        assert_eq!(localization.get("menu.new_game"), "Neues Spiel");
+        assert_eq!(
+            localization.format(
+                "character.stats",
+                &[("name", &"Bia"), ("hp", &7), ("max_hp", &10)]
+            ),
+            "Bia\nHP: 7/10"
+        );
+
+        assert!(matches!(
+            StringTable::parse("en", "broken.lang", "a = b\nno separator"),
+            Err(LocalizationError::Syntax(_, 2, _))
+        ));
+    }
+
+    #[test]
+    fn picks_plural_forms() {
+        let mut localization = localization();
+        assert_eq!(
+            localization.plural("battle.enemies_left", 1, &[]),
+            "1 enemy left"
+        );
+        assert_eq!(
+            localization.plural("battle.enemies_left", 0, &[]),
+            "0 enemies left"
+        );
+        localization.set_language("ru");
+        assert_eq!(
+            localization.plural("battle.enemies_left", 21, &[]),
+            "Остался 21 враг"
+        );
+        assert_eq!(
+            localization.plural("battle.enemies_left", 3, &[]),
+            "Осталось 3 врага"
+        );
+        assert_eq!(
+            localization.plural("battle.enemies_left", 12, &[]),
+            "Осталось 12 врагов"
+        );
+    }
+}
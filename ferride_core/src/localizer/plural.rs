@@ -0,0 +1,62 @@
+///Plural forms as named by the Unicode CLDR. Languages only use some of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+impl PluralCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+///Plural form of `count` in `language`, given as an ISO 639-1 code like `de`. Unknown
+///languages use the English rule
+pub fn plural_category(language: &str, count: u64) -> PluralCategory {
+    let language = language.split(['-', '_']).next().unwrap_or(language);
+    let (ones, tens) = (count % 10, count % 100);
+    match language {
+        "ja" | "ko" | "zh" | "vi" | "th" | "id" => PluralCategory::Other,
+        "fr" | "pt" if count <= 1 => PluralCategory::One,
+        "fr" | "pt" => PluralCategory::Other,
+        "ru" | "uk" | "be" => {
+            if ones == 1 && tens != 11 {
+                PluralCategory::One
+            } else if (2..=4).contains(&ones) && !(12..=14).contains(&tens) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "pl" => {
+            if count == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&ones) && !(12..=14).contains(&tens) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "ar" => match tens {
+            _ if count == 0 => PluralCategory::Zero,
+            _ if count == 1 => PluralCategory::One,
+            _ if count == 2 => PluralCategory::Two,
+            3..=10 => PluralCategory::Few,
+            11..=99 => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        },
+        _ if count == 1 => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
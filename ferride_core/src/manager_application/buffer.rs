@@ -2,26 +2,65 @@ use crate::graphics_provider::{
     BufferWriter, Index, IndexBufferWriter, Vertex, VertexBufferWriter,
 };
 
+///Indices of a scene. Stored as u16 until an index does not fit anymore, then all indices are
+///promoted to u32
 #[derive(Debug)]
 pub struct IndexBuffer {
     indices: Vec<u8>,
     num_indices: u32,
+    format: wgpu::IndexFormat,
 }
 impl IndexBuffer {
     pub fn new() -> Self {
         Self {
             indices: Vec::new(),
             num_indices: 0,
+            format: wgpu::IndexFormat::Uint16,
         }
     }
     pub fn extend_from_slice<I: Index>(&mut self, new_indices: &[I]) {
         self.num_indices += new_indices.len() as u32;
-        self.indices
-            .extend_from_slice(bytemuck::cast_slice(new_indices));
+        if I::index_format() == self.format {
+            self.indices
+                .extend_from_slice(bytemuck::cast_slice(new_indices));
+            return;
+        }
+        if self.format == wgpu::IndexFormat::Uint16
+            && new_indices.iter().any(|i| i.as_u32() > u16::MAX as u32)
+        {
+            self.promote();
+        }
+        for index in new_indices {
+            match self.format {
+                wgpu::IndexFormat::Uint16 => self
+                    .indices
+                    .extend_from_slice(&(index.as_u32() as u16).to_ne_bytes()),
+                wgpu::IndexFormat::Uint32 => self
+                    .indices
+                    .extend_from_slice(&index.as_u32().to_ne_bytes()),
+            }
+        }
     }
     pub fn len(&self) -> u32 {
         self.num_indices
     }
+    pub fn format(&self) -> wgpu::IndexFormat {
+        self.format
+    }
+    ///Removes all indices, but keeps the allocation. The format stays promoted
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.num_indices = 0;
+    }
+
+    fn promote(&mut self) {
+        self.indices = self
+            .indices
+            .chunks_exact(2)
+            .flat_map(|i| (u16::from_ne_bytes([i[0], i[1]]) as u32).to_ne_bytes())
+            .collect();
+        self.format = wgpu::IndexFormat::Uint32;
+    }
 }
 impl BufferWriter for IndexBuffer {
     fn buffer_len(&self) -> u32 {
@@ -32,7 +71,11 @@ impl BufferWriter for IndexBuffer {
         Some(&self.indices)
     }
 }
-impl IndexBufferWriter for IndexBuffer {}
+impl IndexBufferWriter for IndexBuffer {
+    fn index_format(&self) -> Option<wgpu::IndexFormat> {
+        Some(self.format)
+    }
+}
 
 #[derive(Debug)]
 pub struct VertexBuffer {
@@ -54,6 +97,11 @@ impl VertexBuffer {
     pub fn len(&self) -> u32 {
         self.num_vertices
     }
+    ///Removes all vertices, but keeps the allocation
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.num_vertices = 0;
+    }
 }
 impl BufferWriter for VertexBuffer {
     fn buffer_len(&self) -> u32 {
@@ -65,3 +113,28 @@ impl BufferWriter for VertexBuffer {
     }
 }
 impl VertexBufferWriter for VertexBuffer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn promotes_to_u32() {
+        let mut indices = IndexBuffer::new();
+        indices.extend_from_slice(&[0u32, 1, 2]);
+        assert_eq!(indices.format(), wgpu::IndexFormat::Uint16);
+        assert_eq!(indices.buffer_data().unwrap().len(), 6);
+
+        indices.extend_from_slice(&[70_000u32]);
+        indices.extend_from_slice(&[3u16]);
+        assert_eq!(indices.format(), wgpu::IndexFormat::Uint32);
+        assert_eq!(indices.len(), 5);
+        let data: Vec<u32> = indices
+            .buffer_data()
+            .unwrap()
+            .chunks_exact(4)
+            .map(|i| u32::from_ne_bytes([i[0], i[1], i[2], i[3]]))
+            .collect();
+        assert_eq!(data, [0, 1, 2, 70_000, 3]);
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    io::Cursor,
+    time::{Duration, Instant},
+};
+
+use image::{
+    codecs::gif::GifDecoder,
+    imageops::{crop_imm, resize, FilterType},
+    AnimationDecoder, RgbaImage,
+};
+use log::warn;
+use winit::{
+    event_loop::ActiveEventLoop,
+    window::{CustomCursor, CustomCursorSource, Window},
+};
+
+use crate::create_name_struct;
+
+create_name_struct!(CursorName);
+
+///Cursor that is shown when a window is created
+pub const DEFAULT_CURSOR: &str = "default";
+///Edge length of custom cursors in logical pixels, they are scaled with the window
+pub const CURSOR_SIZE: u32 = 32;
+
+#[derive(Clone, Debug)]
+pub enum CursorSource {
+    Image(&'static str),
+    ///Square frames in a single row, shown for `frame_duration` each
+    SpriteSheet {
+        path: &'static str,
+        frame_duration: Duration,
+    },
+    ///Frames and delays are taken from the gif
+    Gif(&'static str),
+}
+
+#[derive(Clone, Debug)]
+pub struct CursorDescriptor {
+    source: CursorSource,
+    hotspot: (f32, f32),
+}
+impl CursorDescriptor {
+    pub fn image(path: &'static str) -> Self {
+        Self::new(CursorSource::Image(path))
+    }
+
+    pub fn sprite_sheet(path: &'static str, frame_duration: Duration) -> Self {
+        Self::new(CursorSource::SpriteSheet {
+            path,
+            frame_duration,
+        })
+    }
+
+    pub fn gif(path: &'static str) -> Self {
+        Self::new(CursorSource::Gif(path))
+    }
+
+    fn new(source: CursorSource) -> Self {
+        Self {
+            source,
+            hotspot: (0.25, 0.0),
+        }
+    }
+
+    ///Point of the cursor that clicks, relative to its size. Defaults to a quarter of the way
+    ///along the top edge
+    pub fn with_hotspot(mut self, x: f32, y: f32) -> Self {
+        self.hotspot = (x, y);
+        self
+    }
+
+    fn path(&self) -> &'static str {
+        match self.source {
+            CursorSource::Image(path) | CursorSource::Gif(path) => path,
+            CursorSource::SpriteSheet { path, .. } => path,
+        }
+    }
+
+    fn decode_frames(&self) -> Vec<(RgbaImage, Duration)> {
+        let path = self.path();
+        let bytes =
+            crate::vfs::read(path).expect(&format!("Could not read cursor file at '{}'", path));
+        match self.source {
+            CursorSource::Image(_) => {
+                let image = image::load_from_memory(&bytes)
+                    .expect(&format!("Could not parse cursor file at '{}'", path))
+                    .into_rgba8();
+                vec![(image, Duration::ZERO)]
+            }
+            CursorSource::SpriteSheet { frame_duration, .. } => {
+                let image = image::load_from_memory(&bytes)
+                    .expect(&format!("Could not parse cursor file at '{}'", path))
+                    .into_rgba8();
+                split_frames(&image)
+                    .into_iter()
+                    .map(|frame| (frame, frame_duration))
+                    .collect()
+            }
+            CursorSource::Gif(_) => GifDecoder::new(Cursor::new(bytes))
+                .and_then(|decoder| decoder.into_frames().collect_frames())
+                .expect(&format!("Could not parse cursor file at '{}'", path))
+                .into_iter()
+                .map(|frame| {
+                    let delay = Duration::from(frame.delay());
+                    (frame.into_buffer(), delay)
+                })
+                .collect(),
+        }
+    }
+
+    ///Every frame of the cursor and how long it is shown, sized for a window with `scale_factor`
+    fn decode_cursor(&self, scale_factor: f64) -> Vec<(CustomCursorSource, Duration)> {
+        let size = ((CURSOR_SIZE as f64 * scale_factor).round() as u32).max(1);
+        let hotspot_x = ((size as f32 * self.hotspot.0) as u16).min(size as u16 - 1);
+        let hotspot_y = ((size as f32 * self.hotspot.1) as u16).min(size as u16 - 1);
+        self.decode_frames()
+            .into_iter()
+            .map(|(frame, duration)| {
+                let frame = resize(&frame, size, size, FilterType::Gaussian);
+                let (w, h) = (frame.width() as u16, frame.height() as u16);
+                let cursor =
+                    CustomCursor::from_rgba(frame.into_raw(), w, h, hotspot_x, hotspot_y).expect(
+                        &format!("Could not make cursor from file at '{}'", self.path()),
+                    );
+                (cursor, duration)
+            })
+            .collect()
+    }
+}
+
+///Cuts a horizontal strip into square frames
+fn split_frames(image: &RgbaImage) -> Vec<RgbaImage> {
+    let size = image.height().max(1);
+    (0..(image.width() / size).max(1))
+        .map(|i| crop_imm(image, i * size, 0, size, size).to_image())
+        .collect()
+}
+
+///Index of the frame that is shown `elapsed` after the animation started, it loops forever
+fn current_frame(durations: &[Duration], elapsed: Duration) -> usize {
+    let total: Duration = durations.iter().sum();
+    if total.is_zero() {
+        return 0;
+    }
+    let mut elapsed = Duration::from_nanos((elapsed.as_nanos() % total.as_nanos()) as u64);
+    for (i, duration) in durations.iter().enumerate() {
+        if elapsed < *duration {
+            return i;
+        }
+        elapsed -= *duration;
+    }
+    durations.len() - 1
+}
+
+///The custom cursors of a window and which one of them is shown
+pub(super) struct WindowCursors {
+    cursors: Vec<(CursorName, Vec<(CustomCursor, Duration)>)>,
+    active: CursorName,
+    shown_frame: usize,
+    activated_at: Instant,
+}
+impl WindowCursors {
+    ///Shows `active` on the window, if it is one of `descriptors`
+    pub(super) fn new(
+        descriptors: &[(CursorName, CursorDescriptor)],
+        active: CursorName,
+        event_loop: &ActiveEventLoop,
+        window: &Window,
+    ) -> Self {
+        let cursors = descriptors
+            .iter()
+            .map(|(name, descriptor)| {
+                let frames = descriptor
+                    .decode_cursor(window.scale_factor())
+                    .into_iter()
+                    .map(|(source, duration)| (event_loop.create_custom_cursor(source), duration))
+                    .collect();
+                (name.clone(), frames)
+            })
+            .collect();
+        let mut window_cursors = Self {
+            cursors,
+            active: active.clone(),
+            shown_frame: 0,
+            activated_at: Instant::now(),
+        };
+        if window_cursors.cursors.iter().any(|(n, _)| *n == active) {
+            window_cursors.set_active(&active, window);
+        }
+        window_cursors
+    }
+
+    pub(super) fn active(&self) -> &CursorName {
+        &self.active
+    }
+
+    pub(super) fn set_active(&mut self, name: &CursorName, window: &Window) {
+        let Some((_, frames)) = self.cursors.iter().find(|(n, _)| n == name) else {
+            warn!(
+                "Tried to show cursor {:?}, but the window has none by that name",
+                name
+            );
+            return;
+        };
+        if let Some((cursor, _)) = frames.first() {
+            window.set_cursor(cursor.clone());
+        }
+        self.active = name.clone();
+        self.shown_frame = 0;
+        self.activated_at = Instant::now();
+    }
+
+    ///Advances animated cursors to the frame due now
+    pub(super) fn animate(&mut self, window: &Window) {
+        let Some((_, frames)) = self.cursors.iter().find(|(n, _)| *n == self.active) else {
+            return;
+        };
+        if frames.len() < 2 {
+            return;
+        }
+        let durations = frames.iter().map(|(_, d)| *d).collect::<Vec<_>>();
+        let frame = current_frame(&durations, self.activated_at.elapsed());
+        if frame != self.shown_frame {
+            window.set_cursor(frames[frame].0.clone());
+            self.shown_frame = frame;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loops_through_animation_frames() {
+        let strip = RgbaImage::new(96, 32);
+        assert_eq!(split_frames(&strip).len(), 3);
+        assert_eq!(split_frames(&RgbaImage::new(20, 32)).len(), 1);
+
+        let ms = Duration::from_millis;
+        let durations = [ms(100), ms(50), ms(100)];
+        assert_eq!(current_frame(&durations, ms(0)), 0);
+        assert_eq!(current_frame(&durations, ms(120)), 1);
+        assert_eq!(current_frame(&durations, ms(150)), 2);
+        assert_eq!(current_frame(&durations, ms(260)), 0);
+        assert_eq!(current_frame(&[Duration::ZERO], ms(260)), 0);
+    }
+}
@@ -16,6 +16,9 @@ pub mod winit_reexports {
 mod window_descriptor;
 pub use window_descriptor::WindowDescriptor;
 
+mod cursor;
+pub use cursor::{CursorDescriptor, CursorName, CursorSource, CURSOR_SIZE, DEFAULT_CURSOR};
+use cursor::WindowCursors;
 
 mod event_manager;
 pub use event_manager::EventManager;
@@ -35,6 +38,9 @@ pub struct ManagerApplication<E: ApplicationEvent + 'static, M: EventManager<E>>
     event_manager: M,
     window_manager: WindowManager<E>,
     graphics_provider: GraphicsProvider,
+    ///Kept to recreate the cursors when the scale factor of a window changes
+    window_descriptors: Vec<(WindowId, WindowDescriptor)>,
+    cursors: Vec<(WindowId, WindowCursors)>,
 }
 
 impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ApplicationHandler<E>
@@ -56,11 +62,22 @@ impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ApplicationHandler<E
                     } else {
                         self.graphics_provider.remove_window(&id);
                         self.window_manager.remove_window(&id);
+                        self.window_descriptors.retain(|(i, _)| i != &id);
+                        self.cursors.retain(|(i, _)| i != &id);
                     }
                 }
                 WindowEvent::Resized(size) => self.graphics_provider.resize_window(&id, &size),
+                // The window is resized on its own, which fires a Resized event
                 WindowEvent::ScaleFactorChanged { .. } => {
-                    //TODO: I think the window will be resized  on its own, which fires a Resized event
+                    if let (Some(window), Some((_, descriptor)), Some((_, cursors))) = (
+                        self.window_manager.get_window(&id),
+                        self.window_descriptors.iter().find(|(i, _)| i == &id),
+                        self.cursors.iter_mut().find(|(i, _)| i == &id),
+                    ) {
+                        let active = cursors.active().clone();
+                        *cursors =
+                            WindowCursors::new(descriptor.cursors(), active, event_loop, window);
+                    }
                 }
                 WindowEvent::RedrawRequested => {
                     self.graphics_provider.render_window(&id);
@@ -97,6 +114,7 @@ impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ApplicationHandler<E
     }
 
     fn user_event(&mut self, event_loop: &ActiveEventLoop, event: E) {
+        self.graphics_provider.profiler_mut().count_event();
         match event.is_request_new_window() {
             Some((window_descriptor, name)) => {
                 self.create_window(window_descriptor, event_loop, name)
@@ -136,6 +154,14 @@ impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ApplicationHandler<E
             }
             None => {}
         }
+        if let Some((id, cursor)) = event.is_request_set_cursor() {
+            if let (Some(window), Some((_, cursors))) = (
+                self.window_manager.get_window(id),
+                self.cursors.iter_mut().find(|(i, _)| i == id),
+            ) {
+                cursors.set_active(cursor, window);
+            }
+        }
         match event.is_request_set_visibility_render_scene() {
             Some((render_scene, visibility)) => {
                 self.graphics_provider
@@ -155,6 +181,18 @@ impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ApplicationHandler<E
             event,
         );
     }
+
+    fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
+        for (id, cursors) in self.cursors.iter_mut() {
+            if let Some(window) = self.window_manager.get_window(id) {
+                cursors.animate(window);
+            }
+        }
+    }
+
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        self.graphics_provider.profiler().write_chrome_trace_from_env();
+    }
 }
 
 impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ManagerApplication<E, M> {
@@ -163,6 +201,8 @@ impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ManagerApplication<E
             event_manager,
             window_manager: Default::default(),
             graphics_provider: GraphicsProvider::new(),
+            window_descriptors: Vec::new(),
+            cursors: Vec::new(),
         }
     }
 
@@ -173,12 +213,27 @@ impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ManagerApplication<E
         name: &str,
     ) {
         let window = active_loop
-            .create_window(descriptor.get_attributes(active_loop))
+            .create_window(descriptor.get_attributes())
             .expect("OS says: 'No more windows for you'");
+        if !descriptor.cursors().is_empty() {
+            let cursors = WindowCursors::new(
+                descriptor.cursors(),
+                DEFAULT_CURSOR.into(),
+                active_loop,
+                &window,
+            );
+            self.cursors.push((window.id(), cursors));
+        }
         self.window_manager
-            .send_event(E::new_window(&window.id(), name));
+            .send_event(E::new_window(&window.id(), name, window.scale_factor()));
         self.graphics_provider.init_window(&window);
+        self.graphics_provider
+            .set_post_processing(&window.id(), descriptor.post_processing().to_vec());
+        self.graphics_provider
+            .set_virtual_resolution(&window.id(), descriptor.virtual_resolution());
         // window.request_redraw();
+        self.window_descriptors
+            .push((window.id(), descriptor.clone()));
         self.window_manager.add_window(window);
     }
 
@@ -197,7 +252,7 @@ impl<'a, E: ApplicationEvent + 'static, M: EventManager<E>> ManagerApplication<E
 
 pub trait ApplicationEvent: Debug {
     fn app_resumed() -> Self;
-    fn new_window(id: &WindowId, name: &str) -> Self;
+    fn new_window(id: &WindowId, name: &str, scale_factor: f64) -> Self;
     fn new_texture(label: &str, id: Option<u32>) -> Self;
     fn new_render_scene(render_scene: &RenderSceneName) -> Self;
     fn is_request_new_window<'a>(&'a self) -> Option<(&'a WindowDescriptor, &'a str)>;
@@ -216,5 +271,6 @@ pub trait ApplicationEvent: Debug {
     fn is_request_set_visibility_render_scene<'a>(
         &'a self,
     ) -> Option<(&'a RenderSceneName, &'a Visibility)>;
+    fn is_request_set_cursor<'a>(&'a self) -> Option<(&'a WindowId, &'a CursorName)>;
     fn is_quit(&self) -> bool;
 }
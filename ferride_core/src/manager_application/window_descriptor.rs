@@ -1,22 +1,22 @@
-use image::imageops::{resize, FilterType};
-use std::fs;
 pub mod winit_reexports {
     pub use winit::{
         dpi::{Position, Size},
         window::{Fullscreen, Icon, Theme, WindowButtons, WindowLevel},
     };
 }
-use winit::{
-    event_loop::ActiveEventLoop,
-    window::{CustomCursor, CustomCursorSource, WindowAttributes},
-};
+use winit::window::WindowAttributes;
 use winit_reexports::*;
 
+use super::cursor::{CursorDescriptor, CursorName, DEFAULT_CURSOR};
+use crate::graphics_provider::{PostProcessDescriptor, VirtualResolution};
+
 #[derive(Clone, Debug)]
 pub struct WindowDescriptor {
     attributes: WindowAttributes,
-    cursor_path: Option<&'static str>,
+    cursors: Vec<(CursorName, CursorDescriptor)>,
     icon_path: Option<&'static str>,
+    post_processing: Vec<PostProcessDescriptor>,
+    virtual_resolution: Option<VirtualResolution>,
 }
 impl WindowDescriptor {
     pub fn new() -> Self {
@@ -25,18 +25,53 @@ impl WindowDescriptor {
         }
     }
 
-    pub fn with_cursor(mut self, path: &'static str) -> Self {
-        self.cursor_path = Some(path);
+    ///Static image as the `DEFAULT_CURSOR`
+    pub fn with_cursor(self, path: &'static str) -> Self {
+        self.with_named_cursor(DEFAULT_CURSOR, CursorDescriptor::image(path))
+    }
+
+    ///Adds a cursor the window can switch to, see `ExternalEvent::is_request_set_cursor`.
+    ///Replaces an earlier cursor of the same name
+    pub fn with_named_cursor(mut self, name: &str, descriptor: CursorDescriptor) -> Self {
+        let name = CursorName::from(name);
+        self.cursors.retain(|(n, _)| *n != name);
+        self.cursors.push((name, descriptor));
         self
     }
 
+    pub fn cursors(&self) -> &[(CursorName, CursorDescriptor)] {
+        &self.cursors
+    }
+
     pub fn with_icon(mut self, path: &'static str) -> Self {
         self.icon_path = Some(path);
         self
     }
 
+    ///Full screen passes applied in order to everything rendered in the window
+    pub fn with_post_processing(mut self, passes: Vec<PostProcessDescriptor>) -> Self {
+        self.post_processing = passes;
+        self
+    }
+
+    pub fn post_processing(&self) -> &[PostProcessDescriptor] {
+        &self.post_processing
+    }
+
+    ///Cameras of the window's scenes lay them out in `virtual_resolution` instead of stretching
+    ///them with the window. Static cameras follow it too, see `RessourceDescriptor::static_cameras`
+    pub fn with_virtual_resolution(mut self, virtual_resolution: VirtualResolution) -> Self {
+        self.virtual_resolution = Some(virtual_resolution);
+        self
+    }
+
+    pub fn virtual_resolution(&self) -> Option<VirtualResolution> {
+        self.virtual_resolution
+    }
+
     fn decode_icon(&self, path: &'static str) -> Icon {
-        let bytes = fs::read(path).expect(&format!("Could not read icon file at '{}'", path));
+        let bytes =
+            crate::vfs::read(path).expect(&format!("Could not read icon file at '{}'", path));
 
         let (icon_rgba, icon_width, icon_height) = {
             let image = image::load_from_memory(&bytes)
@@ -50,25 +85,10 @@ impl WindowDescriptor {
             .expect(&format!("Could not make icon from file at '{}'", path))
     }
 
-    fn decode_cursor(&self, path: &'static str) -> CustomCursorSource {
-        let bytes = fs::read(path).expect(&format!("Could not read cursor file at '{}'", path));
-        let img = image::load_from_memory(&bytes)
-            .expect(&format!("Could not parse cursor file at '{}'", path))
-            .into_rgba8();
-        let img = resize(&img, 32, 32, FilterType::Gaussian);
-        let samples = img.into_flat_samples();
-        let (_, w, h) = samples.extents();
-        let (w, h) = (w as u16, h as u16);
-        CustomCursor::from_rgba(samples.samples, w, h, w / 4, 0)
-            .expect(&format!("Could not make cursor from file at '{}'", path))
-    }
-
-    pub fn get_attributes(&self, event_loop: &ActiveEventLoop) -> WindowAttributes {
+    ///The cursors are left out, as the scale factor is only known once the window exists. See
+    ///`WindowCursors::new`
+    pub fn get_attributes(&self) -> WindowAttributes {
         let mut attributes = self.attributes.clone();
-        if let Some(cursor_path) = self.cursor_path {
-            let cursor_source = self.decode_cursor(cursor_path);
-            attributes = attributes.with_cursor(event_loop.create_custom_cursor(cursor_source));
-        }
         if let Some(icon_path) = self.icon_path {
             let icon = self.decode_icon(icon_path);
             attributes = attributes.with_window_icon(Some(icon));
@@ -80,8 +100,10 @@ impl Default for WindowDescriptor {
     fn default() -> Self {
         Self {
             attributes: WindowAttributes::default(),
-            cursor_path: None,
+            cursors: Vec::new(),
             icon_path: None,
+            post_processing: Vec::new(),
+            virtual_resolution: None,
         }
     }
 }
@@ -1,19 +1,18 @@
 use ferride_core::{
     app::{ManagerApplication, WindowDescriptor},
     game_engine::{Game, RessourceDescriptorBuilder, Scene},
-    graphics::{Index as I, RenderSceneDescriptor, ShaderDescriptor, Vertex},
+    graphics::{Color, Index as I, RenderSceneDescriptor, RenderSceneKind, ShaderDescriptor, Vertex},
     reexports::{
         wgpu::{vertex_attr_array, VertexAttribute},
         winit::PhysicalSize,
     },
+    vfs::VirtualFileSystem,
 };
 
 mod sos;
 mod polygon;
 mod simple_polygon;
 use simple_polygon::Polygon;
-mod color;
-use color::Color;
 
 use ferride_core::game_engine::example::EmptyEntityType as EntityType;
 use ferride_core::game_engine::example::EmptyExternalEvent as Event;
@@ -46,10 +45,16 @@ impl Vertex for SimpleVertex {
 const TARGET_FPS: u8 = 60;
 
 fn main() {
+    // The shader is found next to the executable, in the working directory or, when started
+    // with `cargo run -p polygon` from the workspace root, in `polygon/`
+    VirtualFileSystem::new().with_directory("polygon").install();
+
     let ressources = RessourceDescriptorBuilder::new(RenderSceneDescriptor {
         index_format: u16::index_format(),
         use_textures: false,
         vertex_buffer_layout: SimpleVertex::describe_buffer_layout(),
+        shader_defines: &[],
+        kind: RenderSceneKind::Vertices,
     })
     .with_windows(vec![(
         "polygon".into(),
@@ -67,7 +72,7 @@ fn main() {
             render_scene: "polygon".into(),
             target_window: "polygon".into(),
             shader_descriptor: ShaderDescriptor {
-                file: "C:\\Users\\DarkL\\Desktop\\Rust\\placeholder\\polygon\\shader.wgsl",
+                file: "shader.wgsl",
                 fragment_shader: "fs_main",
                 vertex_shader: "vs_main",
                 uniforms: &[],
@@ -5,11 +5,12 @@ use std::{
 
 use ferride_core::{
     game_engine::{BoundingBox, Entity, EntityName},
+    graphics::Color,
     reexports::winit::PhysicalSize,
 };
 use threed::Vector;
 
-use crate::{color::Color, EntityType, Event, SimpleVertex};
+use crate::{EntityType, Event, SimpleVertex};
 #[derive(Clone, Copy, PartialEq, Default, PartialOrd)]
 struct FixedNumber(i128);
 impl Debug for FixedNumber {
@@ -505,7 +506,7 @@ impl Polygon {
                                 0.0,
                             ),
                         ],
-                        Color::from_str("red"),
+                        Color::RED,
                     ),
                     (
                         [
@@ -525,7 +526,7 @@ impl Polygon {
                                 0.0,
                             ),
                         ],
-                        Color::from_str("red"),
+                        Color::RED,
                     ),
                 ]
             })
@@ -577,7 +578,7 @@ impl Polygon {
                                                 0.0,
                                             ),
                                         ],
-                                        Color::from_str("black"),
+                                        Color::BLACK,
                                     ),
                                     (
                                         [
@@ -597,7 +598,7 @@ impl Polygon {
                                                 0.0,
                                             ),
                                         ],
-                                        Color::from_str("black"),
+                                        Color::BLACK,
                                     ),
                                 ]
                             })
@@ -622,7 +623,7 @@ impl Polygon {
                             0.0,
                         ),
                     ],
-                    Color::from_str("black"),
+                    Color::BLACK,
                 )
             }))
             .chain(self.triangles.iter().map(|t| {
@@ -644,7 +645,7 @@ impl Polygon {
                             0.0,
                         ),
                     ],
-                    Color::from_str("purple"),
+                    Color::new_rgb(170, 0, 140),
                 )
             }))
     }
@@ -667,7 +668,7 @@ impl Entity<EntityType, Event> for Polygon {
                 SimpleVertex::new(triangle[1].clone(), color.clone()),
                 SimpleVertex::new(triangle[2].clone(), color.clone()),
             ];
-            let start_index = vertices.len() as u16;
+            let start_index = vertices.len();
             let new_indices = [start_index, start_index + 1, start_index + 2];
             vertices.extend_from_slice(&new_vertices);
             indices.extend_from_slice(&new_indices)
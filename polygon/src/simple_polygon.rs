@@ -1,11 +1,11 @@
 use crate::{
-    color::Color,
     sos::{FixedNumber, Point, SCALE},
     EntityType, Event, SimpleVertex,
 };
 
 use ferride_core::{
     game_engine::{BoundingBox, Entity, EntityName},
+    graphics::Color,
     reexports::winit::PhysicalSize,
 };
 use threed::{Matrix, Vector};
@@ -442,7 +442,7 @@ impl Polygon {
                                 0.0,
                             ),
                         ],
-                        Color::from_str("red"),
+                        Color::RED,
                     ),
                     (
                         [
@@ -462,7 +462,7 @@ impl Polygon {
                                 0.0,
                             ),
                         ],
-                        Color::from_str("red"),
+                        Color::RED,
                     ),
                 ]
             })
@@ -483,7 +483,7 @@ impl Polygon {
                             0.0,
                         ),
                     ],
-                    Color::from_str("black"),
+                    Color::BLACK,
                 )
             }))
             .chain(self.triangles.iter().map(|t| {
@@ -505,7 +505,7 @@ impl Polygon {
                             0.0,
                         ),
                     ],
-                    Color::from_str("purple"),
+                    Color::new_rgb(170, 0, 140),
                 )
             }))
     }
@@ -528,7 +528,7 @@ impl Entity<EntityType, Event> for Polygon {
                 SimpleVertex::new(triangle[1].clone(), color.clone()),
                 SimpleVertex::new(triangle[2].clone(), color.clone()),
             ];
-            let start_index = vertices.len() as u16;
+            let start_index = vertices.len();
             let new_indices = [start_index, start_index + 1, start_index + 2];
             vertices.extend_from_slice(&new_vertices);
             indices.extend_from_slice(&new_indices)
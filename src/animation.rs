@@ -79,6 +79,10 @@ impl<T> Animation<T> {
         false
     }
 
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
     pub fn keyframe(&self) -> &T {
         &self.keyframes[self.current_keyframe].1
     }
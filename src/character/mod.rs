@@ -1,4 +1,5 @@
 use std::fmt::{Debug, Display};
+use ferride_core::localization::tr_with;
 
 mod skilled_character;
 pub use skilled_character::{CharacterBuilder, CharacterGuiManager, SkilledCharacter, CHARACTER_FONT_SIZE};
@@ -72,6 +73,11 @@ impl Character {
     pub fn is_dead(&self) -> bool {
         self.health <= 0
     }
+
+    ///At most a quarter of the max health left
+    pub fn is_low_on_health(&self) -> bool {
+        self.health as u32 * 4 <= self.max_health as u32
+    }
 }
 impl Debug for Character {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -90,21 +96,19 @@ impl Debug for Character {
     }
 }
 
-pub const CHARACTER_DISPLAY_LINES: f32 = 4.0;
 impl Display for Character {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}
-HP: {}/{}
-ST: {}/{}
-EX: {}",
-            self.name,
-            self.health,
-            self.max_health,
-            self.stamina,
-            self.max_stamina,
-            self.exhaustion,
-        )
+        let stats = tr_with(
+            "character.stats",
+            &[
+                ("name", &self.name),
+                ("health", &self.health),
+                ("max_health", &self.max_health),
+                ("stamina", &self.stamina),
+                ("max_stamina", &self.max_stamina),
+                ("exhaustion", &self.exhaustion),
+            ],
+        );
+        write!(f, "{}", stats)
     }
 }
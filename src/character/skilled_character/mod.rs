@@ -1,8 +1,8 @@
+use ferride_core::graphics::Color;
 use ferride_core::reexports::winit::PhysicalSize;
 use threed::Vector;
 
 use crate::battle_action::BattleActionManager;
-use crate::color::Color;
 use crate::ui::button_styles::{ColorPair, UNFOCUS_LOW_COLOR};
 use crate::ui::{Button, ButtonStyle, FlexItem, FontSize, Padding, ProgressBar};
 use crate::{BATTLE_DETAIL_OVERLAY, RESOLUTION};
@@ -12,7 +12,7 @@ use self::ui::CharacterGui;
 
 use super::skills::Skill;
 
-use super::{Character, KIBehavior, CHARACTER_DISPLAY_LINES};
+use super::{Character, KIBehavior};
 
 pub mod ui;
 
@@ -28,8 +28,6 @@ pub trait CharacterGuiManager {
 }
 
 pub const CHARACTER_FONT_SIZE: u8 = 32;
-pub const CHARACTER_TEXT_HEIGHT: f32 =
-    (CHARACTER_DISPLAY_LINES + 0.25) * CHARACTER_FONT_SIZE as f32;
 pub const ORIGINAL_CHARACTER_PORTRAIT_SIZE: PhysicalSize<u16> = PhysicalSize::new(608, 1080);
 const HEIGHT_SCALE_CHARACTER_PORTRAIT: f32 =
     ORIGINAL_CHARACTER_PORTRAIT_SIZE.height as f32 / (3.0 * RESOLUTION.height as f32);
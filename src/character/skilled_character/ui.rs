@@ -108,6 +108,9 @@ impl CharacterGui {
     }
 }
 impl Entity<Type, Event> for CharacterGui {
+    fn is_dirty(&mut self) -> bool {
+        self.button.is_dirty() | self.bars.is_dirty()
+    }
     fn update(
         &mut self,
         entities: &Vec<&Box<dyn Entity<Type, Event>>>,
@@ -172,10 +175,6 @@ impl FlexItem for CharacterGui {
         self.bars.set_position(position);
     }
 
-    fn is_dirty(&mut self) -> bool {
-        self.button.is_dirty()
-    }
-
     fn set_focus(&mut self, focus: bool) {
         self.button.set_focus(focus);
     }
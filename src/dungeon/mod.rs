@@ -1,10 +1,19 @@
 use std::fmt::{Debug, Display};
 
+mod tilemap;
+pub use tilemap::{
+    TilemapEntity, TilemapLayer, Tileset, CORNER_DOWN, CORNER_LEFT, CORNER_RIGHT, CORNER_UP,
+};
+
 pub trait Edge {
     fn as_char(&self, direction: Direction) -> char;
     fn from_char(c: char, direction: Direction) -> Option<Self>
     where
         Self: Sized;
+    ///Whether the edge casts shadows
+    fn blocks_light(&self) -> bool {
+        false
+    }
 }
 
 pub trait Center {
@@ -61,6 +70,9 @@ impl Edge for Wall {
             _ => None,
         }
     }
+    fn blocks_light(&self) -> bool {
+        true
+    }
 }
 
 struct Trap;
@@ -80,7 +92,7 @@ impl Occupance for Trap {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Direction {
     Left,
     Up,
@@ -193,11 +205,11 @@ impl Display for Tile {
 /// |       |
 /// |-------|
 
-struct DungeonLayout {
+pub struct DungeonLayout {
     tiles: Vec<Tile>,
     width: u8,
 }
-enum DungeonLayoutBuilderErr {
+pub enum DungeonLayoutBuilderErr {
     UnhandledSymbol(char),
     MalformedLayout(String),
     EmptyDungeon,
@@ -0,0 +1,468 @@
+use ferride_core::{
+    app::{IndexBuffer, VertexBuffer},
+    game_engine::{
+        BoundingBox, Entity, EntityName, LineSegment, SpritePosition, SpriteSheet,
+        SpriteSheetName,
+    },
+    reexports::winit::PhysicalSize,
+};
+use std::fmt::Debug;
+use threed::Vector;
+
+use crate::{vertex::render_sprite, Event, Type};
+
+use super::{Direction, DungeonLayout, Tile};
+
+///Bit of a corner mask for a wall segment leaving the corner upwards
+pub const CORNER_UP: u8 = 1;
+pub const CORNER_RIGHT: u8 = 2;
+pub const CORNER_DOWN: u8 = 4;
+pub const CORNER_LEFT: u8 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TilemapLayer {
+    Floor,
+    ///Edges of the tiles and the corners where they meet
+    Edges,
+    Occupiers,
+}
+
+///Sprites of a tileset sprite sheet. Edges and occupiers are looked up by the character they are
+///printed as
+pub struct Tileset {
+    sprite_sheet: SpriteSheetName,
+    floor: SpritePosition,
+    edges: Vec<(Direction, char, SpritePosition)>,
+    corners: Vec<(u8, SpritePosition)>,
+    occupiers: Vec<(char, SpritePosition)>,
+}
+impl Tileset {
+    pub fn new(sprite_sheet: SpriteSheetName, floor: SpritePosition) -> Self {
+        Self {
+            sprite_sheet,
+            floor,
+            edges: Vec::new(),
+            corners: Vec::new(),
+            occupiers: Vec::new(),
+        }
+    }
+
+    ///Sprite for edges printed as `symbol` on the `direction` side of a tile
+    pub fn with_edge(mut self, direction: Direction, symbol: char, sprite: SpritePosition) -> Self {
+        self.edges.push((direction, symbol, sprite));
+        self
+    }
+
+    ///Sprite for a corner whose wall segments match `mask`, a combination of `CORNER_UP`,
+    ///`CORNER_RIGHT`, `CORNER_DOWN` and `CORNER_LEFT`. Corners without a sprite stay empty
+    pub fn with_corner(mut self, mask: u8, sprite: SpritePosition) -> Self {
+        self.corners.push((mask, sprite));
+        self
+    }
+
+    pub fn with_occupier(mut self, symbol: char, sprite: SpritePosition) -> Self {
+        self.occupiers.push((symbol, sprite));
+        self
+    }
+
+    fn edge(&self, direction: Direction, symbol: char) -> Option<&SpritePosition> {
+        self.edges
+            .iter()
+            .find(|(d, s, _)| *d == direction && *s == symbol)
+            .map(|(_, _, sprite)| sprite)
+    }
+
+    fn corner(&self, mask: u8) -> Option<&SpritePosition> {
+        self.corners
+            .iter()
+            .find(|(m, _)| *m == mask)
+            .map(|(_, sprite)| sprite)
+    }
+
+    fn occupier(&self, symbol: char) -> Option<&SpritePosition> {
+        self.occupiers
+            .iter()
+            .find(|(s, _)| *s == symbol)
+            .map(|(_, sprite)| sprite)
+    }
+}
+
+struct TileSprite {
+    layer: TilemapLayer,
+    bounding_box: BoundingBox,
+    sprite: SpritePosition,
+}
+
+///Renders a `DungeonLayout` with a `Tileset`. The top left corner of the layout is at `position`.
+///Edges are drawn as strips of `edge_thickness` on the tile borders, corners as squares where
+///the borders meet
+pub struct TilemapEntity {
+    name: EntityName,
+    sprite_sheet: SpriteSheetName,
+    position: Vector<f32>,
+    size: PhysicalSize<f32>,
+    sprites: Vec<TileSprite>,
+    ///Walls of the layout, they cast shadows as long as the edges are rendered
+    occluders: Vec<LineSegment>,
+    layers: Vec<TilemapLayer>,
+    view: Option<BoundingBox>,
+    is_dirty: bool,
+}
+impl TilemapEntity {
+    pub fn new(
+        name: EntityName,
+        layout: &DungeonLayout,
+        tileset: &Tileset,
+        position: Vector<f32>,
+        tile_size: f32,
+        edge_thickness: f32,
+    ) -> Self {
+        let width = layout.width as usize;
+        let height = layout.height();
+        let tile_center = |x: usize, y: usize| {
+            Vector::new(
+                position.x + (x as f32 + 0.5) * tile_size,
+                position.y - (y as f32 + 0.5) * tile_size,
+                position.z,
+            )
+        };
+        let mut sprites = Vec::new();
+        let mut push = |layer, anchor, size, sprite: &SpritePosition| {
+            sprites.push(TileSprite {
+                layer,
+                bounding_box: BoundingBox { anchor, size },
+                sprite: SpritePosition::new(sprite.x, sprite.y),
+            })
+        };
+        for (i, tile) in layout.tiles.iter().enumerate() {
+            let center = tile_center(i % width, i / width);
+            push(
+                TilemapLayer::Floor,
+                center.clone(),
+                PhysicalSize::new(tile_size, tile_size),
+                &tileset.floor,
+            );
+            for (direction, edge) in tile.edges() {
+                let symbol = edge.as_char(direction);
+                let sprite = match tileset.edge(direction, symbol) {
+                    Some(sprite) => sprite,
+                    None => continue,
+                };
+                let offset = tile_size / 2.0;
+                let (anchor, size) = match direction {
+                    Direction::Left | Direction::Right => {
+                        let x = if let Direction::Left = direction {
+                            center.x - offset
+                        } else {
+                            center.x + offset
+                        };
+                        (
+                            Vector::new(x, center.y, center.z),
+                            PhysicalSize::new(edge_thickness, tile_size),
+                        )
+                    }
+                    Direction::Up | Direction::Down => {
+                        let y = if let Direction::Up = direction {
+                            center.y + offset
+                        } else {
+                            center.y - offset
+                        };
+                        (
+                            Vector::new(center.x, y, center.z),
+                            PhysicalSize::new(tile_size, edge_thickness),
+                        )
+                    }
+                };
+                push(TilemapLayer::Edges, anchor, size, sprite);
+            }
+            if let Some(occupier) = &tile.occupier {
+                if let Some(sprite) = tileset.occupier(occupier.as_char()) {
+                    push(
+                        TilemapLayer::Occupiers,
+                        center.clone(),
+                        PhysicalSize::new(tile_size, tile_size),
+                        sprite,
+                    );
+                }
+            }
+        }
+        for y in 0..=height {
+            for x in 0..=width {
+                let mask = layout.corner_mask(x, y);
+                if mask == 0 {
+                    continue;
+                }
+                if let Some(sprite) = tileset.corner(mask) {
+                    push(
+                        TilemapLayer::Edges,
+                        Vector::new(
+                            position.x + x as f32 * tile_size,
+                            position.y - y as f32 * tile_size,
+                            position.z,
+                        ),
+                        PhysicalSize::new(edge_thickness, edge_thickness),
+                        sprite,
+                    );
+                }
+            }
+        }
+        let occluders = layout.wall_segments(&position, tile_size);
+        Self {
+            name,
+            sprite_sheet: tileset.sprite_sheet.clone(),
+            position,
+            size: PhysicalSize::new(width as f32 * tile_size, height as f32 * tile_size),
+            sprites,
+            occluders,
+            layers: vec![
+                TilemapLayer::Floor,
+                TilemapLayer::Edges,
+                TilemapLayer::Occupiers,
+            ],
+            view: None,
+            is_dirty: true,
+        }
+    }
+
+    ///Only renders the given layers, so e.g. occupiers can be a separate entity with another z
+    pub fn with_layers(mut self, layers: Vec<TilemapLayer>) -> Self {
+        self.layers = layers;
+        self
+    }
+}
+impl Debug for TilemapEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TilemapEntity")
+            .field("name", &self.name)
+            .field("layers", &self.layers)
+            .field("sprites", &self.sprites.len())
+            .finish()
+    }
+}
+impl Entity<Type, Event> for TilemapEntity {
+    fn render(
+        &mut self,
+        vertices: &mut VertexBuffer,
+        _indices: &mut IndexBuffer,
+        sprite_sheet: Vec<Option<&SpriteSheet>>,
+    ) {
+        let sprite_sheet = match sprite_sheet[0] {
+            Some(sprite_sheet) => sprite_sheet,
+            None => return,
+        };
+        for layer in self.layers.iter() {
+            for sprite in self.sprites.iter().filter(|sprite| sprite.layer == *layer) {
+                if let Some(view) = &self.view {
+                    if !view.intersects(&sprite.bounding_box) {
+                        continue;
+                    }
+                }
+                render_sprite(&sprite.bounding_box, vertices, sprite_sheet, &sprite.sprite);
+            }
+        }
+    }
+    fn camera_view(&mut self, view: &BoundingBox) {
+        let moved = match &self.view {
+            Some(old) => {
+                old.anchor.x != view.anchor.x
+                    || old.anchor.y != view.anchor.y
+                    || old.size != view.size
+            }
+            None => true,
+        };
+        if moved {
+            self.view = Some(view.clone());
+            self.is_dirty = true;
+        }
+    }
+    fn light_occluders(&self) -> Vec<LineSegment> {
+        if self.layers.contains(&TilemapLayer::Edges) {
+            self.occluders.clone()
+        } else {
+            vec![]
+        }
+    }
+    fn is_dirty(&mut self) -> bool {
+        let dirt = self.is_dirty;
+        self.is_dirty = false;
+        dirt
+    }
+    fn sprite_sheets(&self) -> Vec<&SpriteSheetName> {
+        vec![&self.sprite_sheet]
+    }
+    fn name(&self) -> &EntityName {
+        &self.name
+    }
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox {
+            anchor: Vector::new(
+                self.position.x + self.size.width / 2.0,
+                self.position.y - self.size.height / 2.0,
+                self.position.z,
+            ),
+            size: self.size,
+        }
+    }
+    fn entity_type(&self) -> Type {
+        Type::Background
+    }
+}
+
+impl Tile {
+    fn edges(&self) -> impl Iterator<Item = (Direction, &Box<dyn super::Edge>)> {
+        [
+            (Direction::Left, &self.left),
+            (Direction::Up, &self.up),
+            (Direction::Right, &self.right),
+            (Direction::Down, &self.down),
+        ]
+        .into_iter()
+        .filter_map(|(direction, edge)| edge.as_ref().map(|edge| (direction, edge)))
+    }
+}
+
+impl DungeonLayout {
+    fn height(&self) -> usize {
+        (self.tiles.len() + self.width as usize - 1) / self.width as usize
+    }
+
+    fn tile(&self, x: isize, y: isize) -> Option<&Tile> {
+        if x < 0 || y < 0 || x >= self.width as isize {
+            return None;
+        }
+        self.tiles
+            .get(y as usize * self.width as usize + x as usize)
+    }
+
+    ///Wall segments meeting in the top left corner of tile (x, y)
+    fn corner_mask(&self, x: usize, y: usize) -> u8 {
+        let (x, y) = (x as isize, y as isize);
+        let has = |x, y, edge: fn(&Tile) -> bool| self.tile(x, y).map_or(false, edge);
+        let mut mask = 0;
+        if has(x - 1, y - 1, |t| t.right.is_some()) || has(x, y - 1, |t| t.left.is_some()) {
+            mask |= CORNER_UP;
+        }
+        if has(x, y - 1, |t| t.down.is_some()) || has(x, y, |t| t.up.is_some()) {
+            mask |= CORNER_RIGHT;
+        }
+        if has(x - 1, y, |t| t.right.is_some()) || has(x, y, |t| t.left.is_some()) {
+            mask |= CORNER_DOWN;
+        }
+        if has(x - 1, y - 1, |t| t.down.is_some()) || has(x - 1, y, |t| t.up.is_some()) {
+            mask |= CORNER_LEFT;
+        }
+        mask
+    }
+
+    ///Tile borders with an edge that blocks light, for a layout whose top left corner is at
+    ///`position`. Borders shared by two tiles are only returned once
+    pub fn wall_segments(&self, position: &Vector<f32>, tile_size: f32) -> Vec<LineSegment> {
+        let blocks = |x, y, edge: fn(&Tile) -> &Option<Box<dyn super::Edge>>| {
+            self.tile(x, y)
+                .and_then(|tile| edge(tile).as_ref())
+                .map_or(false, |edge| edge.blocks_light())
+        };
+        let mut segments = Vec::new();
+        for i in 0..self.tiles.len() {
+            let (x, y) = (
+                (i % self.width as usize) as isize,
+                (i / self.width as usize) as isize,
+            );
+            let left = position.x + x as f32 * tile_size;
+            let top = position.y - y as f32 * tile_size;
+            let (right, bottom) = (left + tile_size, top - tile_size);
+            if blocks(x, y, |t| &t.left) {
+                segments.push(LineSegment::new([left, top], [left, bottom]));
+            }
+            if blocks(x, y, |t| &t.up) {
+                segments.push(LineSegment::new([left, top], [right, top]));
+            }
+            if blocks(x, y, |t| &t.right) && !blocks(x + 1, y, |t| &t.left) {
+                segments.push(LineSegment::new([right, top], [right, bottom]));
+            }
+            if blocks(x, y, |t| &t.down) && !blocks(x, y + 1, |t| &t.up) {
+                segments.push(LineSegment::new([left, bottom], [right, bottom]));
+            }
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dungeon::DungeonLayoutBuilder;
+    use ferride_core::game_engine::SpriteSheetDimensions;
+
+    const LAYOUT: &str = "\
+|--|-|
+|  |T|
+|  |T|
+--|  |
+  | T|
+  |--|";
+
+    fn tileset() -> Tileset {
+        Tileset::new("Tiles".into(), SpritePosition::new(0, 0))
+            .with_edge(Direction::Left, '|', SpritePosition::new(1, 0))
+            .with_edge(Direction::Right, '|', SpritePosition::new(1, 0))
+            .with_edge(Direction::Up, '-', SpritePosition::new(2, 0))
+            .with_edge(Direction::Down, '-', SpritePosition::new(2, 0))
+            .with_corner(CORNER_RIGHT | CORNER_DOWN, SpritePosition::new(3, 0))
+            .with_corner(CORNER_LEFT | CORNER_DOWN, SpritePosition::new(3, 1))
+            .with_occupier('T', SpritePosition::new(0, 1))
+    }
+
+    fn count(tilemap: &TilemapEntity, layer: TilemapLayer) -> usize {
+        tilemap.sprites.iter().filter(|s| s.layer == layer).count()
+    }
+
+    #[test]
+    fn autotiles_layers_and_culls() {
+        let layout = DungeonLayoutBuilder::default().build(LAYOUT).unwrap();
+        assert_eq!(layout.corner_mask(0, 0), CORNER_RIGHT | CORNER_DOWN);
+        assert_eq!(
+            layout.corner_mask(1, 0),
+            CORNER_RIGHT | CORNER_DOWN | CORNER_LEFT
+        );
+        assert_eq!(layout.corner_mask(2, 0), CORNER_LEFT | CORNER_DOWN);
+        assert_eq!(layout.corner_mask(0, 2), 0);
+
+        let mut tilemap = TilemapEntity::new(
+            "Dungeon".into(),
+            &layout,
+            &tileset(),
+            Vector::new(0.0, 0.0, 0.0),
+            32.0,
+            4.0,
+        );
+        assert_eq!(count(&tilemap, TilemapLayer::Floor), 4);
+        // 10 edges, the trap edge has no sprite, plus the two outer corners with a sprite
+        assert_eq!(count(&tilemap, TilemapLayer::Edges), 10 - 1 + 2);
+        assert_eq!(count(&tilemap, TilemapLayer::Occupiers), 2);
+        // Every edge but the trap, the walls do not share a border
+        let walls = tilemap.light_occluders();
+        assert_eq!(walls.len(), 9);
+        assert!(walls.contains(&LineSegment::new([32.0, 0.0], [32.0, -32.0])));
+        assert!(walls.contains(&LineSegment::new([32.0, -64.0], [64.0, -64.0])));
+
+        let sprite_sheet = SpriteSheet::new(1, &SpriteSheetDimensions::new(4, 4));
+        let render = |tilemap: &mut TilemapEntity| {
+            let mut vertices = VertexBuffer::new();
+            let mut indices = IndexBuffer::new();
+            tilemap.render(&mut vertices, &mut indices, vec![Some(&sprite_sheet)]);
+            vertices.len()
+        };
+        assert_eq!(render(&mut tilemap), 4 + 11 + 2);
+
+        let mut floor = tilemap.with_layers(vec![TilemapLayer::Floor]);
+        assert_eq!(render(&mut floor), 4);
+        assert!(floor.light_occluders().is_empty());
+        floor.camera_view(&BoundingBox {
+            anchor: Vector::new(16.0, -16.0, 0.0),
+            size: PhysicalSize::new(20.0, 20.0),
+        });
+        assert!(floor.is_dirty());
+        assert_eq!(render(&mut floor), 1);
+    }
+}
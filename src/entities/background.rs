@@ -46,14 +46,13 @@ impl Entity<Type, Event> for Background {
     fn render(
         &mut self,
         vertices: &mut VertexBuffer,
-        indices: &mut IndexBuffer,
+        _indices: &mut IndexBuffer,
         sprite_sheet: Vec<Option<&SpriteSheet>>,
     ) {
         if let Some(sprite_sheet) = sprite_sheet[0] {
             render_sprite(
                 &self.bounding_box(),
                 vertices,
-                indices,
                 sprite_sheet,
                 &SpritePosition::new(0, 0),
             );
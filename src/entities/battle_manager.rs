@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 
+use ferride_core::app::DEFAULT_CURSOR;
 use ferride_core::game_engine::{Entity, EntityName};
 use threed::Vector;
 use ferride_core::reexports::winit::PhysicalSize;
@@ -9,7 +10,7 @@ use crate::{
     event::{BattleEvent, EntityEvent, Event},
     game_state::BattleState,
     ui::{Alignment, FlexCharacterGuiLine, FlexCharacterGuiLineManager, FlexDirection, FlexOrigin},
-    Type, RESOLUTION,
+    Type, BUSY_CURSOR, MAIN_WINDOW, RESOLUTION, TARGET_CURSOR,
 };
 
 pub const BATTLE_MANAGER: &str = "Battle Manager";
@@ -122,6 +123,12 @@ impl Entity<Type, Event> for BattleManager {
                         self.gui.children[line_index].focus_child(character_index);
                     }
                 }
+                let cursor = if valid_targets.is_empty() {
+                    DEFAULT_CURSOR
+                } else {
+                    TARGET_CURSOR
+                };
+                return vec![Event::RequestSetCursor(MAIN_WINDOW.into(), cursor.into())];
             }
             EntityEvent::AnimateAction(characters) => {
                 for character in characters {
@@ -144,7 +151,7 @@ impl Entity<Type, Event> for BattleManager {
                 return if self.pending_attack_animations.is_empty() {
                     vec![Event::BattleEvent(BattleEvent::ActionConsequences)]
                 } else {
-                    vec![]
+                    vec![Event::RequestSetCursor(MAIN_WINDOW.into(), BUSY_CURSOR.into())]
                 };
             }
             EntityEvent::CharacterDeath(character) => {
@@ -192,6 +199,7 @@ impl Entity<Type, Event> for BattleManager {
             }
         }
         if self.pending_attack_animations.is_empty() {
+            events.push(Event::RequestSetCursor(MAIN_WINDOW.into(), DEFAULT_CURSOR.into()));
             events.push(Event::BattleEvent(BattleEvent::ActionConsequences));
         }
         events
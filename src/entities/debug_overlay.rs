@@ -0,0 +1,203 @@
+use ferride_core::{
+    app::{IndexBuffer, VertexBuffer},
+    fonts::font_sprite_sheets,
+    game_engine::{BoundingBox, DebugInfo, Entity, EntityName, SpriteSheet, SpriteSheetName},
+    graphics::Color,
+    reexports::winit::PhysicalSize,
+};
+use log::warn;
+use std::{fmt::Debug, sync::OnceLock, time::Duration};
+use threed::Vector;
+
+use crate::{
+    ui::{FontSize, Overflow, Text, TextStyle, VerticalAlign},
+    vertex::render_ui_box_border,
+    Event, Type, FONT,
+};
+
+const ENTITY_COLOR: Color = Color::new_rgba(255, 40, 40, 255);
+const CAMERA_TARGET_COLOR: Color = Color::new_rgba(40, 255, 40, 255);
+const CAMERA_BOUND_COLOR: Color = Color::new_rgba(40, 120, 255, 255);
+const TEXT_COLOR: Color = Color::new_rgba(255, 255, 255, 255);
+const SLOW_FRAME_COLOR: Color = Color::new_rgba(255, 64, 64, 255);
+///Frames taking longer miss 60 FPS
+const FRAME_BUDGET_MS: f32 = 1000.0 / 60.0;
+const BORDER_THICKNESS: f32 = 2.0;
+const FONT_SIZE: u8 = 16;
+const LABEL_WIDTH: u16 = 600;
+
+///Outlines every entity and camera box handed over by the game and shows the frame rate and
+///the profiler metrics of the last frame
+pub struct DebugOverlay {
+    name: EntityName,
+    ///Pages of `FONT`, looked up once the Game adds the overlay and has registered the fonts
+    fonts: OnceLock<Vec<SpriteSheetName>>,
+    view_size: PhysicalSize<f32>,
+    info: Option<DebugInfo>,
+}
+impl DebugOverlay {
+    pub fn new(name: EntityName, view_size: PhysicalSize<f32>) -> Self {
+        Self {
+            name,
+            fonts: OnceLock::new(),
+            view_size,
+            info: None,
+        }
+    }
+
+    ///`text` is shown with its markup if `markup` is set
+    fn render_label(
+        &self,
+        text: String,
+        markup: bool,
+        top_left: Vector<f32>,
+        vertices: &mut VertexBuffer,
+        indices: &mut IndexBuffer,
+        fonts: &[Option<&SpriteSheet>],
+    ) {
+        let position =
+            top_left + Vector::new(LABEL_WIDTH as f32 / 2.0, -(FONT_SIZE as f32) / 2.0, 0.0);
+        let mut label = Text::new(
+            String::new(),
+            TEXT_COLOR,
+            self.name.clone(),
+            PhysicalSize::new(LABEL_WIDTH, FONT_SIZE as u16),
+            position,
+            FontSize::new(FONT_SIZE),
+            false,
+        )
+        .with_style(TextStyle {
+            vertical_align: VerticalAlign::Center,
+            overflow: Overflow::Ellipsis,
+            ..TextStyle::default()
+        });
+        if !markup {
+            label.set_text(text);
+        } else if let Err(err) = label.set_markup(text.clone()) {
+            warn!("Showing debug label {:?} as written: {}", text, err);
+            label.set_text(text);
+        }
+        label.render(vertices, indices, fonts.to_vec());
+    }
+}
+fn millis(duration: &Duration) -> f32 {
+    duration.as_secs_f32() * 1000.0
+}
+
+impl Debug for DebugOverlay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebugOverlay")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+impl Entity<Type, Event> for DebugOverlay {
+    fn debug_info(&mut self, info: &DebugInfo) {
+        self.info = Some(info.clone());
+    }
+    fn render(
+        &mut self,
+        vertices: &mut VertexBuffer,
+        indices: &mut IndexBuffer,
+        sprite_sheet: Vec<Option<&SpriteSheet>>,
+    ) {
+        let Some(info) = &self.info else {
+            return;
+        };
+        let fonts = sprite_sheet.as_slice();
+        for entity in info.entities.iter() {
+            let bounding_box = &entity.bounding_box;
+            render_ui_box_border(bounding_box, vertices, BORDER_THICKNESS, &ENTITY_COLOR);
+            let top_left = &bounding_box.anchor
+                + Vector::new(
+                    -bounding_box.size.width / 2.0,
+                    bounding_box.size.height / 2.0 + FONT_SIZE as f32 + BORDER_THICKNESS,
+                    0.0,
+                );
+            self.render_label(
+                format!("{} z: {:.1}", entity.name.as_str(), entity.z),
+                false,
+                top_left,
+                vertices,
+                indices,
+                fonts,
+            );
+        }
+        for camera in info.cameras.iter() {
+            if let Some(target) = &camera.target {
+                render_ui_box_border(target, vertices, BORDER_THICKNESS, &CAMERA_TARGET_COLOR);
+            }
+            if let Some(bound) = &camera.bound {
+                render_ui_box_border(bound, vertices, BORDER_THICKNESS, &CAMERA_BOUND_COLOR);
+            }
+        }
+        let metrics = &info.metrics;
+        let frame_time = millis(&info.frame_time);
+        let frame_color = if frame_time > FRAME_BUDGET_MS {
+            SLOW_FRAME_COLOR
+        } else {
+            TEXT_COLOR
+        };
+        let frame = format!(
+            "FPS: {:.0} frame: [color={}]{:.2}ms[/color]",
+            info.fps,
+            frame_color.to_hex(),
+            frame_time
+        );
+        let mut lines = vec![
+            format!(
+                "events: {} latency: {:.2}ms draw: {:.2}ms",
+                metrics.events,
+                millis(&metrics.event_latency),
+                millis(&metrics.draw)
+            ),
+        ];
+        lines.extend(metrics.scenes.iter().map(|scene| {
+            format!(
+                "{} upd: {:.2} rnd: {:.2} upl: {:.2} vtx: {}",
+                scene.name,
+                millis(&scene.update),
+                millis(&scene.render),
+                millis(&scene.upload),
+                scene.vertices
+            )
+        }));
+        lines.extend(metrics.entity_types.iter().map(|entity_type| {
+            format!(
+                "{} x{} upd: {:.2} rnd: {:.2}",
+                entity_type.entity_type,
+                entity_type.count,
+                millis(&entity_type.update),
+                millis(&entity_type.render)
+            )
+        }));
+        let mut top_left = Vector::new(
+            -self.view_size.width / 2.0 + 8.0,
+            self.view_size.height / 2.0 - 8.0,
+            0.0,
+        );
+        self.render_label(frame, true, top_left.clone(), vertices, indices, fonts);
+        for line in lines {
+            top_left.y -= FONT_SIZE as f32;
+            self.render_label(line, false, top_left.clone(), vertices, indices, fonts);
+        }
+    }
+    fn sprite_sheets(&self) -> Vec<&SpriteSheetName> {
+        self.fonts
+            .get_or_init(|| font_sprite_sheets(&FONT.into()))
+            .iter()
+            .collect()
+    }
+    fn name(&self) -> &EntityName {
+        &self.name
+    }
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox {
+            anchor: Vector::new(0.0, 0.0, 0.0),
+            size: self.view_size,
+        }
+    }
+    fn entity_type(&self) -> Type {
+        Type::Menu
+    }
+}
@@ -1,6 +1,9 @@
 mod background;
 pub use background::Background;
 
+mod debug_overlay;
+pub use debug_overlay::DebugOverlay;
+
 mod enemy;
 pub use enemy::Enemy;
 
@@ -11,4 +14,4 @@ mod player;
 pub use player::Player;
 
 mod transition;
-pub use transition::Transition;
+pub use transition::{Transition, TransitionTypes};
@@ -3,8 +3,8 @@ use std::{fmt::Debug, time::Duration};
 use ferride_core::{
     app::{IndexBuffer, VertexBuffer},
     game_engine::{
-        BoundingBox, Direction, Entity, EntityName, SceneName, SpritePosition, SpriteSheet,
-        SpriteSheetName, VelocityController,
+        BoundingBox, Direction, Entity, EntityName, Light, SceneName, SpritePosition,
+        SpriteSheet, SpriteSheetName, VelocityController,
     },
 reexports::winit::{
     PhysicalSize,
@@ -23,6 +23,8 @@ pub struct Player {
     pub velocity: VelocityController,
     pub animation: Animation<SpritePosition>,
     pub sprite_sheet: SpriteSheetName,
+    ///Carried along, its position is ignored
+    pub lantern: Option<Light>,
 }
 impl Debug for Player {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -86,6 +88,16 @@ impl Entity<Type, Event> for Player {
         vec![&self.sprite_sheet]
     }
 
+    fn lights(&self) -> Vec<Light> {
+        self.lantern
+            .iter()
+            .map(|lantern| Light {
+                position: self.position.clone(),
+                ..lantern.clone()
+            })
+            .collect()
+    }
+
     fn z(&self) -> f32 {
         self.position.z
     }
@@ -93,14 +105,13 @@ impl Entity<Type, Event> for Player {
     fn render(
         &mut self,
         vertices: &mut VertexBuffer,
-        indices: &mut IndexBuffer,
+        _indices: &mut IndexBuffer,
         sprite_sheet: Vec<Option<&SpriteSheet>>,
     ) {
         if let Some(sprite_sheet) = sprite_sheet[0] {
             render_sprite(
                 &self.bounding_box(),
                 vertices,
-                indices,
                 sprite_sheet,
                 self.animation.keyframe(),
             );
@@ -99,12 +99,12 @@ impl Entity<Type, Event> for Transition {
         _sprite_sheet: Vec<Option<&SpriteSheet>>,
     ) {
         let (new_vertices, new_indices) = self.animation.keyframe();
-        let start_index = vertices.len() as u16;
+        let start_index = vertices.len();
         vertices.extend_from_slice(new_vertices);
         indices.extend_from_slice(
             new_indices
                 .iter()
-                .map(|i| i + start_index)
+                .map(|i| *i as u32 + start_index)
                 .collect::<Vec<_>>()
                 .as_slice(),
         );
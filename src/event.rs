@@ -1,5 +1,7 @@
 use ferride_core::{
-    game_engine::{Entity, EntityName, ExternalEvent, Scene, SceneName},
+    app::CursorName,
+    audio::AudioCommand,
+    game_engine::{Entity, EntityName, ExternalEvent, Scene, SceneName, WindowName},
     graphics::{UniformBufferName, Visibility},
     reexports::winit::keyboard::KeyCode,
 };
@@ -32,6 +34,10 @@ pub enum Event {
     RequestDeleteScene(SceneName),
     RequestDeleteEntity(EntityName, SceneName),
     RequestSetVisibilityScene(SceneName, Visibility),
+    RequestSetCursor(WindowName, CursorName),
+    Audio(AudioCommand),
+    ///Language code like `de`
+    RequestSetLanguage(String),
     ButtonPressed(EntityName, KeyCode),
     BattleEvent(BattleEvent),
     RequestAddEntities(Vec<Box<dyn Entity<Type, Self>>>, SceneName),
@@ -71,6 +77,12 @@ impl ExternalEvent for Event {
             _ => None,
         }
     }
+    fn is_request_set_cursor<'a>(&'a self) -> Option<(&'a WindowName, &'a CursorName)> {
+        match self {
+            Event::RequestSetCursor(window, cursor) => Some((window, cursor)),
+            _ => None,
+        }
+    }
     fn is_request_new_scenes<'a>(&'a self) -> bool {
         match self {
             Event::RequestNewScenes(_) => true,
@@ -103,6 +115,18 @@ impl ExternalEvent for Event {
             _ => None,
         }
     }
+    fn is_audio_command<'a>(&'a self) -> Option<&'a AudioCommand> {
+        match self {
+            Event::Audio(command) => Some(command),
+            _ => None,
+        }
+    }
+    fn is_request_set_language<'a>(&'a self) -> Option<&'a str> {
+        match self {
+            Event::RequestSetLanguage(language) => Some(language),
+            _ => None,
+        }
+    }
     fn is_delete_entity<'a>(&'a self) -> Option<(&'a EntityName, &'a SceneName)> {
         match self {
             Event::RequestDeleteEntity(entity, scene) => Some((entity, scene)),
@@ -3,19 +3,29 @@ use crate::{
     entities::BATTLE_MANAGER,
     event::{BattleEvent, EntityEvent},
     game_state::UIState,
-    ui::{Alignment, Button, ButtonStyle, FlexButtonLine, FlexDirection, FlexOrigin, FontSize},
+    ui::{
+        Alignment, Button, ButtonStyle, FlexButtonLine, FlexDirection, FlexOrigin, FontSize,
+        TextContent,
+    },
     BATTLE_ACTION_SELECTION_OVERLAY_SCENE, BATTLE_DETAIL_OVERLAY, BATTLE_DETAIL_OVERLAY_SCENE,
-    BATTLE_SCENE, END_GAME_BUTTON, RESOLUTION, START_GAME_BUTTON,
+    BATTLE_DRUMS, BATTLE_SCENE, BATTLE_TRANSITION_SCENE, END_GAME_BUTTON, LANGUAGE_BUTTON,
+    MAIN_MENU_SCENE, RESOLUTION, START_GAME_BUTTON, TRANSITION_NAME,
 };
 use ferride_core::{
+    audio::{AudioCommand, Bus},
     game_engine::{EntityName, Scene, SceneName, State},
     graphics::Visibility,
+    localization::localization,
     reexports::winit::{keyboard::KeyCode, PhysicalSize},
 };
 use log::debug;
+use std::time::Duration;
 use threed::Vector;
 
-use crate::{battle_action::BattleAction, event::Event, game_state::GameState, EnemyType};
+use crate::{
+    battle_action::BattleAction, battle_transition_scene, event::Event, game_state::GameState,
+    EnemyType,
+};
 
 struct KeyBindings {
     accept: Vec<KeyCode>,
@@ -52,19 +62,26 @@ impl GameLogic {
                 if matches!(key_code, KeyCode::Enter | KeyCode::Space) {
                     match entity.as_str() {
                         END_GAME_BUTTON => vec![Event::EndGame],
+                        LANGUAGE_BUTTON => {
+                            let localization =
+                                localization().read().expect("Localization lock poisoned");
+                            let languages = localization.languages();
+                            if languages.is_empty() {
+                                return vec![];
+                            }
+                            let current = languages
+                                .iter()
+                                .position(|language| *language == localization.language())
+                                .unwrap_or(0);
+                            let next = languages[(current + 1) % languages.len()];
+                            vec![Event::RequestSetLanguage(next.to_string())]
+                        }
                         START_GAME_BUTTON => {
-                            todo!("Start game");
-                            // vec![
-                            //     Event::RequestDeleteScene(MAIN_MENU_SCENE.into()),
-                            //     Event::RequestNewScenes(vec![Scene {
-                            //         name: BATTLE_SCENE.into(),
-                            //         render_scene: BATTLE_SCENE.into(),
-                            //         target_window: MAIN_WINDOW.into(),
-                            //         z_index: 0,
-                            //         shader_descriptor: SHADER_UI_TEXTURE,
-                            //         entities: vec![],
-                            //     }]),
-                            // ]
+                            self.game_state = GameState::Exploration;
+                            vec![
+                                Event::RequestDeleteScene(MAIN_MENU_SCENE.into()),
+                                Event::RequestNewScenes(self.game_state.get_start_scenes()),
+                            ]
                         }
                         _ => vec![],
                     }
@@ -76,6 +93,33 @@ impl GameLogic {
         }
     }
 
+    fn exploration_event(&mut self, event: Event) -> Vec<Event> {
+        match event {
+            Event::InitiateBattle(enemy, entity, scene) => {
+                if self.pending_battle.is_some() {
+                    return vec![];
+                }
+                self.pending_battle = Some((enemy, entity, scene.clone()));
+                vec![
+                    Event::RequestNewScenes(vec![battle_transition_scene()]),
+                    Event::RequestSuspendScene(scene),
+                ]
+            }
+            Event::AnimationEnded(animation) if animation.as_str() == TRANSITION_NAME => {
+                let Some((_, entity, scene)) = self.pending_battle.take() else {
+                    return vec![];
+                };
+                self.game_state = GameState::battle();
+                vec![
+                    Event::RequestDeleteEntity(entity, scene),
+                    Event::RequestDeleteScene(BATTLE_TRANSITION_SCENE.into()),
+                    Event::RequestNewScenes(self.game_state.get_start_scenes()),
+                ]
+            }
+            _ => vec![],
+        }
+    }
+
     fn battle_event(&mut self, event: Event) -> Vec<Event> {
         let (battle_state, ui_state) = match &mut self.game_state {
             GameState::Battle(battle_state, ui_state) => (battle_state, ui_state),
@@ -98,18 +142,21 @@ impl GameLogic {
                         battle_state.current_time,
                     );
                 }
-                return vec![Event::EntityEvent(
-                    BATTLE_MANAGER.into(),
-                    EntityEvent::BattleHighlightValidSkillTargets(
-                        battle_state
-                            .characters
-                            .iter()
-                            .enumerate()
-                            .filter(|(i, _)| !battle_state.actions.contains_character(*i))
-                            .map(|(_, c)| c.character.name().into())
-                            .collect(),
+                return vec![
+                    Event::Audio(AudioCommand::SetBusVolume(Bus::Music, 0.1)),
+                    Event::EntityEvent(
+                        BATTLE_MANAGER.into(),
+                        EntityEvent::BattleHighlightValidSkillTargets(
+                            battle_state
+                                .characters
+                                .iter()
+                                .enumerate()
+                                .filter(|(i, _)| !battle_state.actions.contains_character(*i))
+                                .map(|(_, c)| c.character.name().into())
+                                .collect(),
+                        ),
                     ),
-                )];
+                ];
             }
             Event::BattleEvent(BattleEvent::NextAction) => {
                 let action = battle_state.actions.pop();
@@ -161,6 +208,16 @@ impl GameLogic {
                 {
                     todo!("Player Loses");
                 }
+                let low_on_health = battle_state.characters.iter().any(|c| {
+                    c.character.alignment() == &CharacterAlignment::Friendly
+                        && c.character.is_low_on_health()
+                });
+                let fade = Duration::from_secs(1);
+                events.push(Event::Audio(if low_on_health {
+                    AudioCommand::AddStem(BATTLE_DRUMS.into(), fade)
+                } else {
+                    AudioCommand::RemoveStem(BATTLE_DRUMS.into(), fade)
+                }));
                 let free_characters: Vec<_> = battle_state
                     .characters
                     .iter()
@@ -235,14 +292,18 @@ impl GameLogic {
                                 let name = s.name();
                                 let name = name.as_str();
                                 Box::new(Button::new(
-                                    name.to_string(),
+                                    TextContent::localized(&format!(
+                                        "skill.{}",
+                                        name.to_lowercase()
+                                    )),
                                     name.into(),
                                     PhysicalSize::new(400, 100),
                                     Vector::<f32>::y_axis() * 100.0 * i as f32,
                                     FontSize::new(32),
                                     true,
                                     ButtonStyle::default(),
-                                ))
+                                )
+                                .with_markup())
                             })
                             .collect();
                         *ui_state = UIState::ActionSelection(character_index);
@@ -563,55 +624,9 @@ impl State<Event> for GameLogic {
     fn handle_event(&mut self, event: Event) -> Vec<Event> {
         match self.game_state {
             GameState::MainMenu => self.main_menu_event(event),
+            GameState::Exploration => self.exploration_event(event),
             GameState::Battle(_, _) => self.battle_event(event),
         }
-        // match event {
-        //     Event::InitiateBattle(enemy, entity, scene) => {
-        //         if self.pending_battle.is_none() {
-        //             let shader_descriptor = ShaderDescriptor {
-        //                 file: "res/shader/transition.wgsl",
-        //                 vertex_shader: "vs_main",
-        //                 fragment_shader: "fs_main",
-        //                 uniforms: vec![UTIME],
-        //             };
-        //             self.pending_battle = Some((enemy, entity, scene.clone()));
-        //             return vec![
-        //                 Event::RequestNewScenes(vec![Scene {
-        //                     name: BATTLE_TRANSITION_SCENE.into(),
-        //                     render_scene: BATTLE_TRANSITION_SCENE.into(),
-        //                     target_window: MAIN_WINDOW.into(),
-        //                     z_index: 1,
-        //                     entities: vec![Box::new(Transition::new(
-        //                         TransitionTypes::BattleTransition,
-        //                         TRANSITION_NAME,
-        //                         Duration::from_millis(750),
-        //                     ))],
-        //                     shader_descriptor,
-        //                 }]),
-        //                 Event::RequestSuspendScene(scene),
-        //             ];
-        //         }
-        //     }
-        //     Event::AnimationEnded(animation_entity) => {
-        //         if animation_entity == TRANSITION_NAME.into() {
-        //             let response = if let Some((enemy, entity, scene)) = &self.pending_battle {
-        //                 println!("Starting Battle!");
-        //                 vec![
-        //                     Event::RequestDeleteEntity(entity.clone(), scene.clone()),
-        //                     Event::RequestDeleteScene(BATTLE_TRANSITION_SCENE.into()),
-        //                     Event::RequestActivateSuspendedScene(scene.clone()),
-        //                 ]
-        //             } else {
-        //                 vec![]
-        //             };
-        //             if response.len() > 0 {
-        //                 self.pending_battle = None;
-        //             }
-        //             return response;
-        //         }
-        //     }
-        //     _ => {}
-        // }
         // vec![]
     }
 }
@@ -1,22 +1,32 @@
+use crate::animation::Animation;
 use crate::battle_action::BattleActionManager;
 use crate::character::characters::BiaKarui;
 use crate::character::skills::AttackSkill;
 use crate::character::CharacterAlignment;
 use crate::character::SimpleKI;
-use ferride_core::game_engine::{Scene, SpritePosition};
+use ferride_core::game_engine::{
+    EmissionMode, EmissionShape, Light, ParticleEmitter, Scene, SpritePosition, VelocityController,
+};
+use std::time::Duration;
 use threed::Vector;
+use ferride_core::graphics::{Color, SpriteInstance};
 use ferride_core::reexports::winit::PhysicalSize;
 
+use crate::dungeon::{
+    Direction, DungeonLayoutBuilder, TilemapEntity, Tileset, CORNER_DOWN, CORNER_LEFT,
+    CORNER_RIGHT, CORNER_UP,
+};
 use crate::{
-    color::Color,
-    entities::BattleManager,
+    entities::{Background, BattleManager, Enemy, Player},
     ui::{
         Alignment, Button, ButtonStyle, FlexBox, FlexButtonLine, FlexDirection, FlexOrigin,
-        FontSize, Image,
+        FontSize, Image, TextAlign, TextContent, TextStyle,
     },
-    Character, Event, SkilledCharacter, BATTLE_ACTION_SELECTION_OVERLAY_SCENE,
-    BATTLE_DETAIL_OVERLAY_SCENE, BATTLE_SCENE, END_GAME_BUTTON, MAIN_MENU_SCENE, MAIN_WINDOW,
-    RESOLUTION, SHADER_UI_TEXTURE, START_GAME_BUTTON,
+    Character, EnemyType, Event, SkilledCharacter, Type, BACKGROUND,
+    BATTLE_ACTION_SELECTION_OVERLAY_SCENE, BATTLE_DETAIL_OVERLAY_SCENE, BATTLE_SCENE, DUNGEON,
+    END_GAME_BUTTON, FROG, LANGUAGE_BUTTON, MAIN_MENU_SCENE, MAIN_SCENE, MAIN_WINDOW, MENU_FONT,
+    PLAYER_SPRITE_SHEET, PROTAGONIST, RESOLUTION, SHADER_CAMERA_SPRITE_BATCH, SHADER_SPRITE_BATCH,
+    START_GAME_BUTTON,
 };
 
 use crate::character::CharacterBuilder;
@@ -27,12 +37,16 @@ pub use ui_state::UIState;
 mod battle_state;
 pub use battle_state::BattleState;
 
+#[derive(Default)]
 pub enum GameState {
+    #[default]
     MainMenu,
+    ///Walking through the lit main scene until an enemy is met
+    Exploration,
     Battle(BattleState, UIState),
 }
-impl Default for GameState {
-    fn default() -> Self {
+impl GameState {
+    pub fn battle() -> Self {
         let player = BiaKarui::default().build();
         let enemy = SkilledCharacter::new(
             Character::new("Enemy", CharacterAlignment::Enemy, 100, 5, 10, 5, 10),
@@ -54,12 +68,86 @@ impl Default for GameState {
         Self::Battle(battle_state, UIState::CharacterSelection)
     }
 }
+///Labels of the main menu, centered if a translation needs more than one line
+fn menu_label_style() -> TextStyle {
+    TextStyle {
+        align: TextAlign::Center,
+        ..TextStyle::default()
+    }
+}
+///Four keyframes of the first row of a sprite sheet, looping
+fn walk_cycle() -> Animation<SpritePosition> {
+    Animation::new(
+        (0..4)
+            .map(|x| (Duration::from_millis(240), SpritePosition::new(x, 0)))
+            .collect(),
+        false,
+    )
+}
+const MAIN_DUNGEON: &str = "\
+|-------------------|
+|                   |
+|                   |
+|  |-|              |
+|  | |              |
+|  |-|              |
+|              |-|  |
+|              | |  |
+|              |-|  |
+|                   |
+|                   |
+|-------------------|";
+///Walls around the start of the exploration, they cast the shadows of the lantern
+fn main_dungeon() -> TilemapEntity {
+    let layout = DungeonLayoutBuilder::default()
+        .build(MAIN_DUNGEON)
+        .expect("MAIN_DUNGEON is malformed");
+    let tileset = Tileset::new(DUNGEON.into(), SpritePosition::new(0, 0))
+        .with_edge(Direction::Left, '|', SpritePosition::new(1, 0))
+        .with_edge(Direction::Right, '|', SpritePosition::new(1, 0))
+        .with_edge(Direction::Up, '-', SpritePosition::new(2, 0))
+        .with_edge(Direction::Down, '-', SpritePosition::new(2, 0))
+        .with_corner(CORNER_RIGHT | CORNER_DOWN, SpritePosition::new(3, 0))
+        .with_corner(CORNER_LEFT | CORNER_DOWN, SpritePosition::new(3, 0))
+        .with_corner(CORNER_RIGHT | CORNER_UP, SpritePosition::new(3, 0))
+        .with_corner(CORNER_LEFT | CORNER_UP, SpritePosition::new(3, 0));
+    TilemapEntity::new(
+        DUNGEON.into(),
+        &layout,
+        &tileset,
+        Vector::new(-560.0, 320.0, 0.0),
+        160.0,
+        16.0,
+    )
+}
+///Ash slowly drifting down over the battle field
+fn battle_ash() -> ParticleEmitter<Type, SpriteInstance> {
+    let top = RESOLUTION.height as f32 / 2.0 + 10.0;
+    ParticleEmitter::new(
+        "BattleAsh".into(),
+        Type::Background,
+        Vector::new(0.0, top, 1.0),
+        0x0A5B,
+    )
+    .with_shape(EmissionShape::Box(PhysicalSize::new(RESOLUTION.width as f32, 10.0)))
+    .with_mode(EmissionMode::Rate(25.0))
+    .with_lifetime(Duration::from_secs(8), Duration::from_secs(12))
+    .with_velocity(Vector::new(0.0, -100.0, 0.0), Vector::new(25.0, 30.0, 0.0))
+    .with_gravity(Vector::new(6.0, 0.0, 0.0))
+    .with_colors(vec![
+        [110, 100, 100, 0],
+        [140, 130, 130, 180],
+        [90, 80, 80, 0],
+    ])
+    .with_sizes(vec![6.0, 3.0])
+}
+
 impl GameState {
     pub fn get_start_scenes(&self) -> Vec<Scene<Event>> {
         match self {
             GameState::MainMenu => vec![Scene {
                 z_index: 1,
-                shader_descriptor: SHADER_UI_TEXTURE,
+                shader_descriptor: SHADER_SPRITE_BATCH,
                 name: MAIN_MENU_SCENE.into(),
                 render_scene: MAIN_MENU_SCENE.into(),
                 target_window: MAIN_WINDOW.into(),
@@ -94,34 +182,86 @@ impl GameState {
                             true,
                             vec![
                                 Box::new(Button::new(
-                                    String::from("New Game"),
+                                    TextContent::localized("menu.new_game"),
                                     START_GAME_BUTTON.into(),
                                     PhysicalSize::new(800, 600),
                                     Vector::scalar(0.0),
                                     FontSize::new(40),
                                     true,
                                     ButtonStyle::default(),
-                                )),
+                                )
+                                .with_font(MENU_FONT.into())
+                                .with_text_style(menu_label_style())),
                                 Box::new(Button::new(
-                                    String::from("End Game"),
+                                    TextContent::localized("menu.language"),
+                                    LANGUAGE_BUTTON.into(),
+                                    PhysicalSize::new(800, 600),
+                                    Vector::scalar(0.0),
+                                    FontSize::new(40),
+                                    true,
+                                    ButtonStyle::default(),
+                                )
+                                .with_font(MENU_FONT.into())
+                                .with_text_style(menu_label_style())),
+                                Box::new(Button::new(
+                                    TextContent::localized("menu.end_game"),
                                     END_GAME_BUTTON.into(),
                                     PhysicalSize::new(800, 600),
                                     Vector::scalar(0.0),
                                     FontSize::new(40),
                                     true,
                                     ButtonStyle::default(),
-                                )),
+                                )
+                                .with_font(MENU_FONT.into())
+                                .with_text_style(menu_label_style())),
                             ],
                         )),
                     ],
-                ))],
+                )
+                .fill_safe_area())],
+            }],
+            GameState::Exploration => vec![Scene {
+                z_index: 0,
+                shader_descriptor: SHADER_CAMERA_SPRITE_BATCH,
+                name: MAIN_SCENE.into(),
+                render_scene: MAIN_SCENE.into(),
+                target_window: MAIN_WINDOW.into(),
+                entities: vec![
+                    Box::new(Player {
+                        name: PROTAGONIST.into(),
+                        size: PhysicalSize::new(64, 128),
+                        position: Vector::new(0.0, 0.0, 0.0),
+                        velocity: VelocityController::new(3.0),
+                        animation: walk_cycle(),
+                        sprite_sheet: PLAYER_SPRITE_SHEET.into(),
+                        lantern: Some(
+                            Light::point(Vector::scalar(0.0), 420.0, [1.0, 0.75, 0.45])
+                                .with_intensity(1.4)
+                                .with_flicker(0.15, 6.0),
+                        ),
+                    }),
+                    Box::new(Background {
+                        name: BACKGROUND.into(),
+                        size: PhysicalSize::new(2560, 1440),
+                        sprite_sheet: BACKGROUND.into(),
+                    }),
+                    Box::new(main_dungeon()),
+                    Box::new(Enemy {
+                        name: FROG.into(),
+                        size: PhysicalSize::new(64, 64),
+                        position: Vector::new(400.0, 150.0, 0.0),
+                        animation: walk_cycle(),
+                        enemy_type: EnemyType::Frog,
+                        sprite_sheet: FROG.into(),
+                    }),
+                ],
             }],
             GameState::Battle(battle_state, UIState::CharacterSelection) => {
                 let characters = BattleManager::new(battle_state);
                 vec![
                     Scene {
                         name: BATTLE_DETAIL_OVERLAY_SCENE.into(),
-                        shader_descriptor: SHADER_UI_TEXTURE,
+                        shader_descriptor: SHADER_SPRITE_BATCH,
                         render_scene: BATTLE_DETAIL_OVERLAY_SCENE.into(),
                         target_window: MAIN_WINDOW.into(),
                         entities: vec![],
@@ -132,12 +272,12 @@ impl GameState {
                         render_scene: BATTLE_SCENE.into(),
                         target_window: MAIN_WINDOW.into(),
                         z_index: 0,
-                        shader_descriptor: SHADER_UI_TEXTURE,
-                        entities: vec![Box::new(characters)],
+                        shader_descriptor: SHADER_SPRITE_BATCH,
+                        entities: vec![Box::new(characters), Box::new(battle_ash())],
                     },
                     Scene {
                         name: BATTLE_ACTION_SELECTION_OVERLAY_SCENE.into(),
-                        shader_descriptor: SHADER_UI_TEXTURE,
+                        shader_descriptor: SHADER_SPRITE_BATCH,
                         render_scene: BATTLE_ACTION_SELECTION_OVERLAY_SCENE.into(),
                         target_window: MAIN_WINDOW.into(),
                         entities: vec![],
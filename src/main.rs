@@ -1,29 +1,34 @@
 use env_logger::Env;
-use ferride_core::app::{ManagerApplication, WindowDescriptor};
-use ferride_core::graphics::{RenderSceneDescriptor, ShaderDescriptor};
-// use rodio::{Decoder, OutputStream, Sink, Source};
+use ferride_core::app::{CursorDescriptor, ManagerApplication, WindowDescriptor};
+use ferride_core::audio::Bus;
+use ferride_core::graphics::{
+    PostProcessDescriptor, RenderSceneDescriptor, RenderSceneKind, ScalingMode, ShaderDescriptor,
+    VirtualResolution, SPRITE_BATCH_SHADER,
+};
+use ferride_core::localization::Localization;
+use ferride_core::vfs::{PackedArchive, VirtualFileSystem};
 use std::fmt::Debug;
-// use std::fs::File;
-// use std::io::BufReader;
-use ferride_core::reexports::winit::PhysicalSize;
-use std::path::PathBuf;
+use ferride_core::reexports::winit::{keyboard::KeyCode, PhysicalSize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use ferride_core::graphics::{Index as I, Vertex as V};
 
 use ferride_core::game_engine::{
-    static_camera, CameraDescriptor, EntityType, Game, RessourceDescriptor, SpriteSheetDimensions,
+    CameraDescriptor, EntityType, Game, RessourceDescriptor, Scene,
+    SpriteSheetDimensions,
 };
 
 mod animation;
 
 mod entities;
+use entities::{DebugOverlay, Transition, TransitionTypes};
 
 mod vertex;
-use vertex::{SimpleVertex, UiVertex, Vertex};
+use vertex::SimpleVertex;
 
 mod ui;
 
-mod color;
 
 mod game_state;
 
@@ -43,7 +48,7 @@ mod dungeon;
 
 type Index = u16;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Type {
     Background,
     Player,
@@ -59,12 +64,23 @@ enum EnemyType {
 }
 
 const TRANSITION_NAME: &str = "BattleTransition";
-const SHADER_UI_TEXTURE: ShaderDescriptor = ShaderDescriptor {
-    file: "res/shader/ui_texture.wgsl",
+const SHADER_SPRITE_BATCH: ShaderDescriptor = ShaderDescriptor {
+    file: SPRITE_BATCH_SHADER,
     vertex_shader: "vs_main",
     fragment_shader: "fs_main",
     uniforms: &[UUI_CAMERA],
 };
+const SHADER_TRANSITION: ShaderDescriptor = ShaderDescriptor {
+    file: "res/shader/transition.wgsl",
+    vertex_shader: "vs_main",
+    fragment_shader: "fs_main",
+    uniforms: &[UTIME],
+};
+///Sprite batch of Scenes with a camera, which is bound in place of the UI camera
+const SHADER_CAMERA_SPRITE_BATCH: ShaderDescriptor = ShaderDescriptor {
+    uniforms: &[],
+    ..SHADER_SPRITE_BATCH
+};
 const BATTLE_PRINT_STATE_BUTTON: &str = "BattlePrintState";
 const BATTLE_ATTACK_BUTTON: &str = "BattleAttack";
 const BATTLE_ATTACK_TWO_BUTTON: &str = "BattleAttackTwo";
@@ -74,43 +90,73 @@ const BATTLE_ACTION_SELECTION_OVERLAY_SCENE: &str = "BattleActionSelectionOverla
 const BATTLE_ACTION_SELECTION_OVERLAY: &str = "BattleActionSelectionOverlay";
 
 const MAIN_WINDOW: &str = "MainWindow";
+const TARGET_CURSOR: &str = "target";
+const BUSY_CURSOR: &str = "busy";
 
 const MAIN_MENU_SCENE: &str = "MainMenuScene";
 const BATTLE_SCENE: &str = "BattleScene";
 const MAIN_SCENE: &str = "MainScene";
+const MAIN_LIGHTING: &str = "MainLighting";
 const BATTLE_TRANSITION_SCENE: &str = "BattleTransitionScene";
+const DEBUG_OVERLAY_SCENE: &str = "DebugOverlayScene";
+
+const JUNGLE_MUSIC: &str = "Jungle";
+///Stem of `JUNGLE_MUSIC` while a friendly character is low on health
+const BATTLE_DRUMS: &str = "BattleDrums";
 
 const UTIME: &str = "Time";
 const UUI_CAMERA: &str = "UICamera";
 const FROG: &str = "Frog";
+const PROTAGONIST: &str = "Protagonist";
+const PLAYER_SPRITE_SHEET: &str = "PlayerSpriteSheet";
+const BACKGROUND: &str = "Background";
+const DUNGEON: &str = "Dungeon";
+///Font `Text` uses unless told otherwise
 const FONT: &str = "Font";
+///Vector font of the menus, rasterized at whatever size they are shown
+const MENU_FONT: &str = "MenuFont";
 const END_GAME_BUTTON: &str = "EndGameButton";
 const START_GAME_BUTTON: &str = "StartGameButton";
+const LANGUAGE_BUTTON: &str = "LanguageButton";
+const LANGUAGES: &[&str] = &["en", "de"];
 const RESOLUTION: PhysicalSize<u16> = PhysicalSize::new(1920, 1080);
 const FLOAT_RESOULTION: PhysicalSize<f32> =
     PhysicalSize::new(RESOLUTION.width as f32, RESOLUTION.height as f32);
 
 const TARGET_FPS: u8 = 60;
-fn main() {
-    env_logger::Builder::from_env(Env::default().default_filter_or("warn")).init();
 
+fn ressource_descriptor() -> RessourceDescriptor {
     let cursor_path = "res/images/cursor/Cursor_Goth_Cursor.png";
     let main_window_descriptor = WindowDescriptor::new()
         .with_cursor(cursor_path)
+        .with_named_cursor(
+            TARGET_CURSOR,
+            CursorDescriptor::image("res/images/cursor/target.png").with_hotspot(0.5, 0.5),
+        )
+        .with_named_cursor(
+            BUSY_CURSOR,
+            CursorDescriptor::sprite_sheet(
+                "res/images/cursor/busy.png",
+                Duration::from_millis(80),
+            )
+            .with_hotspot(0.5, 0.5),
+        )
         .with_title("Wispers in the Void - Dark Dynasty")
-        .with_inner_size(RESOLUTION.clone());
-    let protaginist_name = "Protagonist";
-    let player_sprite_sheet = "PlayerSpriteSheet";
-    let background = "Background";
+        .with_inner_size(RESOLUTION.clone())
+        .with_virtual_resolution(VirtualResolution::new(FLOAT_RESOULTION, ScalingMode::Fit))
+        .with_post_processing(vec![
+            PostProcessDescriptor::vignette([0.2, 0.0, 0.0, 1.0], 0.6, 0.8, 0.45),
+            PostProcessDescriptor::film_grain(0.06, 24.0, 2.0),
+        ]);
     let camera_descriptor = CameraDescriptor {
         view_size: FLOAT_RESOULTION.clone(),
         speed: 90.0,
         acceleration_steps: 30,
-        target_entity: protaginist_name.into(),
-        bound_entity: Some(background.into()),
+        target_entity: PROTAGONIST.into(),
+        bound_entity: Some(BACKGROUND.into()),
         max_offset_position: 100.0,
     };
-    let ressources = RessourceDescriptor {
+    RessourceDescriptor {
         windows: vec![(MAIN_WINDOW.into(), main_window_descriptor)],
         uniforms: vec![
             (
@@ -118,20 +164,28 @@ fn main() {
                 bytemuck::cast_slice(&[0.0_f32]).to_vec(),
                 ferride_core::reexports::wgpu::ShaderStages::FRAGMENT,
             ),
+        ],
+        static_cameras: vec![(UUI_CAMERA.into(), MAIN_WINDOW.into(), FLOAT_RESOULTION)],
+        sounds: vec![
+            (
+                JUNGLE_MUSIC.into(),
+                PathBuf::from("res/audio/Jungle.mp3"),
+                Bus::Music,
+            ),
             (
-                UUI_CAMERA.into(),
-                bytemuck::cast_slice(&static_camera(FLOAT_RESOULTION.clone())).to_vec(),
-                ferride_core::reexports::wgpu::ShaderStages::VERTEX,
+                BATTLE_DRUMS.into(),
+                PathBuf::from("res/audio/BattleDrums.wav"),
+                Bus::Music,
             ),
         ],
-        default_render_scene: (
-            None,
-            RenderSceneDescriptor {
-                index_format: Index::index_format(),
-                use_textures: true,
-                vertex_buffer_layout: Vertex::describe_buffer_layout(),
-            },
-        ),
+        fonts: vec![
+            (FONT.into(), PathBuf::from("res/fonts/font.fnt")),
+            (
+                MENU_FONT.into(),
+                PathBuf::from("res/fonts/DejaVuSans-Bold.ttf"),
+            ),
+        ],
+        default_render_scene: (None, RenderSceneDescriptor::sprite_batch()),
         render_scenes: vec![
             (
                 vec![
@@ -139,22 +193,15 @@ fn main() {
                     BATTLE_DETAIL_OVERLAY_SCENE.into(),
                     BATTLE_ACTION_SELECTION_OVERLAY_SCENE.into(),
                     MAIN_MENU_SCENE.into(),
+                    DEBUG_OVERLAY_SCENE.into(),
                 ],
                 None,
-                RenderSceneDescriptor {
-                    index_format: Index::index_format(),
-                    use_textures: true,
-                    vertex_buffer_layout: UiVertex::describe_buffer_layout(),
-                },
+                RenderSceneDescriptor::sprite_batch(),
             ),
             (
                 vec![MAIN_SCENE.into()],
                 Some(camera_descriptor),
-                RenderSceneDescriptor {
-                    index_format: Index::index_format(),
-                    use_textures: true,
-                    vertex_buffer_layout: Vertex::describe_buffer_layout(),
-                },
+                RenderSceneDescriptor::sprite_batch(),
             ),
             (
                 vec![BATTLE_TRANSITION_SCENE.into()],
@@ -162,6 +209,8 @@ fn main() {
                 RenderSceneDescriptor {
                     index_format: Index::index_format(),
                     vertex_buffer_layout: SimpleVertex::describe_buffer_layout(),
+                    shader_defines: &[],
+                    kind: RenderSceneKind::Vertices,
                     use_textures: false,
                 },
             ),
@@ -169,7 +218,7 @@ fn main() {
         image_directory: PathBuf::from("res/images/spriteSheets/"),
         sprite_sheets: vec![
             (
-                player_sprite_sheet.into(),
+                PLAYER_SPRITE_SHEET.into(),
                 PathBuf::from("res/images/spriteSheets/ProtagonistP.png"),
                 SpriteSheetDimensions::new(4, 1),
             ),
@@ -179,72 +228,128 @@ fn main() {
                 SpriteSheetDimensions::new(4, 1),
             ),
             (
-                FONT.into(),
-                PathBuf::from("res/fonts/font.png"),
-                SpriteSheetDimensions::new(16, 16),
+                BACKGROUND.into(),
+                PathBuf::from("res/images/spriteSheets/background.png"),
+                SpriteSheetDimensions::new(1, 1),
+            ),
+            (
+                DUNGEON.into(),
+                PathBuf::from("res/images/spriteSheets/dungeon.png"),
+                SpriteSheetDimensions::new(4, 1),
             ),
         ],
-    };
-    // let main_scene = Scene {
-    //     z_index: 0,
-    //     shader_descriptor: ShaderDescriptor {
-    //         file: "res/shader/texture_array.wgsl",
-    //         vertex_shader: "vs_main",
-    //         fragment_shader: "fs_main",
-    //         uniforms: vec![],
-    //     },
-    //     name: MAIN_SCENE.into(),
-    //     render_scene: MAIN_SCENE.into(),
-    //     target_window: MAIN_WINDOW.into(),
-    //     entities: vec![
-    //         Box::new(Player {
-    //             name: protaginist_name.into(),
-    //             size: PhysicalSize::new(64, 128),
-    //             position: Vector::new(0.0, 0.0, 0.0),
-    //             velocity: VelocityController::new(3.0),
-    //             animation: Animation::new(
-    //                 player_sprite_sheet.into(),
-    //                 vec![
-    //                     (Duration::from_millis(240), SpritePosition::new(0, 0)),
-    //                     (Duration::from_millis(240), SpritePosition::new(1, 0)),
-    //                     (Duration::from_millis(240), SpritePosition::new(2, 0)),
-    //                     (Duration::from_millis(240), SpritePosition::new(3, 0)),
-    //                 ],
-    //                 false,
-    //             ),
-    //         }),
-    //         Box::new(Background {
-    //             name: background.into(),
-    //             size: PhysicalSize::new(1280, 800),
-    //             sprite_sheet: background.into(),
-    //         }),
-    //         Box::new(Enemy {
-    //             name: FROG.into(),
-    //             size: PhysicalSize::new(64, 64),
-    //             position: Vector::new(100.0, 100.0, 0.0),
-    //             animation: Animation::new(
-    //                 FROG.into(),
-    //                 vec![
-    //                     (Duration::from_millis(240), SpritePosition::new(0, 0)),
-    //                     (Duration::from_millis(240), SpritePosition::new(1, 0)),
-    //                     (Duration::from_millis(240), SpritePosition::new(2, 0)),
-    //                     (Duration::from_millis(240), SpritePosition::new(3, 0)),
-    //                 ],
-    //                 false,
-    //             ),
-    //             enemy_type: EnemyType::Frog,
-    //         }),
-    //     ],
-    // };
+    }
+}
+
+fn debug_overlay_scene() -> Scene<Event> {
+    Scene {
+        z_index: i32::MAX,
+        shader_descriptor: SHADER_SPRITE_BATCH,
+        name: DEBUG_OVERLAY_SCENE.into(),
+        render_scene: DEBUG_OVERLAY_SCENE.into(),
+        target_window: MAIN_WINDOW.into(),
+        entities: vec![Box::new(DebugOverlay::new(
+            "DebugOverlay".into(),
+            FLOAT_RESOULTION,
+        ))],
+    }
+}
+
+///Closes in on the screen before a battle, `TRANSITION_NAME` sends `Event::AnimationEnded` once
+///it is covered
+fn battle_transition_scene() -> Scene<Event> {
+    Scene {
+        z_index: 1,
+        shader_descriptor: SHADER_TRANSITION,
+        name: BATTLE_TRANSITION_SCENE.into(),
+        render_scene: BATTLE_TRANSITION_SCENE.into(),
+        target_window: MAIN_WINDOW.into(),
+        entities: vec![Box::new(Transition::new(
+            TransitionTypes::BattleTransition,
+            TRANSITION_NAME,
+            Duration::from_millis(750),
+        ))],
+    }
+}
+
+const PACKED_RESSOURCES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/res.pak"));
+fn main() {
+    env_logger::Builder::from_env(Env::default().default_filter_or("warn")).init();
+
+    VirtualFileSystem::new()
+        .with_archive(
+            PackedArchive::from_static(PACKED_RESSOURCES).expect("Packed ressources are corrupt"),
+        )
+        .install();
+    Localization::load(Path::new("res/lang"), LANGUAGES)
+        .expect("Could not load string tables")
+        .install();
+
+    let ressources = ressource_descriptor();
 
     // todo!("PROMOTE CAMERA TO ENTITY. And implement a static camera with screen size");
-    // let (_stream, stream_handle) = OutputStream::try_default().unwrap();
-    // let sink = Sink::try_new(&stream_handle).unwrap();
-    //
-    // let file = BufReader::new(File::open("res/audio/Jungle.mp3").unwrap());
-    // let source = Decoder::new(file).unwrap().amplify(0.1);
-    // sink.append(source);
-
-    let mut app = ManagerApplication::new(Game::new(ressources, TARGET_FPS, GameLogic::new()));
+
+    let debug_overlay_scene = debug_overlay_scene();
+    let game = Game::new(ressources, TARGET_FPS, GameLogic::new())
+        .with_lighting(MAIN_SCENE.into(), MAIN_LIGHTING.into(), [0.08, 0.06, 0.12])
+        .with_scene_music(
+            MAIN_MENU_SCENE.into(),
+            JUNGLE_MUSIC.into(),
+            Duration::from_secs(2),
+        )
+        .with_scene_music(
+            MAIN_SCENE.into(),
+            JUNGLE_MUSIC.into(),
+            Duration::from_secs(2),
+        )
+        .with_scene_music(
+            BATTLE_SCENE.into(),
+            JUNGLE_MUSIC.into(),
+            Duration::from_secs(2),
+        )
+        .with_debug_overlay(debug_overlay_scene, KeyCode::F3, FLOAT_RESOULTION);
+    let mut app = ManagerApplication::new(game);
     app.run();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shaders_match_render_scenes() {
+        let ressources = ressource_descriptor();
+        let mut scenes = vec![debug_overlay_scene(), battle_transition_scene()];
+        for game_state in [
+            game_state::GameState::MainMenu,
+            game_state::GameState::Exploration,
+            game_state::GameState::battle(),
+        ] {
+            scenes.extend(game_state.get_start_scenes());
+        }
+        for scene in scenes {
+            if let Err(err) =
+                ressources.validate_render_scene(&scene.render_scene, &scene.shader_descriptor)
+            {
+                panic!("{}: {}", scene.name.as_str(), err);
+            }
+        }
+    }
+
+    #[test]
+    fn exploration_casts_shadows() {
+        let scenes = game_state::GameState::Exploration.get_start_scenes();
+        let scene = scenes
+            .iter()
+            .find(|scene| scene.name.as_str() == MAIN_SCENE)
+            .unwrap();
+        assert!(scene
+            .entities
+            .iter()
+            .any(|entity| !entity.lights().is_empty()));
+        assert!(scene
+            .entities
+            .iter()
+            .any(|entity| !entity.light_occluders().is_empty()));
+    }
+}
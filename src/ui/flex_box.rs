@@ -25,9 +25,13 @@ pub enum FlexOrigin {
 
 pub trait FlexItem: Entity<Type, Event> {
     fn set_position(&mut self, position: &Vector<f32>);
-    fn is_dirty(&mut self) -> bool;
     fn set_focus(&mut self, focus: bool);
     fn has_focus(&self) -> bool;
+    ///Size the item wants, with its content broken to stay within `max_width`. Items that do
+    ///not size themselves by their content keep their bounding box
+    fn measure(&self, _max_width: Option<f32>) -> PhysicalSize<f32> {
+        self.bounding_box().size
+    }
 }
 #[macro_export]
 macro_rules! impl_flex_struct {
@@ -43,7 +47,13 @@ macro_rules! impl_flex_struct {
     };
     () => {
         fn flex(&mut self) {
-            let boxes = self.children.iter().map(|x| x.bounding_box());
+            // Children sized by their content are placed by the size they take, which can be
+            // ahead of their bounding box
+            let max_width = Some(self.dimensions.width as f32);
+            let boxes = self.children.iter().map(|child| BoundingBox {
+                size: child.measure(max_width),
+                ..child.bounding_box()
+            });
 
             let flex_origin = &self.position
                 + Vector::new(
@@ -206,8 +216,14 @@ macro_rules! impl_flex_struct {
             for child in &mut self.children {
                 events.append(&mut child.update(entities, delta_t, scene));
             }
-            if self.children.iter_mut().any(|c| c.is_dirty()) || self.is_dirty {
-               self.flex();
+            // Every child has to be asked, so all of them reset their flag
+            let children_dirty = self
+                .children
+                .iter_mut()
+                .fold(false, |dirty, c| c.is_dirty() || dirty);
+            if children_dirty || self.is_dirty {
+                self.flex();
+                self.is_dirty |= children_dirty;
             }
             events
         }
@@ -249,10 +265,39 @@ macro_rules! impl_flex_struct {
             }
             events
         }
+        fn flex_measure(&self, max_width: Option<f32>) -> PhysicalSize<f32> {
+            if !self.shrink_to_content {
+                return PhysicalSize::new(
+                    self.dimensions.width as f32,
+                    self.dimensions.height as f32,
+                );
+            }
+            let sizes = self.children.iter().map(|child| child.measure(max_width));
+            let gaps = self.gap * self.children.len().saturating_sub(1) as f32;
+            let (width, height) = match self.flex_direction {
+                FlexDirection::X => sizes.fold((gaps, 0.0_f32), |(width, height), size| {
+                    (width + size.width, height.max(size.height))
+                }),
+                FlexDirection::Y => sizes.fold((0.0_f32, gaps), |(width, height), size| {
+                    (width.max(size.width), height + size.height)
+                }),
+            };
+            PhysicalSize::new(width, height)
+        }
         fn flex_set_position(&mut self, position: &Vector<f32>) {
             self.position = position.clone();
             self.flex();
         }
+        fn flex_display_scale(&mut self, scale: &ferride_core::game_engine::DisplayScale) {
+            for child in self.children.iter_mut() {
+                child.display_scale(scale);
+            }
+        }
+        fn flex_language_changed(&mut self) {
+            for child in self.children.iter_mut() {
+                child.language_changed();
+            }
+        }
     };
 }
 
@@ -268,6 +313,8 @@ pub struct FlexBox {
     pub children: Vec<Box<dyn FlexItem>>,
     name: EntityName,
     shrink_to_content: bool,
+    ///Takes the size and position of the safe area instead of its own
+    fills_safe_area: bool,
     number_of_sprites: Vec<usize>,
     is_dirty: bool,
 }
@@ -297,15 +344,22 @@ impl FlexBox {
             children,
             name,
             shrink_to_content,
+            fills_safe_area: false,
             number_of_sprites,
             is_dirty: true,
         }
     }
 
+    ///For top level layouts, so they cover the visible part of the window at any resolution
+    pub fn fill_safe_area(mut self) -> Self {
+        self.fills_safe_area = true;
+        self
+    }
+
     fn render_background(
         &self,
         vertices: &mut ferride_core::app::VertexBuffer,
-        indices: &mut ferride_core::app::IndexBuffer,
+        _indices: &mut ferride_core::app::IndexBuffer,
         sprite_sheet: &[Option<&ferride_core::game_engine::SpriteSheet>],
         index: &mut usize,
     ) {
@@ -316,7 +370,6 @@ impl FlexBox {
                 render_ui_sprite(
                     &self.bounding_box(),
                     vertices,
-                    indices,
                     sprite_sheet,
                     sprite_position,
                     None,
@@ -341,6 +394,11 @@ impl Debug for FlexBox {
     }
 }
 impl Entity<Type, Event> for FlexBox {
+    fn is_dirty(&mut self) -> bool {
+        let dirt = self.is_dirty;
+        self.is_dirty = false;
+        dirt
+    }
     fn update(
         &mut self,
         entities: &Vec<&Box<dyn Entity<Type, Event>>>,
@@ -375,6 +433,19 @@ impl Entity<Type, Event> for FlexBox {
     fn entity_type(&self) -> Type {
         Type::Menu
     }
+    fn display_scale(&mut self, scale: &ferride_core::game_engine::DisplayScale) {
+        self.flex_display_scale(scale)
+    }
+    fn language_changed(&mut self) {
+        self.flex_language_changed()
+    }
+    fn safe_area(&mut self, area: &BoundingBox) {
+        if self.fills_safe_area {
+            self.dimensions = PhysicalSize::new(area.size.width as u16, area.size.height as u16);
+            self.flex_set_position(&area.anchor);
+            self.is_dirty = true;
+        }
+    }
     fn delete_child_entity(&mut self, name: &EntityName) {
         self.children.retain(|child| child.name() != name);
         for child in &mut self.children {
@@ -395,13 +466,11 @@ impl FlexItem for FlexBox {
         // }
         self.flex_set_position(position);
     }
-    fn is_dirty(&mut self) -> bool {
-        let dirt = self.is_dirty;
-        self.is_dirty = false;
-        dirt
-    }
     fn set_focus(&mut self, _focus: bool) {}
     fn has_focus(&self) -> bool {
         false
     }
+    fn measure(&self, max_width: Option<f32>) -> PhysicalSize<f32> {
+        self.flex_measure(max_width)
+    }
 }
@@ -3,14 +3,13 @@ use std::fmt::Debug;
 use ferride_core::{
     app::{IndexBuffer, VertexBuffer},
     game_engine::{BoundingBox, Entity, EntityName, SpritePosition, SpriteSheet, SpriteSheetName},
-    graphics::DEFAULT_TEXTURE,
+    graphics::{Color, DEFAULT_TEXTURE},
     reexports::winit::{keyboard::PhysicalKey, PhysicalSize},
 };
 use threed::Vector;
 
 use crate::{
-    color::Color,
-    ui::{FlexItem, Text},
+    ui::{FlexItem, Text, TextStyle},
     vertex::{render_ui_box_border, render_ui_sprite},
     Event, Type,
 };
@@ -113,7 +112,7 @@ pub struct Button {
 }
 impl Button {
     pub fn new(
-        text: String,
+        text: impl Into<crate::ui::TextContent>,
         name: EntityName,
         size: PhysicalSize<u16>,
         position: Vector<f32>,
@@ -148,14 +147,40 @@ impl Button {
             return;
         }
         self.is_highlighted = is_highlighted;
-        self.update_text_color()
+        self.update_text_color();
+        self.is_dirty = true;
+    }
+
+    pub fn with_font(mut self, font: ferride_core::fonts::FontName) -> Self {
+        self.text = self.text.with_font(font);
+        self
+    }
+
+    ///See `Text::with_markup`
+    pub fn with_markup(mut self) -> Self {
+        self.text = self.text.with_markup();
+        self
+    }
+
+    ///Aligns the label, for example when it is broken into several lines
+    pub fn with_text_style(mut self, style: TextStyle) -> Self {
+        self.text = self.text.with_style(style);
+        self
     }
 
-    pub fn set_content(&mut self, text: String) {
+    pub fn set_content(&mut self, text: impl Into<crate::ui::TextContent>) {
         self.text.set_text(text);
         self.is_dirty = true;
     }
 
+    fn has_border(&self) -> bool {
+        match self.style {
+            ButtonStyle::BorderBox(_) => true,
+            ButtonStyle::BackgroundImage(BackgroundImageStyle { with_border, .. }) => with_border,
+            _ => false,
+        }
+    }
+
     fn update_text_color(&mut self) {
         let colors = match &self.style {
             ButtonStyle::BorderBox(style) => Some((
@@ -200,6 +225,18 @@ impl Debug for Button {
     }
 }
 impl Entity<Type, Event> for Button {
+    fn is_dirty(&mut self) -> bool {
+        let dirt = self.is_dirty;
+        self.is_dirty = false;
+        self.text.is_dirty() || dirt
+    }
+    fn display_scale(&mut self, scale: &ferride_core::game_engine::DisplayScale) {
+        self.text.display_scale(scale)
+    }
+    fn language_changed(&mut self) {
+        self.text.language_changed();
+        self.is_dirty = true;
+    }
     fn handle_key_input(&mut self, input: &ferride_core::reexports::winit::event::KeyEvent) -> Vec<Event> {
         if self.is_focused && input.state == ferride_core::reexports::winit::event::ElementState::Pressed {
             match input.physical_key {
@@ -220,7 +257,6 @@ impl Entity<Type, Event> for Button {
     ) -> Vec<Event> {
         if self.is_dirty {
             self.position = self.text.position().clone();
-            self.is_dirty = false;
         }
         self.text.update(entities, delta_t, scene)
     }
@@ -254,7 +290,6 @@ impl Entity<Type, Event> for Button {
                     render_ui_sprite(
                         &bbox,
                         vertices,
-                        indices,
                         &sprite_sheet,
                         &SpritePosition::new(0, 0),
                         low_color,
@@ -262,7 +297,6 @@ impl Entity<Type, Event> for Button {
                     render_ui_box_border(
                         &bbox,
                         vertices,
-                        indices,
                         BORDER_THICKNESS,
                         high_color.expect("No high color"),
                     );
@@ -280,7 +314,6 @@ impl Entity<Type, Event> for Button {
                         render_ui_sprite(
                             &bbox,
                             vertices,
-                            indices,
                             &sprite_sheet,
                             &SpritePosition::new(0, 0),
                             color,
@@ -292,7 +325,8 @@ impl Entity<Type, Event> for Button {
                 todo!("implement ButtonStyle::Image");
             }
             ButtonStyle::BackgroundImage(style) => {
-                if let Some(sprite_sheet) = sprite_sheet[1] {
+                let background = self.text.sprite_sheets().len();
+                if let Some(sprite_sheet) = sprite_sheet[background] {
                     let sprite_position = if self.is_highlighted {
                         &style.highlight_sprite
                     } else if self.is_focused {
@@ -310,18 +344,10 @@ impl Entity<Type, Event> for Button {
                     let mut bbox = self.bounding_box();
                     bbox.size.height -= 2.0 * BORDER_THICKNESS;
                     bbox.size.width -= 2.0 * BORDER_THICKNESS;
-                    render_ui_sprite(
-                        &bbox,
-                        vertices,
-                        indices,
-                        sprite_sheet,
-                        sprite_position,
-                        None,
-                    );
+                    render_ui_sprite(&bbox, vertices, sprite_sheet, sprite_position, None);
                     render_ui_box_border(
                         &bbox,
                         vertices,
-                        indices,
                         BORDER_THICKNESS,
                         border_color.expect("No border color"),
                     );
@@ -336,21 +362,10 @@ impl Entity<Type, Event> for Button {
     }
     fn bounding_box(&self) -> BoundingBox {
         let mut bbox = self.text.bounding_box();
-        let mut resize_bbox = || {
+        if self.has_border() {
             bbox.size.height += 2.0 * BORDER_THICKNESS;
             bbox.size.width += 2.0 * BORDER_THICKNESS;
             bbox.anchor += Vector::new(BORDER_THICKNESS, BORDER_THICKNESS, 0.0);
-        };
-        match self.style {
-            ButtonStyle::BorderBox(_) => {
-                resize_bbox();
-            }
-            ButtonStyle::BackgroundImage(BackgroundImageStyle { with_border, .. })
-                if with_border =>
-            {
-                resize_bbox();
-            }
-            _ => {}
         }
         bbox
     }
@@ -374,23 +389,64 @@ impl Entity<Type, Event> for Button {
 }
 impl FlexItem for Button {
     fn set_position(&mut self, position: &Vector<f32>) {
-        self.is_dirty = true;
+        // Containers position their children every time they flex, which must not keep them dirty
+        if self.text.position() != *position {
+            self.is_dirty = true;
+        }
         self.text.set_position(position);
     }
 
-    fn is_dirty(&mut self) -> bool {
-        self.text.is_dirty()
-    }
-
     fn set_focus(&mut self, is_focused: bool) {
         if self.is_focused == is_focused {
             return;
         }
         self.is_focused = is_focused;
         self.update_text_color();
+        self.is_dirty = true;
     }
 
     fn has_focus(&self) -> bool {
         self.is_focused
     }
+
+    fn measure(&self, max_width: Option<f32>) -> PhysicalSize<f32> {
+        let border = if self.has_border() {
+            2.0 * BORDER_THICKNESS
+        } else {
+            0.0
+        };
+        let text = self
+            .text
+            .measure(max_width.map(|max_width| max_width - border));
+        PhysicalSize::new(text.width + border, text.height + border)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::text::FontSize;
+
+    #[test]
+    fn focus_and_highlight_mark_dirty() {
+        let mut button = Button::new(
+            "Ok",
+            "Ok".into(),
+            PhysicalSize::new(100, 40),
+            Vector::scalar(0.0),
+            FontSize::new(20),
+            false,
+            ButtonStyle::default(),
+        );
+        button.is_dirty();
+        assert!(!button.is_dirty());
+        button.set_focus(true);
+        assert!(button.is_dirty());
+        button.set_highlighted(true);
+        assert!(button.is_dirty());
+        button.set_position(&Vector::scalar(0.0));
+        assert!(!button.is_dirty());
+        button.set_position(&Vector::new(10.0, 0.0, 0.0));
+        assert!(button.is_dirty());
+    }
 }
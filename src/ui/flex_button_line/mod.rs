@@ -118,7 +118,7 @@ impl<T: FlexItem> FlexInputManager<T> {
     fn render_background(
         &self,
         vertices: &mut ferride_core::app::VertexBuffer,
-        indices: &mut ferride_core::app::IndexBuffer,
+        _indices: &mut ferride_core::app::IndexBuffer,
         sprite_sheet: &[Option<&ferride_core::game_engine::SpriteSheet>],
         index: &mut usize,
     ) {
@@ -129,7 +129,6 @@ impl<T: FlexItem> FlexInputManager<T> {
                 render_ui_sprite(
                     &self.bounding_box(),
                     vertices,
-                    indices,
                     sprite_sheet,
                     sprite_position,
                     None,
@@ -154,6 +153,11 @@ impl<T: FlexItem> Debug for FlexInputManager<T> {
     }
 }
 impl<T: FlexItem> Entity<Type, Event> for FlexInputManager<T> {
+    fn is_dirty(&mut self) -> bool {
+        let dirt = self.is_dirty;
+        self.is_dirty = false;
+        dirt
+    }
     fn delete_child_entity(&mut self, name: &EntityName) {
         let original_len = self.children.len();
         self.children.retain(|child| child.name() != name);
@@ -220,6 +224,12 @@ impl<T: FlexItem> Entity<Type, Event> for FlexInputManager<T> {
     fn sprite_sheets(&self) -> Vec<&ferride_core::game_engine::SpriteSheetName> {
         self.flex_sprite_sheets()
     }
+    fn display_scale(&mut self, scale: &ferride_core::game_engine::DisplayScale) {
+        self.flex_display_scale(scale)
+    }
+    fn language_changed(&mut self) {
+        self.flex_language_changed()
+    }
     fn entity_type(&self) -> Type {
         Type::Menu
     }
@@ -241,12 +251,6 @@ impl<T: FlexItem> FlexItem for FlexInputManager<T> {
         self.flex_set_position(position);
     }
 
-    fn is_dirty(&mut self) -> bool {
-        let dirt = self.is_dirty;
-        self.is_dirty = false;
-        dirt
-    }
-
     fn set_focus(&mut self, focus: bool) {
         if focus == self.has_focus {
             return;
@@ -264,4 +268,7 @@ impl<T: FlexItem> FlexItem for FlexInputManager<T> {
     fn has_focus(&self) -> bool {
         self.has_focus
     }
+    fn measure(&self, max_width: Option<f32>) -> PhysicalSize<f32> {
+        self.flex_measure(max_width)
+    }
 }
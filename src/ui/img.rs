@@ -1,12 +1,13 @@
 use ferride_core::{
     app::{IndexBuffer, VertexBuffer},
     game_engine::{BoundingBox, Entity, EntityName, SpritePosition, SpriteSheet, SpriteSheetName},
+    graphics::Color,
 };
 use std::fmt::Debug;
 use threed::Vector;
 use ferride_core::reexports::winit::PhysicalSize;
 
-use crate::{color::Color, vertex::render_ui_sprite, Event, Type};
+use crate::{vertex::render_ui_sprite, Event, Type};
 
 use super::FlexItem;
 
@@ -45,17 +46,21 @@ impl Debug for Image {
     }
 }
 impl Entity<Type, Event> for Image {
+    fn is_dirty(&mut self) -> bool {
+        let dirt = self.is_dirty;
+        self.is_dirty = false;
+        dirt
+    }
     fn render(
         &mut self,
         vertices: &mut VertexBuffer,
-        indices: &mut IndexBuffer,
+        _indices: &mut IndexBuffer,
         sprite_sheet: Vec<Option<&SpriteSheet>>,
     ) {
         if let Some(sprite_sheet) = sprite_sheet[0] {
             render_ui_sprite(
                 &self.bounding_box(),
                 vertices,
-                indices,
                 sprite_sheet,
                 &self.image.1,
                 self.blend_color.as_ref(),
@@ -83,12 +88,6 @@ impl FlexItem for Image {
         self.position = position.clone();
     }
 
-    fn is_dirty(&mut self) -> bool {
-        let dirt = self.is_dirty;
-        self.is_dirty = false;
-        dirt
-    }
-    
     fn set_focus(&mut self, _focus: bool) {
     }
 
@@ -2,7 +2,10 @@ mod flex_box;
 pub use flex_box::{Alignment, FlexBox, FlexDirection, FlexItem, FlexOrigin};
 
 mod text;
-pub use {text::FontSize, text::Text};
+pub use {
+    text::FontSize, text::Overflow, text::Text, text::TextAlign, text::TextContent,
+    text::TextStyle, text::VerticalAlign,
+};
 
 mod img;
 pub use img::Image;
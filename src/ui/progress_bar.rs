@@ -3,14 +3,13 @@ use std::time::Duration;
 use ferride_core::{
     app::{IndexBuffer, VertexBuffer},
     game_engine::{BoundingBox, Entity, EntityName, SpritePosition, SpriteSheet, SpriteSheetName},
-    graphics::DEFAULT_TEXTURE,
+    graphics::{Color, DEFAULT_TEXTURE},
     reexports::winit::PhysicalSize,
 };
 use threed::Vector;
 
 use crate::{
     animation::Animation,
-    color::Color,
     event::Event,
     vertex::{render_ui_box_border, render_ui_sprite},
     Type, TARGET_FPS,
@@ -89,7 +88,6 @@ fn render_bar_part(
     max_value: f32,
     current_value: f32,
     vertices: &mut VertexBuffer,
-    indices: &mut IndexBuffer,
     sprite_sheet: &SpriteSheet,
     sprite_position: &SpritePosition,
     bounding_box: &BoundingBox,
@@ -110,7 +108,6 @@ fn render_bar_part(
     render_ui_sprite(
         &bounding_box,
         vertices,
-        indices,
         sprite_sheet,
         &sprite_position,
         Some(color),
@@ -118,6 +115,11 @@ fn render_bar_part(
 }
 
 impl Entity<Type, Event> for ProgressBar {
+    fn is_dirty(&mut self) -> bool {
+        let dirt = self.is_dirty || self.animation.is_running();
+        self.is_dirty = false;
+        dirt
+    }
     fn update(
         &mut self,
         _entities: &Vec<&Box<dyn Entity<Type, Event>>>,
@@ -134,7 +136,7 @@ impl Entity<Type, Event> for ProgressBar {
     fn render(
         &mut self,
         vertices: &mut VertexBuffer,
-        indices: &mut IndexBuffer,
+        _indices: &mut IndexBuffer,
         sprite_sheet: Vec<Option<&SpriteSheet>>,
     ) {
         if let Some(sprite_sheet) = sprite_sheet[0] {
@@ -151,18 +153,11 @@ impl Entity<Type, Event> for ProgressBar {
             render_ui_sprite(
                 &bounding_box,
                 vertices,
-                indices,
                 sprite_sheet,
                 &sprite_position,
                 Some(&self.colors.low),
             );
-            render_ui_box_border(
-                &bounding_box,
-                vertices,
-                indices,
-                border_thickness,
-                &self.colors.high,
-            );
+            render_ui_box_border(&bounding_box, vertices, border_thickness, &self.colors.high);
             let animation_differential = animation_value - self.current_value;
             let (value_color, animation_color) = if animation_differential <= 0.0 {
                 (&PROGRESS_BAR_ANIMATION_COLOR, &self.colors.high)
@@ -174,7 +169,6 @@ impl Entity<Type, Event> for ProgressBar {
                     self.max_value,
                     animation_value,
                     vertices,
-                    indices,
                     sprite_sheet,
                     &sprite_position,
                     &bounding_box,
@@ -185,7 +179,6 @@ impl Entity<Type, Event> for ProgressBar {
                 self.max_value,
                 self.current_value,
                 vertices,
-                indices,
                 sprite_sheet,
                 &sprite_position,
                 &bounding_box,
@@ -196,7 +189,6 @@ impl Entity<Type, Event> for ProgressBar {
                     self.max_value,
                     animation_value,
                     vertices,
-                    indices,
                     sprite_sheet,
                     &sprite_position,
                     &bounding_box,
@@ -231,12 +223,6 @@ impl Entity<Type, Event> for ProgressBar {
     }
 }
 impl FlexItem for ProgressBar {
-    fn is_dirty(&mut self) -> bool {
-        let dirt = self.is_dirty;
-        self.is_dirty = false;
-        dirt
-    }
-
     fn has_focus(&self) -> bool {
         false
     }
@@ -248,3 +234,49 @@ impl FlexItem for ProgressBar {
         self.position = position.clone();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ferride_core::{
+        game_engine::static_camera,
+        graphics::{compare_golden, ReferenceRasterizer, SpriteInstance, Vertex},
+    };
+
+    use super::*;
+    use crate::ui::Padding;
+
+    #[test]
+    fn render_matches_golden() {
+        let mut bar = ProgressBar::new(
+            "bar".into(),
+            PhysicalSize::new(60, 12),
+            Vector::scalar(0.0),
+            100,
+            40,
+            ColorPair::new(
+                Color::new_rgba(200, 30, 30, 255),
+                Color::new_rgba(40, 40, 40, 255),
+            ),
+            Padding::default(),
+        );
+        let mut vertices = VertexBuffer::new();
+        let mut indices = IndexBuffer::new();
+        bar.render(&mut vertices, &mut indices, vec![Some(&SpriteSheet::default())]);
+
+        let image = ReferenceRasterizer::new(PhysicalSize::new(80, 24))
+            .with_camera(bytemuck::cast_slice(&static_camera(PhysicalSize::new(
+                80.0, 24.0,
+            ))))
+            .render(
+                &vertices,
+                &indices,
+                &SpriteInstance::describe_buffer_layout(),
+            );
+        compare_golden(
+            &image,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/goldens/progress_bar.png"),
+            1,
+        )
+        .unwrap();
+    }
+}
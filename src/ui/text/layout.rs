@@ -0,0 +1,589 @@
+use ferride_core::fonts::Font;
+use ferride_core::reexports::winit::PhysicalSize;
+
+const ELLIPSIS: char = '…';
+
+///How lines are placed between the left and right edge of a `Text`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+    ///Stretches the spaces of every line but the last one of a paragraph to fill the width
+    Justify,
+}
+
+///How the lines are placed between the top and bottom edge of a `Text`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VerticalAlign {
+    Top,
+    Center,
+    Bottom,
+}
+
+///What happens to lines that do not fit into the box of a `Text`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Overflow {
+    ///Drops them, the first line is always shown
+    Clip,
+    ///Drops them and ends the last line that fits with `…`
+    Ellipsis,
+    ///Makes the font smaller until the text fits, without breaking words
+    ShrinkToFit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextStyle {
+    pub align: TextAlign,
+    pub vertical_align: VerticalAlign,
+    ///Distance between the tops of two lines, in line heights of the font
+    pub line_spacing: f32,
+    pub overflow: Overflow,
+}
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self {
+            align: TextAlign::Left,
+            vertical_align: VerticalAlign::Top,
+            line_spacing: 1.0,
+            overflow: Overflow::Clip,
+        }
+    }
+}
+
+///Part of the text in one font and size
+#[derive(Debug, Clone)]
+pub struct Run<'a> {
+    pub font: &'a Font,
+    ///Before `Overflow::ShrinkToFit` scales it
+    pub size: f32,
+    pub content: RunContent<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RunContent<'a> {
+    Text(&'a str),
+    ///Square as high as the ascent of the font
+    Icon,
+}
+
+///What a run is broken into
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Atom {
+    Character(char),
+    Icon,
+}
+impl Atom {
+    fn is_space(&self) -> bool {
+        *self == Atom::Character(' ')
+    }
+}
+
+///Sizes of the runs at the scale they are laid out in
+#[derive(Clone, Copy)]
+struct Metrics<'a> {
+    runs: &'a [Run<'a>],
+    scale: f32,
+}
+impl Metrics<'_> {
+    fn size(&self, run: usize) -> f32 {
+        self.runs[run].size * self.scale
+    }
+
+    fn ascent(&self, run: usize) -> f32 {
+        self.runs[run].font.ascent(self.size(run))
+    }
+
+    fn line_height(&self, run: usize) -> f32 {
+        self.runs[run].font.line_height(self.size(run))
+    }
+
+    fn advance(&self, atom: Atom, run: usize) -> f32 {
+        match atom {
+            Atom::Character(character) => self.runs[run].font.advance(character, self.size(run)),
+            Atom::Icon => self.ascent(run),
+        }
+    }
+
+    fn kerning(&self, (first, first_run): (Atom, usize), (second, run): (Atom, usize)) -> f32 {
+        match (first, second) {
+            (Atom::Character(first), Atom::Character(second)) if first_run == run => {
+                self.runs[run].font.kerning(first, second, self.size(run))
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyph {
+    pub atom: Atom,
+    pub run: usize,
+    ///From the start of the line
+    pub x: f32,
+}
+
+///Glyphs of one line
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Line {
+    pub glyphs: Vec<Glyph>,
+    ///Up to the end of the last glyph that is no space
+    pub width: f32,
+    ///From the top of the line to the baseline of its tallest run
+    pub ascent: f32,
+    pub height: f32,
+    ///The last line of its paragraph is not justified
+    pub ends_paragraph: bool,
+}
+impl Line {
+    fn push(&mut self, atom: Atom, run: usize, metrics: Metrics) {
+        let pen = match self.glyphs.last() {
+            Some(last) => {
+                last.x
+                    + metrics.advance(last.atom, last.run)
+                    + metrics.kerning((last.atom, last.run), (atom, run))
+            }
+            None => 0.0,
+        };
+        self.glyphs.push(Glyph { atom, run, x: pen });
+        if !atom.is_space() {
+            self.width = pen + metrics.advance(atom, run);
+        }
+        self.fit(run, metrics);
+    }
+
+    ///Makes the line high enough for `run`
+    fn fit(&mut self, run: usize, metrics: Metrics) {
+        self.ascent = self.ascent.max(metrics.ascent(run));
+        self.height = self.height.max(metrics.line_height(run));
+    }
+
+    ///Where the pen would be after `atoms`, if they were pushed
+    fn width_with(&self, atoms: &[(Atom, usize)], metrics: Metrics) -> f32 {
+        let mut line = self.clone();
+        atoms
+            .iter()
+            .for_each(|(atom, run)| line.push(*atom, *run, metrics));
+        line.width
+    }
+
+    fn trim_end(&mut self, metrics: Metrics) {
+        while self
+            .glyphs
+            .last()
+            .is_some_and(|glyph| glyph.atom.is_space())
+        {
+            self.glyphs.pop();
+        }
+        self.width = self.glyphs.last().map_or(0.0, |glyph| {
+            glyph.x + metrics.advance(glyph.atom, glyph.run)
+        });
+    }
+
+    fn is_blank(&self) -> bool {
+        self.glyphs.iter().all(|glyph| glyph.atom.is_space())
+    }
+}
+
+///Atoms of every paragraph with the run the paragraph starts in
+fn paragraphs(runs: &[Run]) -> Vec<(Vec<(Atom, usize)>, usize)> {
+    let mut paragraphs = vec![(Vec::new(), 0)];
+    for (index, run) in runs.iter().enumerate() {
+        match run.content {
+            RunContent::Text(text) => {
+                for character in text.chars() {
+                    let atom = match character {
+                        '\n' => {
+                            paragraphs.push((Vec::new(), index));
+                            continue;
+                        }
+                        '\t' => Atom::Character(' '),
+                        character => Atom::Character(character),
+                    };
+                    if let Some((atoms, _)) = paragraphs.last_mut() {
+                        atoms.push((atom, index));
+                    }
+                }
+            }
+            RunContent::Icon => {
+                if let Some((atoms, _)) = paragraphs.last_mut() {
+                    atoms.push((Atom::Icon, index));
+                }
+            }
+        }
+    }
+    paragraphs
+}
+
+///Breaks the runs into lines at `\n` and, to stay within `max_width`, between words. Words wider
+///than `max_width` are broken between glyphs, the returned flag tells if any was
+fn break_lines(metrics: Metrics, max_width: f32) -> (Vec<Line>, bool) {
+    let mut lines = Vec::new();
+    let mut broke_word = false;
+    if metrics.runs.is_empty() {
+        return (lines, broke_word);
+    }
+    for (atoms, first_run) in paragraphs(metrics.runs) {
+        let mut line = Line::default();
+        for word in atoms.chunk_by(|(a, _), (b, _)| a.is_space() == b.is_space()) {
+            if word[0].0.is_space() {
+                word.iter()
+                    .for_each(|(atom, run)| line.push(*atom, *run, metrics));
+                continue;
+            }
+            if !line.is_blank() && line.width_with(word, metrics) > max_width {
+                line.trim_end(metrics);
+                lines.push(std::mem::take(&mut line));
+            }
+            for glyph in word {
+                if !line.is_blank() && line.width_with(&[*glyph], metrics) > max_width {
+                    broke_word = true;
+                    lines.push(std::mem::take(&mut line));
+                }
+                line.push(glyph.0, glyph.1, metrics);
+            }
+        }
+        if line.glyphs.is_empty() {
+            line.fit(first_run, metrics);
+        }
+        line.ends_paragraph = true;
+        lines.push(line);
+    }
+    (lines, broke_word)
+}
+
+///From the top of the first line to the bottom of the last one
+fn height(lines: &[Line], line_spacing: f32) -> f32 {
+    match lines.split_last() {
+        Some((last, lines)) => {
+            lines
+                .iter()
+                .map(|line| line.height * line_spacing)
+                .sum::<f32>()
+                + last.height
+        }
+        None => 0.0,
+    }
+}
+
+fn width(lines: &[Line]) -> f32 {
+    lines
+        .iter()
+        .fold(0.0, |width: f32, line| width.max(line.width))
+}
+
+///A glyph placed in the box of a `Text`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacedGlyph {
+    pub atom: Atom,
+    pub run: usize,
+    ///Top left corner relative to the top left corner of the box, y grows downwards
+    pub x: f32,
+    pub y: f32,
+}
+
+///Runs broken into lines, that fit into a box as far as its `Overflow` allows
+#[derive(Debug, Clone, Default)]
+pub struct TextLayout {
+    pub lines: Vec<Line>,
+    ///Can be smaller than asked for with `Overflow::ShrinkToFit`
+    pub font_size: f32,
+    ///Of the run sizes, to get from the asked for to the laid out font size
+    pub scale: f32,
+    ///Width of the widest and height of all lines
+    pub size: PhysicalSize<f32>,
+    ///Lines were dropped, because they did not fit
+    pub is_cut: bool,
+}
+impl TextLayout {
+    ///`font_size` is the size of the Text the runs are part of. `snap` rounds font sizes tried by
+    ///`Overflow::ShrinkToFit` to ones the display can show
+    pub fn new(
+        runs: &[Run],
+        font_size: f32,
+        bounds: PhysicalSize<f32>,
+        style: &TextStyle,
+        snap: impl Fn(f32) -> f32,
+    ) -> Self {
+        let mut size = font_size;
+        let metrics = |size: f32| Metrics {
+            runs,
+            scale: size / font_size,
+        };
+        let (mut lines, mut broke_word) = break_lines(metrics(size), bounds.width);
+        if style.overflow == Overflow::ShrinkToFit {
+            let fits = |lines: &Vec<Line>, broke_word: bool| {
+                !broke_word && height(lines, style.line_spacing) <= bounds.height
+            };
+            while !fits(&lines, broke_word) && size > 1.0 {
+                size = snap(size - 1.0).min(size - 1.0).max(1.0);
+                (lines, broke_word) = break_lines(metrics(size), bounds.width);
+            }
+        }
+        let metrics = metrics(size);
+        let mut fitting = 0;
+        let mut top = 0.0;
+        for line in &lines {
+            if fitting > 0 && top + line.height > bounds.height {
+                break;
+            }
+            top += line.height * style.line_spacing;
+            fitting += 1;
+        }
+        let is_cut = lines.len() > fitting;
+        if is_cut {
+            lines.truncate(fitting);
+            if style.overflow == Overflow::Ellipsis {
+                if let Some(line) = lines.last_mut() {
+                    let run = line.glyphs.last().map_or(0, |glyph| glyph.run);
+                    let ellipsis = (Atom::Character(ELLIPSIS), run);
+                    line.trim_end(metrics);
+                    while !line.glyphs.is_empty()
+                        && line.width_with(&[ellipsis], metrics) > bounds.width
+                    {
+                        line.glyphs.pop();
+                        line.trim_end(metrics);
+                    }
+                    line.push(ellipsis.0, ellipsis.1, metrics);
+                }
+            }
+        }
+        Self {
+            size: PhysicalSize::new(width(&lines), height(&lines, style.line_spacing)),
+            lines,
+            font_size: size,
+            scale: metrics.scale,
+            is_cut,
+        }
+    }
+
+    ///Every glyph placed in a `bounds` sized box. `runs` are the ones the layout was made of
+    pub fn positions(
+        &self,
+        runs: &[Run],
+        bounds: PhysicalSize<f32>,
+        style: &TextStyle,
+    ) -> Vec<PlacedGlyph> {
+        let metrics = Metrics {
+            runs,
+            scale: self.scale,
+        };
+        let mut top = match style.vertical_align {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Center => (bounds.height - self.size.height) / 2.0,
+            VerticalAlign::Bottom => bounds.height - self.size.height,
+        };
+        let mut positions = Vec::new();
+        for line in &self.lines {
+            let free = bounds.width - line.width;
+            let (start, stretch) = match style.align {
+                TextAlign::Left => (0.0, 0.0),
+                TextAlign::Center => (free / 2.0, 0.0),
+                TextAlign::Right => (free, 0.0),
+                TextAlign::Justify if line.ends_paragraph || !free.is_finite() => (0.0, 0.0),
+                TextAlign::Justify => {
+                    let visible = line
+                        .glyphs
+                        .iter()
+                        .rposition(|glyph| !glyph.atom.is_space())
+                        .unwrap_or(0);
+                    let spaces = line.glyphs[..visible]
+                        .iter()
+                        .filter(|glyph| glyph.atom.is_space())
+                        .count();
+                    (
+                        0.0,
+                        if spaces > 0 {
+                            free / spaces as f32
+                        } else {
+                            0.0
+                        },
+                    )
+                }
+            };
+            let mut spaces = 0;
+            for glyph in &line.glyphs {
+                positions.push(PlacedGlyph {
+                    atom: glyph.atom,
+                    run: glyph.run,
+                    x: start + glyph.x + spaces as f32 * stretch,
+                    y: top + line.ascent - metrics.ascent(glyph.run),
+                });
+                if glyph.atom.is_space() {
+                    spaces += 1;
+                }
+            }
+            top += line.height * style.line_spacing;
+        }
+        positions
+    }
+}
+
+///Size of the runs with every line shown, broken to stay within `max_width`
+pub fn measure(runs: &[Run], max_width: Option<f32>, line_spacing: f32) -> PhysicalSize<f32> {
+    let metrics = Metrics { runs, scale: 1.0 };
+    let (lines, _) = break_lines(metrics, max_width.unwrap_or(f32::INFINITY));
+    PhysicalSize::new(width(&lines), height(&lines, line_spacing))
+}
+
+#[cfg(test)]
+mod tests {
+    use ferride_core::fonts::BitmapFont;
+
+    use super::*;
+
+    fn font() -> Font {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/res/fonts/font.fnt");
+        BitmapFont::parse(path, &std::fs::read(path).unwrap())
+            .unwrap()
+            .into()
+    }
+
+    fn runs<'a>(font: &'a Font, text: &'a str) -> [Run<'a>; 1] {
+        [Run {
+            font,
+            size: 32.0,
+            content: RunContent::Text(text),
+        }]
+    }
+
+    fn text(line: &Line) -> String {
+        line.glyphs
+            .iter()
+            .map(|glyph| match glyph.atom {
+                Atom::Character(character) => character,
+                Atom::Icon => '#',
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wraps_on_words_and_breaks_long_ones() {
+        let font = font();
+        let word = font.advance('a', 32.0) * 4.0;
+        let runs = runs(&font, "aaa aaa\taaa\n\naaaaaaaaa");
+        let metrics = Metrics {
+            runs: &runs,
+            scale: 1.0,
+        };
+        let (lines, broke_word) = break_lines(metrics, word * 2.0);
+        let lines = lines.iter().map(text).collect::<Vec<_>>();
+        assert_eq!(lines, ["aaa aaa", "aaa", "", "aaaaaaaa", "a"]);
+        assert!(broke_word);
+
+        let size = measure(&self::runs(&font, "aaa aaa"), Some(word), 1.5);
+        assert_eq!(size.height, font.line_height(32.0) * 2.5);
+        assert!(size.width <= word);
+    }
+
+    #[test]
+    fn aligns_lines_in_the_box() {
+        let font = font();
+        let runs = runs(&font, "ab cd ef");
+        let bounds = PhysicalSize::new(500.0, 200.0);
+        let x_of = |align: TextAlign, vertical_align: VerticalAlign| {
+            let style = TextStyle {
+                align,
+                vertical_align,
+                ..TextStyle::default()
+            };
+            let layout = TextLayout::new(&runs, 32.0, bounds, &style, |s| s);
+            let positions = layout.positions(&runs, bounds, &style);
+            (
+                positions[0].x,
+                positions[7].x + font.advance('f', 32.0),
+                positions[0].y,
+            )
+        };
+        let (left, _, top) = x_of(TextAlign::Left, VerticalAlign::Top);
+        assert_eq!((left, top), (0.0, 0.0));
+        let (_, right, bottom) = x_of(TextAlign::Right, VerticalAlign::Bottom);
+        assert!((right - bounds.width).abs() < 0.01);
+        assert!((bottom + font.line_height(32.0) - bounds.height).abs() < 0.01);
+        let (start, end, middle) = x_of(TextAlign::Center, VerticalAlign::Center);
+        assert!((start - (bounds.width - end)).abs() < 0.01);
+        assert!((middle * 2.0 + font.line_height(32.0) - bounds.height).abs() < 0.01);
+
+        let style = TextStyle {
+            align: TextAlign::Justify,
+            ..TextStyle::default()
+        };
+        let narrow = PhysicalSize::new(font.advance('a', 32.0) * 7.0, 200.0);
+        let layout = TextLayout::new(&runs, 32.0, narrow, &style, |s| s);
+        let positions = layout.positions(&runs, narrow, &style);
+        let x_of = |character: char| {
+            positions
+                .iter()
+                .find(|glyph| glyph.atom == Atom::Character(character))
+                .unwrap()
+                .x
+        };
+        assert!((x_of('d') + font.advance('d', 32.0) - narrow.width).abs() < 0.01);
+        assert_eq!(x_of('e'), 0.0);
+    }
+
+    #[test]
+    fn puts_runs_on_one_baseline() {
+        let font = font();
+        let runs = [
+            Run {
+                font: &font,
+                size: 32.0,
+                content: RunContent::Text("a"),
+            },
+            Run {
+                font: &font,
+                size: 64.0,
+                content: RunContent::Text("b"),
+            },
+            Run {
+                font: &font,
+                size: 32.0,
+                content: RunContent::Icon,
+            },
+        ];
+        let bounds = PhysicalSize::new(1000.0, 1000.0);
+        let layout = TextLayout::new(&runs, 32.0, bounds, &TextStyle::default(), |s| s);
+        assert_eq!(text(&layout.lines[0]), "ab#");
+        assert_eq!(layout.size.height, font.line_height(64.0));
+        let positions = layout.positions(&runs, bounds, &TextStyle::default());
+        assert!(positions.iter().all(|glyph| {
+            let baseline = glyph.y + font.ascent(runs[glyph.run].size);
+            (baseline - font.ascent(64.0)).abs() < 0.01
+        }));
+        assert_eq!(
+            positions[2].x,
+            font.advance('a', 32.0) + font.advance('b', 64.0)
+        );
+        assert_eq!(layout.size.width, positions[2].x + font.ascent(32.0));
+    }
+
+    #[test]
+    fn handles_overflow() {
+        let font = font();
+        let line_height = font.line_height(32.0);
+        let bounds = PhysicalSize::new(1000.0, line_height * 1.5);
+        let runs = runs(&font, "first line\nsecond line");
+
+        let clipped = TextLayout::new(&runs, 32.0, bounds, &TextStyle::default(), |s| s);
+        assert!(clipped.is_cut);
+        assert_eq!(clipped.lines.len(), 1);
+
+        let style = TextStyle {
+            overflow: Overflow::Ellipsis,
+            ..TextStyle::default()
+        };
+        let ellipsis = TextLayout::new(&runs, 32.0, bounds, &style, |s| s);
+        assert_eq!(text(&ellipsis.lines[0]), "first line…");
+
+        let style = TextStyle {
+            overflow: Overflow::ShrinkToFit,
+            ..TextStyle::default()
+        };
+        let shrunk = TextLayout::new(&runs, 32.0, bounds, &style, |s| s.floor());
+        assert!(!shrunk.is_cut);
+        assert!(shrunk.font_size < 32.0 && shrunk.font_size == shrunk.font_size.floor());
+        assert_eq!(shrunk.scale, shrunk.font_size / 32.0);
+        assert!(shrunk.size.height <= bounds.height);
+    }
+}
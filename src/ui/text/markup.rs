@@ -0,0 +1,222 @@
+//!Inline markup of a `Text`. Tags are written in square brackets and closed in reverse order:
+//!
+//!- `[color=#ff0000]12[/color]` colors the text, takes `#rrggbb[aa]` or a color name
+//!- `[font=MenuFont]…[/font]` uses another registered font
+//!- `[size=24]…[/size]` changes the font size
+//!- `[b]…[/b]` draws the text bold
+//!- `[icon=sheet:x,y]` shows the sprite at `x,y` of the SpriteSheet `sheet` inline
+//!
+//!`[[` is a literal `[`
+use std::{error::Error, fmt::Display};
+
+use ferride_core::fonts::FontName;
+use ferride_core::game_engine::{SpritePosition, SpriteSheetName};
+use ferride_core::graphics::Color;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkupError {
+    ///Byte offset of a `[` without a `]`
+    UnterminatedTag(usize),
+    UnknownTag(String),
+    ///Tag and the value it can not use
+    InvalidValue(String, String),
+    ///Closing tag that does not close the innermost open one
+    UnexpectedClose(String),
+    ///Tag still open at the end of the text
+    Unclosed(String),
+    ///Font that is not registered
+    UnknownFont(FontName),
+    ///SpriteSheet the Text was not added with
+    UnknownSpriteSheet(SpriteSheetName),
+}
+impl Display for MarkupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnterminatedTag(offset) => write!(f, "Tag at byte {} is never closed", offset),
+            Self::UnknownTag(tag) => write!(f, "Unknown tag '{}'", tag),
+            Self::InvalidValue(tag, value) => write!(f, "Invalid value '{}' of '{}'", value, tag),
+            Self::UnexpectedClose(tag) => write!(f, "'[/{}]' closes no open tag", tag),
+            Self::Unclosed(tag) => write!(f, "'[{}]' is never closed", tag),
+            Self::UnknownFont(font) => write!(f, "Font {:?} is not registered", font),
+            Self::UnknownSpriteSheet(name) => {
+                write!(f, "SpriteSheet {:?} is not part of the Text", name)
+            }
+        }
+    }
+}
+impl Error for MarkupError {}
+
+///How a span differs from the Text it is part of
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpanStyle {
+    pub color: Option<Color>,
+    pub font: Option<FontName>,
+    pub size: Option<f32>,
+    pub bold: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpanContent {
+    Text(String),
+    ///Square sprite as high as the ascent of the font
+    Icon(SpriteSheetName, SpritePosition),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub content: SpanContent,
+    pub style: SpanStyle,
+}
+impl Span {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self {
+            content: SpanContent::Text(text.into()),
+            style: SpanStyle::default(),
+        }
+    }
+}
+
+fn icon(value: &str) -> Option<SpanContent> {
+    let (sprite_sheet, position) = value.rsplit_once(':')?;
+    let (x, y) = position.split_once(',')?;
+    if sprite_sheet.is_empty() {
+        return None;
+    }
+    Some(SpanContent::Icon(
+        sprite_sheet.into(),
+        SpritePosition::new(x.trim().parse().ok()?, y.trim().parse().ok()?),
+    ))
+}
+
+///Splits `markup` into spans of one style. Always returns at least one span
+pub fn parse_markup(markup: &str) -> Result<Vec<Span>, MarkupError> {
+    let mut spans = Vec::new();
+    let mut open: Vec<(&str, SpanStyle)> = Vec::new();
+    let mut text = String::new();
+    let mut rest = markup;
+    while let Some(start) = rest.find('[') {
+        text.push_str(&rest[..start]);
+        let tag_start = markup.len() - rest.len() + start;
+        rest = &rest[start + 1..];
+        if let Some(after) = rest.strip_prefix('[') {
+            text.push('[');
+            rest = after;
+            continue;
+        }
+        let end = rest
+            .find(']')
+            .ok_or(MarkupError::UnterminatedTag(tag_start))?;
+        let tag = &rest[..end];
+        rest = &rest[end + 1..];
+
+        let style = open
+            .last()
+            .map_or_else(SpanStyle::default, |(_, style)| style.clone());
+        if !text.is_empty() {
+            spans.push(Span {
+                content: SpanContent::Text(std::mem::take(&mut text)),
+                style: style.clone(),
+            });
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            match open.pop() {
+                Some((open_name, _)) if open_name == name => continue,
+                _ => return Err(MarkupError::UnexpectedClose(name.to_string())),
+            }
+        }
+        let (name, value) = match tag.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (tag, None),
+        };
+        let invalid =
+            || MarkupError::InvalidValue(name.to_string(), value.unwrap_or_default().to_string());
+        let mut style = style;
+        match (name, value) {
+            ("color", Some(value)) => style.color = Some(value.parse().map_err(|_| invalid())?),
+            ("font", Some(value)) if !value.is_empty() => style.font = Some(value.into()),
+            ("size", Some(value)) => {
+                let size = value.parse::<f32>().ok();
+                style.size = Some(
+                    size.filter(|size| size.is_finite() && *size > 0.0)
+                        .ok_or_else(invalid)?,
+                );
+            }
+            ("b", None) => style.bold = true,
+            ("icon", Some(value)) => {
+                spans.push(Span {
+                    content: icon(value).ok_or_else(invalid)?,
+                    style,
+                });
+                continue;
+            }
+            ("color" | "font" | "size" | "b" | "icon", _) => return Err(invalid()),
+            _ => return Err(MarkupError::UnknownTag(name.to_string())),
+        }
+        open.push((name, style));
+    }
+    if let Some((name, _)) = open.last() {
+        return Err(MarkupError::Unclosed(name.to_string()));
+    }
+    text.push_str(rest);
+    if !text.is_empty() || spans.is_empty() {
+        spans.push(Span::plain(text));
+    }
+    Ok(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_tags_into_spans() {
+        let spans =
+            parse_markup("Hit [b]for [color=#ff0000]12[/color][/b] [[fire] [icon=icons:2,1]")
+                .unwrap();
+        let red = Color::new_rgb(255, 0, 0);
+        let texts = spans
+            .iter()
+            .map(|span| (span.content.clone(), span.style.color, span.style.bold))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            [
+                (SpanContent::Text("Hit ".into()), None, false),
+                (SpanContent::Text("for ".into()), None, true),
+                (SpanContent::Text("12".into()), Some(red), true),
+                (SpanContent::Text(" [fire] ".into()), None, false),
+                (
+                    SpanContent::Icon("icons".into(), SpritePosition::new(2, 1)),
+                    None,
+                    false
+                ),
+            ]
+        );
+        assert_eq!(parse_markup("").unwrap(), [Span::plain("")]);
+        let sized = parse_markup("[font=MenuFont][size=24]big[/size][/font]").unwrap();
+        assert_eq!(sized[0].style.font, Some("MenuFont".into()));
+        assert_eq!(sized[0].style.size, Some(24.0));
+    }
+
+    #[test]
+    fn rejects_malformed_markup() {
+        let invalid = |tag: &str, value: &str| MarkupError::InvalidValue(tag.into(), value.into());
+        let cases = [
+            ("ab[color=red", MarkupError::UnterminatedTag(2)),
+            ("[i]x[/i]", MarkupError::UnknownTag("i".into())),
+            ("[color=#ff00]x[/color]", invalid("color", "#ff00")),
+            ("[size=-2]x[/size]", invalid("size", "-2")),
+            ("[b=1]x[/b]", invalid("b", "1")),
+            ("[icon=icons:2]", invalid("icon", "icons:2")),
+            ("[b]x[/color]", MarkupError::UnexpectedClose("color".into())),
+            (
+                "[b][color=red]x[/b][/color]",
+                MarkupError::UnexpectedClose("b".into()),
+            ),
+            ("[b]x", MarkupError::Unclosed("b".into())),
+        ];
+        for (markup, error) in cases {
+            assert_eq!(parse_markup(markup), Err(error), "{}", markup);
+        }
+    }
+}
@@ -1,15 +1,29 @@
 use log::warn;
-use ferride_core::game_engine::{BoundingBox, Entity, EntityName, SpriteSheet, SpriteSheetName};
-use std::fmt::Debug;
+use ferride_core::fonts::Font;
+use ferride_core::game_engine::{
+    BoundingBox, DisplayScale, Entity, EntityName, SpriteSheet, SpriteSheetName,
+};
+use std::{
+    fmt::Debug,
+    sync::{Arc, OnceLock},
+};
 // use std::cell::RefCell;
 use threed::Vector;
+use ferride_core::fonts::{font, font_sprite_sheets, FontName};
+use ferride_core::graphics::Color;
+use ferride_core::localization::tr_with;
 use ferride_core::reexports::winit::PhysicalSize;
 
-use crate::{color::Color, ui::FlexItem, Event, Type, FONT};
+use crate::{ui::FlexItem, vertex::render_ui_sprite, Event, Type, FONT};
 
-use self::font_manager::render_character;
+use self::font_manager::{render_character, FontFace};
 
 mod font_manager;
+mod layout;
+use layout::{measure, Atom, Run, RunContent, TextLayout};
+mod markup;
+use markup::{parse_markup, MarkupError, Span, SpanContent};
+pub use layout::{Overflow, TextAlign, TextStyle, VerticalAlign};
 
 #[derive(Debug, Clone)]
 pub struct FontSize(u8);
@@ -24,21 +38,78 @@ impl FontSize {
     }
 }
 
+///What a `Text` shows, either as written or looked up in the `Localization`
+#[derive(Debug, Clone)]
+pub enum TextContent {
+    Plain(String),
+    ///Key and the arguments interpolated into its string
+    Localized(String, Vec<(String, String)>),
+}
+impl TextContent {
+    pub fn localized(key: &str) -> Self {
+        Self::Localized(key.to_string(), Vec::new())
+    }
+
+    pub fn with_arg(mut self, name: &str, value: impl ToString) -> Self {
+        if let Self::Localized(_, args) = &mut self {
+            args.push((name.to_string(), value.to_string()));
+        }
+        self
+    }
+
+    fn resolve(&self) -> String {
+        match self {
+            Self::Plain(text) => text.clone(),
+            Self::Localized(key, args) => {
+                let args = args
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value as &dyn std::fmt::Display))
+                    .collect::<Vec<_>>();
+                tr_with(key, &args)
+            }
+        }
+    }
+}
+impl From<String> for TextContent {
+    fn from(text: String) -> Self {
+        Self::Plain(text)
+    }
+}
+impl From<&str> for TextContent {
+    fn from(text: &str) -> Self {
+        Self::Plain(text.to_string())
+    }
+}
+
 pub struct Text {
+    content: TextContent,
+    ///`content` as shown in the current language
     text: String,
     pub color: Color,
     name: EntityName,
     size: PhysicalSize<u16>,
     max_size: PhysicalSize<u16>,
     position: Vector<f32>,
-    sprite_sheet: SpriteSheetName,
+    font: FontName,
+    ///Markup of `text` is parsed instead of shown as written
+    markup: bool,
+    ///`text` split by its markup
+    spans: Vec<Span>,
+    ///Pages of the fonts, then the icons the spans use. Looked up when first needed, which is
+    ///when the Game adds the Text to a scene, and fixed from then on
+    sprite_sheets: OnceLock<Vec<SpriteSheetName>>,
     font_size: FontSize,
+    style: TextStyle,
+    ///Font sizes are snapped to whole physical pixels of the display
+    display_scale: DisplayScale,
     fit_to_content: bool,
     is_dirty: bool,
 }
 impl Text {
+    ///Uses the default font. Fonts have to be registered before the Text is added to a scene,
+    ///`Game::new` registers the fonts of the `RessourceDescriptor`
     pub fn new(
-        mut text: String,
+        content: impl Into<TextContent>,
         color: Color,
         name: EntityName,
         size: PhysicalSize<u16>,
@@ -46,24 +117,211 @@ impl Text {
         font_size: FontSize,
         fit_to_content: bool,
     ) -> Self {
-        text += " ";
+        let content = content.into();
+        let text = content.resolve();
         Self {
+            spans: vec![Span::plain(text.clone())],
             text,
+            content,
             color,
             name,
             max_size: size.clone(),
             size,
             position,
-            sprite_sheet: FONT.into(),
+            font: FONT.into(),
+            markup: false,
+            sprite_sheets: OnceLock::new(),
             font_size,
+            style: TextStyle::default(),
+            display_scale: DisplayScale::default(),
             fit_to_content,
             is_dirty: true,
         }
     }
 
-    pub fn set_text(&mut self, text: String) {
+    pub fn with_font(mut self, font: FontName) -> Self {
+        self.font = font;
+        self.sprite_sheets = OnceLock::new();
+        self
+    }
+
+    ///Shows the content with its markup, see `markup` for the tags. Malformed markup is warned
+    ///about and shown as written, `set_markup` returns the error instead
+    pub fn with_markup(mut self) -> Self {
+        self.markup = true;
+        self.sprite_sheets = OnceLock::new();
+        self.parse_spans();
+        self
+    }
+
+    pub fn with_style(mut self, style: TextStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn set_text(&mut self, content: impl Into<TextContent>) {
+        self.content = content.into();
+        self.text = self.content.resolve();
+        self.parse_spans();
+        self.is_dirty = true;
+        self.resize_to_content();
+    }
+
+    ///Shows `content` with its markup from now on. Keeps the current content if the markup is
+    ///malformed or uses fonts and icons the Text was not added with
+    pub fn set_markup(&mut self, content: impl Into<TextContent>) -> Result<(), MarkupError> {
+        let content = content.into();
+        let text = content.resolve();
+        self.spans = self.markup_spans(&text)?;
+        self.markup = true;
+        self.content = content;
         self.text = text;
         self.is_dirty = true;
+        self.resize_to_content();
+        Ok(())
+    }
+
+    fn parse_spans(&mut self) {
+        self.spans = if self.markup {
+            self.markup_spans(&self.text).unwrap_or_else(|err| {
+                warn!("Showing {:?} as written: {}", self.text, err);
+                vec![Span::plain(self.text.clone())]
+            })
+        } else {
+            vec![Span::plain(self.text.clone())]
+        };
+    }
+
+    ///SpriteSheets of the Text, looked up the first time they are needed
+    fn known_sprite_sheets(&self) -> &Vec<SpriteSheetName> {
+        self.sprite_sheets.get_or_init(|| self.spans_sprite_sheets())
+    }
+
+    ///Pages of the font of the Text and the fonts and icons of its spans
+    fn spans_sprite_sheets(&self) -> Vec<SpriteSheetName> {
+        let mut sprite_sheets = font_sprite_sheets(&self.font);
+        if sprite_sheets.is_empty() {
+            warn!("Text {:?} uses font {:?}, which is not registered", self.name, self.font);
+        }
+        for span in &self.spans {
+            let mut needed = span.style.font.as_ref().map_or(Vec::new(), |name| {
+                let pages = font_sprite_sheets(name);
+                if pages.is_empty() {
+                    warn!("Text {:?} can not show font {:?}", self.name, name);
+                }
+                pages
+            });
+            if let SpanContent::Icon(sprite_sheet, _) = &span.content {
+                needed.push(sprite_sheet.clone());
+            }
+            for sprite_sheet in needed {
+                if !sprite_sheets.contains(&sprite_sheet) {
+                    sprite_sheets.push(sprite_sheet);
+                }
+            }
+        }
+        sprite_sheets
+    }
+
+    ///Spans of `markup`. The SpriteSheets of an Entity can not change once it is added, so after
+    ///that the fonts and icons of the spans have to be among them
+    fn markup_spans(&self, markup: &str) -> Result<Vec<Span>, MarkupError> {
+        let spans = parse_markup(markup)?;
+        let Some(sprite_sheets) = self.sprite_sheets.get() else {
+            return Ok(spans);
+        };
+        for span in &spans {
+            if let Some(name) = &span.style.font {
+                let pages = font_sprite_sheets(name);
+                if pages.is_empty() {
+                    return Err(MarkupError::UnknownFont(name.clone()));
+                }
+                if let Some(page) = pages.into_iter().find(|page| !sprite_sheets.contains(page)) {
+                    return Err(MarkupError::UnknownSpriteSheet(page));
+                }
+            }
+            if let SpanContent::Icon(sprite_sheet, _) = &span.content {
+                if !sprite_sheets.contains(sprite_sheet) {
+                    return Err(MarkupError::UnknownSpriteSheet(sprite_sheet.clone()));
+                }
+            }
+        }
+        Ok(spans)
+    }
+
+    ///Fonts of the spans, `default` for the ones without their own
+    fn span_fonts(&self, default: &Arc<Font>) -> Vec<Arc<Font>> {
+        self.spans
+            .iter()
+            .map(|span| {
+                let font = span.style.font.as_ref().and_then(font);
+                font.unwrap_or_else(|| default.clone())
+            })
+            .collect()
+    }
+
+    fn runs<'a>(
+        spans: &'a [Span],
+        fonts: &'a [Arc<Font>],
+        font_size: f32,
+        display_scale: &DisplayScale,
+    ) -> Vec<Run<'a>> {
+        spans
+            .iter()
+            .zip(fonts)
+            .map(|(span, font)| Run {
+                font,
+                size: span
+                    .style
+                    .size
+                    .map_or(font_size, |size| display_scale.snap(size)),
+                content: match &span.content {
+                    SpanContent::Text(text) => RunContent::Text(text),
+                    SpanContent::Icon(..) => RunContent::Icon,
+                },
+            })
+            .collect()
+    }
+
+    ///Pages of the font `name` out of the SpriteSheets of the Text. A font that is not registered
+    ///falls back to the font of the Text, like in `span_fonts`
+    fn pages<'a>(
+        &self,
+        name: &FontName,
+        sprite_sheets: &[&'a SpriteSheet],
+    ) -> Vec<&'a SpriteSheet> {
+        let mut pages = font_sprite_sheets(name);
+        if pages.is_empty() {
+            pages = font_sprite_sheets(&self.font);
+        }
+        pages
+            .iter()
+            .filter_map(|page| {
+                self.known_sprite_sheets()
+                    .iter()
+                    .position(|known| known == page)
+            })
+            .map(|index| sprite_sheets[index])
+            .collect()
+    }
+
+    ///Takes the measured size right away when fitting the content, so a FlexBox places the Text
+    ///by its new size before it is rendered
+    fn resize_to_content(&mut self) {
+        if !self.fit_to_content {
+            return;
+        }
+        let size = self.measure(Some(self.max_size.width as f32));
+        self.size.width = (size.width as u16).min(self.max_size.width);
+        self.size.height = (size.height as u16).min(self.max_size.height);
+    }
+
+    ///Leaves room around the text, so it does not touch borders drawn around it
+    fn padded(content: PhysicalSize<f32>, font: &Font, font_size: f32) -> PhysicalSize<f32> {
+        PhysicalSize::new(
+            content.width + font_size / 4.0,
+            content.height + font.line_height(font_size) / 4.0,
+        )
     }
 }
 impl Debug for Text {
@@ -75,6 +333,11 @@ impl Debug for Text {
     }
 }
 impl Entity<Type, Event> for Text {
+    fn is_dirty(&mut self) -> bool {
+        let dirt = self.is_dirty;
+        self.is_dirty = false;
+        dirt
+    }
     fn update(
         &mut self,
         _entities: &Vec<&Box<dyn Entity<Type, Event>>>,
@@ -86,77 +349,107 @@ impl Entity<Type, Event> for Text {
     fn render(
         &mut self,
         vertices: &mut ferride_core::app::VertexBuffer,
-        indices: &mut ferride_core::app::IndexBuffer,
+        _indices: &mut ferride_core::app::IndexBuffer,
         sprite_sheet: Vec<Option<&SpriteSheet>>,
     ) {
-        let color = &self.color;
-        let font_size = self.font_size.0 as f32;
-        let mut text_width = f32::NEG_INFINITY;
-        let font = if let Some(ss) = sprite_sheet[0] {
-            ss
-        } else {
+        let Some(font) = font(&self.font) else {
             return;
         };
-        let mut char_y: u16 = 0;
-        let anchor = &self.position
-            + Vector::new(
-                -(self.size.width as f32 - self.font_size.0 as f32) / 2.0,
-                (self.size.height as f32 - self.font_size.0 as f32) / 2.0,
-                0.0,
-            );
-        let mut char_bounding_box = BoundingBox {
-            anchor: anchor.clone(),
-            size: PhysicalSize::new(font_size, font_size),
+        let sprite_sheets = sprite_sheet
+            .into_iter()
+            .take(self.known_sprite_sheets().len());
+        let Some(sprite_sheets) = sprite_sheets.collect::<Option<Vec<_>>>() else {
+            return;
         };
-        let width = self.position.x + (self.size.width as f32) / 2.0;
-        let height = self.size.height / font_size as u16;
-        for chars in self.text.as_bytes().windows(2) {
-            let current = chars[0];
-            let next = chars[1];
-            // if current == \n
-            let new_line = current == 10;
-            if new_line || char_bounding_box.anchor.x >= width {
-                char_y += 1;
-                if char_y >= height {
-                    warn!("Text too long for bounding box");
-                    break;
-                }
-                if char_bounding_box.anchor.x >= text_width {
-                    text_width = char_bounding_box.anchor.x;
-                }
-                char_bounding_box.anchor.x = anchor.x;
-                char_bounding_box.anchor.y -= font_size;
-                if new_line {
-                    continue;
-                }
-            }
-            let character_width = render_character(
-                current,
-                next,
-                &color,
-                &char_bounding_box,
-                vertices,
-                indices,
-                font,
-            );
-            char_bounding_box.anchor.x += character_width;
-        }
-        if char_bounding_box.anchor.x >= text_width {
-            text_width = char_bounding_box.anchor.x;
+        let fonts = self.span_fonts(&font);
+        let font_size = self.display_scale.snap(self.font_size.0 as f32);
+        let runs = Self::runs(&self.spans, &fonts, font_size, &self.display_scale);
+        let size = if self.fit_to_content {
+            self.max_size
+        } else {
+            self.size
+        };
+        let bounds = PhysicalSize::new(size.width as f32, size.height as f32);
+        let layout = TextLayout::new(&runs, font_size, bounds, &self.style, |size| {
+            self.display_scale.snap(size)
+        });
+        if layout.is_cut && self.style.overflow == Overflow::Clip {
+            warn!("Text too long for bounding box");
         }
         if self.fit_to_content {
-            let height = (((char_y as f32 + 1.25) * font_size) as u16).min(self.max_size.height);
-            let width =
-                ((text_width - anchor.x) as u16 + font_size as u16 / 4).min(self.max_size.width);
+            let fitted = Self::padded(layout.size, &font, layout.font_size);
+            let width = (fitted.width as u16).min(self.max_size.width);
+            let height = (fitted.height as u16).min(self.max_size.height);
             if width != self.size.width || height != self.size.height {
                 self.size.width = width;
                 self.size.height = height;
                 self.is_dirty = true;
             }
         }
+
+        let faces = runs
+            .iter()
+            .zip(&self.spans)
+            .map(|(run, span)| FontFace {
+                font: run.font,
+                pages: self.pages(span.style.font.as_ref().unwrap_or(&self.font), &sprite_sheets),
+                size: run.size * layout.scale,
+                pixels_per_unit: self.display_scale.pixels_per_unit,
+            })
+            .collect::<Vec<_>>();
+        let bounds = PhysicalSize::new(self.size.width as f32, self.size.height as f32);
+        let left = self.position.x - bounds.width / 2.0;
+        let top = self.position.y + bounds.height / 2.0;
+        for glyph in layout.positions(&runs, bounds, &self.style) {
+            let (span, face) = (&self.spans[glyph.run], &faces[glyph.run]);
+            let pen = Vector::new(left + glyph.x, top - glyph.y, 0.0);
+            match (glyph.atom, &span.content) {
+                (Atom::Character(character), _) => {
+                    let color = span.style.color.unwrap_or(self.color);
+                    render_character(character, face, &pen, &color, vertices);
+                    if span.style.bold {
+                        let offset = (face.size / 24.0).max(1.0 / face.pixels_per_unit);
+                        let pen = &pen + Vector::new(offset, 0.0, 0.0);
+                        render_character(character, face, &pen, &color, vertices);
+                    }
+                }
+                (Atom::Icon, SpanContent::Icon(icons, position)) => {
+                    let Some(index) = self
+                        .known_sprite_sheets()
+                        .iter()
+                        .position(|name| name == icons)
+                    else {
+                        continue;
+                    };
+                    let size = face.font.ascent(face.size);
+                    let icon = BoundingBox {
+                        anchor: &pen + Vector::new(size / 2.0, -size / 2.0, 0.0),
+                        size: PhysicalSize::new(size, size),
+                    };
+                    let sprite_sheet = sprite_sheets[index];
+                    render_ui_sprite(&icon, vertices, sprite_sheet, position, None);
+                }
+                (Atom::Icon, SpanContent::Text(_)) => {}
+            }
+        }
     }
     fn sprite_sheets(&self) -> Vec<&SpriteSheetName> {
-        vec![&self.sprite_sheet]
+        self.known_sprite_sheets().iter().collect()
+    }
+    fn display_scale(&mut self, scale: &DisplayScale) {
+        if self.display_scale != *scale {
+            self.display_scale = scale.clone();
+            self.is_dirty = true;
+            self.resize_to_content();
+        }
+    }
+    fn language_changed(&mut self) {
+        if let TextContent::Localized(..) = self.content {
+            self.text = self.content.resolve();
+            self.parse_spans();
+            self.is_dirty = true;
+            self.resize_to_content();
+        }
     }
     fn entity_type(&self) -> Type {
         Type::Menu
@@ -178,14 +471,184 @@ impl FlexItem for Text {
     fn set_position(&mut self, position: &Vector<f32>) {
         self.position = position.clone();
     }
-    fn is_dirty(&mut self) -> bool {
-        let dirt = self.is_dirty;
-        self.is_dirty = false;
-        dirt
-    }
     fn set_focus(&mut self, _focus: bool) {
     }
     fn has_focus(&self) -> bool {
         false
     }
+    ///Size the Text takes when it fits its content, with lines broken to stay within `max_width`
+    ///and its max size. A Text that does not fit its content keeps its size. Lets containers size
+    ///themselves before the Text is rendered
+    fn measure(&self, max_width: Option<f32>) -> PhysicalSize<f32> {
+        if !self.fit_to_content {
+            return PhysicalSize::new(self.size.width as f32, self.size.height as f32);
+        }
+        let Some(font) = font(&self.font) else {
+            return PhysicalSize::new(0.0, 0.0);
+        };
+        let fonts = self.span_fonts(&font);
+        let font_size = self.display_scale.snap(self.font_size.0 as f32);
+        let runs = Self::runs(&self.spans, &fonts, font_size, &self.display_scale);
+        let max_size = PhysicalSize::new(self.max_size.width as f32, self.max_size.height as f32);
+        let max_width = max_width.map_or(max_size.width, |width| width.min(max_size.width));
+        let content = measure(&runs, Some(max_width), self.style.line_spacing);
+        let size = Self::padded(content, &font, font_size);
+        PhysicalSize::new(size.width.min(max_size.width), size.height.min(max_size.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ferride_core::{
+        app::{IndexBuffer, VertexBuffer},
+        fonts::{register_font, BitmapFont, Font, REPLACEMENT_CHARACTER},
+    };
+
+    use super::*;
+
+    fn default_font() -> BitmapFont {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/res/fonts/font.fnt");
+        let font = BitmapFont::parse(path, &std::fs::read(path).unwrap()).unwrap();
+        register_font(FONT.into(), font.clone());
+        font
+    }
+
+    #[test]
+    fn renders_any_string_without_panicking() {
+        let font = default_font();
+        let sheets = [SpriteSheet::default(), SpriteSheet::default()];
+        let face_font = Font::from(font.clone());
+        let face = FontFace {
+            font: &face_font,
+            pages: sheets.iter().collect(),
+            size: 32.0,
+            pixels_per_unit: 1.0,
+        };
+        let characters = (0..0x3000)
+            .chain((0x3000..=0x10FFFF).step_by(61))
+            .chain([0xFFFD, 0xFFFF, 0x10FFFF])
+            .filter_map(char::from_u32)
+            .collect::<Vec<_>>();
+        let mut vertices = VertexBuffer::new();
+        for character in characters {
+            assert!(font.glyph_or_replacement(character).is_some());
+            render_character(
+                character,
+                &face,
+                &Vector::scalar(0.0),
+                &Color::new_rgba(255, 255, 255, 255),
+                &mut vertices,
+            );
+        }
+        assert_eq!(font.glyph('ü').map(|glyph| glyph.page), Some(1));
+        assert_eq!(
+            font.glyph_or_replacement('🐸'),
+            font.glyph(REPLACEMENT_CHARACTER)
+        );
+        // Latin-1 letters kern like the ASCII letter they are based on
+        assert_eq!(font.kerning('ì', 'j'), font.kerning('i', 'j'));
+        assert_ne!(font.kerning('ì', '?'), font.kerning('t', '?'));
+        assert_eq!(font.kerning('Á', 'V'), font.kerning('A', 'V'));
+
+        for text in [
+            "Bia Karui’s",
+            "Grüße…",
+            "日本語 🐸",
+            "\0\t\r\n\u{301}",
+            "",
+            "\n",
+        ] {
+            let mut vertices = VertexBuffer::new();
+            let mut indices = IndexBuffer::new();
+            let mut text_entity = Text::new(
+                text,
+                Color::new_rgba(255, 255, 255, 255),
+                "text".into(),
+                PhysicalSize::new(1000, 200),
+                Vector::scalar(0.0),
+                FontSize::new(20),
+                true,
+            );
+            assert_eq!(text_entity.sprite_sheets().len(), 2);
+            text_entity.render(&mut vertices, &mut indices, sheets.iter().map(Some).collect());
+            let shown = text.chars().filter(|c| *c != '\n').count() as u32;
+            assert_eq!(vertices.len(), shown, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn looks_up_font_pages_once_added() {
+        let text = Text::new(
+            "late",
+            Color::new_rgba(255, 255, 255, 255),
+            "text".into(),
+            PhysicalSize::new(100, 20),
+            Vector::scalar(0.0),
+            FontSize::new(20),
+            false,
+        )
+        .with_font("LateFont".into());
+        register_font("LateFont".into(), default_font());
+        assert_eq!(
+            text.sprite_sheets(),
+            font_sprite_sheets(&"LateFont".into()).iter().collect::<Vec<_>>()
+        );
+        assert!(!text.sprite_sheets().is_empty());
+    }
+
+    #[test]
+    fn measures_fitting_and_fixed_texts() {
+        default_font();
+        let text = |fit_to_content| {
+            Text::new(
+                "a few words to break",
+                Color::new_rgba(255, 255, 255, 255),
+                "text".into(),
+                PhysicalSize::new(120, 500),
+                Vector::scalar(0.0),
+                FontSize::new(20),
+                fit_to_content,
+            )
+        };
+        assert_eq!(text(false).measure(Some(60.0)), PhysicalSize::new(120.0, 500.0));
+        let fitting = text(true);
+        let narrow = fitting.measure(Some(60.0));
+        let wide = fitting.measure(None);
+        assert!(narrow.width < wide.width && wide.width <= 120.0);
+        assert!(narrow.height > wide.height);
+    }
+
+    #[test]
+    fn renders_markup_spans() {
+        default_font();
+        let sheets = [SpriteSheet::default(), SpriteSheet::default(), SpriteSheet::default()];
+        let mut text = Text::new(
+            "Hit [b]12[/b] [icon=icons:0,0]",
+            Color::new_rgba(255, 255, 255, 255),
+            "text".into(),
+            PhysicalSize::new(1000, 200),
+            Vector::scalar(0.0),
+            FontSize::new(20),
+            true,
+        )
+        .with_markup();
+        assert_eq!(text.sprite_sheets().len(), 3);
+        let mut vertices = VertexBuffer::new();
+        let mut indices = IndexBuffer::new();
+        text.render(&mut vertices, &mut indices, sheets.iter().map(Some).collect());
+        assert_eq!(vertices.len(), 4 + 2 * 2 + 1 + 1);
+
+        assert_eq!(text.set_markup("[b]x"), Err(MarkupError::Unclosed("b".into())));
+        assert_eq!(text.spans.len(), 4);
+        assert_eq!(
+            text.set_markup("[font=Missing]x[/font]"),
+            Err(MarkupError::UnknownFont("Missing".into()))
+        );
+        assert_eq!(
+            text.set_markup("x[icon=other:0,0]"),
+            Err(MarkupError::UnknownSpriteSheet("other".into()))
+        );
+        assert_eq!(text.spans.len(), 4);
+        assert_eq!(text.sprite_sheets().len(), 3);
+    }
 }
@@ -0,0 +1,57 @@
+use ferride_core::{
+    app::VertexBuffer,
+    game_engine::{BoundingBox, SpritePosition, SpriteSheet},
+    graphics::{Color, SpriteInstance},
+};
+
+fn blend_color(color: &Color) -> u32 {
+    u32::from_be_bytes(color.to_slice())
+}
+
+///Draws a border of `border_thickness` around the `bounding_box`. The top and bottom strip
+///cover the corners
+pub fn render_ui_box_border(
+    bounding_box: &BoundingBox,
+    vertices: &mut VertexBuffer,
+    border_thickness: f32,
+    color: &Color,
+) {
+    let y = bounding_box.anchor.y;
+    let x = bounding_box.anchor.x;
+    let x_offset = (bounding_box.size.width + border_thickness) / 2.0;
+    let y_offset = (bounding_box.size.height + border_thickness) / 2.0;
+    let horizontal = [
+        bounding_box.size.width + 2.0 * border_thickness,
+        border_thickness,
+    ];
+    let vertical = [border_thickness, bounding_box.size.height];
+    let sprite_sheet = SpriteSheet::default();
+    let color = blend_color(color);
+    let strips = [
+        ([x - x_offset, y], vertical),
+        ([x, y + y_offset], horizontal),
+        ([x + x_offset, y], vertical),
+        ([x, y - y_offset], horizontal),
+    ];
+    for (position, size) in strips {
+        vertices.extend_from_slice(&[SpriteInstance::new(
+            position,
+            size,
+            [0.0, 0.0, 1.0, 1.0],
+            sprite_sheet.texture(),
+        )
+        .with_color(color)]);
+    }
+}
+
+///Writes the sprite covering `bounding_box`, blended with `color` if there is one
+pub fn render_ui_sprite(
+    bounding_box: &BoundingBox,
+    vertices: &mut VertexBuffer,
+    sprite_sheet: &SpriteSheet,
+    sprite_position: &SpritePosition,
+    color: Option<&Color>,
+) {
+    let instance = sprite_sheet.instance(bounding_box, sprite_position);
+    vertices.extend_from_slice(&[instance.with_color(color.map_or(0, blend_color))]);
+}